@@ -8,6 +8,8 @@ pub mod checkin;
 pub mod checkout;
 pub mod checksum;
 pub mod extract;
+pub mod list;
+pub mod path;
 
 /// Manage artifacts.
 #[derive(Clone, Debug, clap::Args)]
@@ -26,6 +28,8 @@ pub enum Command {
 	Checkout(self::checkout::Args),
 	Checksum(self::checksum::Args),
 	Extract(self::extract::Args),
+	List(self::list::Args),
+	Path(self::path::Args),
 }
 
 impl Cli {
@@ -52,6 +56,12 @@ impl Cli {
 			Command::Extract(args) => {
 				self.command_artifact_extract(args).await?;
 			},
+			Command::List(args) => {
+				self.command_artifact_list(args).await?;
+			},
+			Command::Path(args) => {
+				self.command_artifact_path(args).await?;
+			},
 		}
 		Ok(())
 	}
@@ -20,6 +20,14 @@ pub struct Args {
 	#[arg(long)]
 	pub deterministic: bool,
 
+	/// Check in the targets of symlinks instead of the symlinks. Symlinks that point outside the path are an error unless this is set to `all`.
+	#[arg(long, num_args = 0..=1, default_missing_value = "root", value_name = "root|all")]
+	pub follow_symlinks: Option<tg::artifact::checkin::FollowSymlinks>,
+
+	/// Compute the artifact's ID without storing it.
+	#[arg(long, conflicts_with_all = ["cache", "destructive"])]
+	pub hash_only: bool,
+
 	/// If false, don't parse ignore files.
 	#[arg(default_value = "true", long, action = clap::ArgAction::Set)]
 	pub ignore: bool,
@@ -35,6 +43,10 @@ pub struct Args {
 	/// The path to check in.
 	#[arg(default_value = ".", index = 1)]
 	pub path: Option<PathBuf>,
+
+	/// Which versions of tagged dependencies to prefer when solving.
+	#[arg(long, value_name = "newest|minimal")]
+	pub strategy: Option<tg::artifact::checkin::Strategy>,
 }
 
 impl Cli {
@@ -49,11 +61,14 @@ impl Cli {
 		let arg = tg::artifact::checkin::Arg {
 			cache: args.cache,
 			destructive: args.destructive,
-			deterministic: false,
+			deterministic: args.deterministic,
+			follow_symlinks: args.follow_symlinks,
+			hash_only: args.hash_only,
 			ignore: args.ignore,
 			locked: args.locked,
 			lockfile: args.lockfile,
 			path,
+			strategy: args.strategy,
 		};
 		let stream = handle
 			.check_in_artifact(arg)
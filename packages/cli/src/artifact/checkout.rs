@@ -63,6 +63,7 @@ impl Cli {
 		let force = args.force;
 		let lockfile = args.lockfile;
 		let arg = tg::artifact::checkout::Arg {
+			cache: true,
 			dependencies,
 			force,
 			lockfile,
@@ -8,6 +8,7 @@ pub struct Args {
 	#[arg(index = 1)]
 	pub blob: tg::blob::Id,
 
+	/// The format of the archive. If not provided, the format is detected from the archive's contents.
 	#[arg(long)]
 	pub format: Option<tg::artifact::archive::Format>,
 }
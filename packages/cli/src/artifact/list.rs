@@ -0,0 +1,28 @@
+use crate::Cli;
+use tangram_client::{self as tg, Handle as _};
+
+/// List the entries of an archive without extracting it.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	#[arg(index = 1)]
+	pub blob: tg::blob::Id,
+
+	#[arg(long)]
+	pub format: tg::artifact::archive::Format,
+
+	#[arg(long)]
+	pub pretty: Option<bool>,
+}
+
+impl Cli {
+	pub async fn command_artifact_list(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+		let arg = tg::artifact::archive::list::Arg {
+			format: args.format,
+		};
+		let output = handle.list_archive_entries(&args.blob, arg).await?;
+		Self::output_json(&output, args.pretty).await?;
+		Ok(())
+	}
+}
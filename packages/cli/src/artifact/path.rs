@@ -0,0 +1,42 @@
+use crate::Cli;
+use tangram_client::{self as tg, Handle as _};
+
+/// Print the path of an artifact in the server's artifacts directory.
+///
+/// If the server's VFS is enabled, then the artifacts directory is a mount, and the artifact's contents are read from the server on demand. Otherwise, the artifact is checked out to the artifacts directory as a copy. The path is only usable on the machine the server is running on.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// The artifact.
+	#[arg(index = 1)]
+	pub artifact: tg::artifact::Id,
+
+	/// Print the path without caching the artifact, and error if it is not present. If the server's VFS is enabled, then the artifact is always present.
+	#[arg(long)]
+	pub no_cache: bool,
+}
+
+impl Cli {
+	pub async fn command_artifact_path(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Check out the artifact to the artifacts directory. If caching is disabled, then the server only checks that the artifact is present.
+		let arg = tg::artifact::checkout::Arg {
+			cache: !args.no_cache,
+			dependencies: true,
+			force: false,
+			lockfile: false,
+			path: None,
+		};
+		let stream = handle
+			.check_out_artifact(&args.artifact, arg)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to create check out stream"))?;
+		let output = self.render_progress_stream(stream).await?;
+
+		// Print the path.
+		println!("{}", output.path.display());
+
+		Ok(())
+	}
+}
@@ -21,6 +21,7 @@ pub enum Command {
 	Cat(self::cat::Args),
 	Checksum(self::checksum::Args),
 	Compress(self::compress::Args),
+	#[command(alias = "from-stdin")]
 	Create(self::create::Args),
 	Decompress(self::decompress::Args),
 	Download(self::download::Args),
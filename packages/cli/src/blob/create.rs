@@ -1,17 +1,41 @@
 use crate::Cli;
+use std::sync::{Arc, Mutex};
 use tangram_client::{self as tg, Handle as _};
+use tokio_util::io::InspectReader;
 
-/// Create a blob.
+/// Create a blob from stdin.
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
-pub struct Args {}
+pub struct Args {
+	/// Compute a checksum of the input with this algorithm while it is read and print it after the blob's ID.
+	#[arg(long)]
+	pub checksum: Option<tg::checksum::Algorithm>,
+}
 
 impl Cli {
-	pub async fn command_blob_create(&self, _args: Args) -> tg::Result<()> {
+	pub async fn command_blob_create(&self, args: Args) -> tg::Result<()> {
 		let handle = self.handle().await?;
-		let reader = tokio::io::stdin();
+
+		// Create a reader that computes the checksum as the input streams through.
+		let writer = Arc::new(Mutex::new(args.checksum.map(tg::checksum::Writer::new)));
+		let reader = InspectReader::new(tokio::io::stdin(), {
+			let writer = writer.clone();
+			move |chunk| {
+				if let Some(writer) = writer.lock().unwrap().as_mut() {
+					writer.update(chunk);
+				}
+			}
+		});
+
+		// Create the blob.
 		let tg::blob::create::Output { blob, .. } = handle.create_blob(reader).await?;
+
+		// Print the blob and the checksum.
 		println!("{blob}");
+		if let Some(writer) = writer.lock().unwrap().take() {
+			println!("{}", writer.finalize());
+		}
+
 		Ok(())
 	}
 }
@@ -11,6 +11,7 @@ pub mod pull;
 pub mod push;
 pub mod put;
 pub mod status;
+pub mod wait;
 
 /// Build a target or manage builds.
 #[derive(Clone, Debug, clap::Args)]
@@ -41,6 +42,7 @@ pub enum Command {
 	Push(self::push::Args),
 	Put(self::put::Args),
 	Status(self::status::Args),
+	Wait(self::wait::Args),
 }
 
 impl Cli {
@@ -79,6 +81,9 @@ impl Cli {
 			Some(Command::Status(args)) => {
 				self.command_build_status(args).await?;
 			},
+			Some(Command::Wait(args)) => {
+				self.command_build_wait(args).await?;
+			},
 		}
 		Ok(())
 	}
@@ -10,6 +10,10 @@ pub struct Args {
 	#[arg(index = 1)]
 	pub build: tg::build::Id,
 
+	/// Only get children that have this label, formatted as `key=value`. May be provided multiple times.
+	#[arg(long = "label", action = clap::ArgAction::Append)]
+	pub labels: Vec<String>,
+
 	#[arg(long)]
 	pub length: Option<u64>,
 
@@ -32,7 +36,12 @@ impl Cli {
 		let remote = args
 			.remote
 			.map(|option| option.unwrap_or_else(|| "default".to_owned()));
+		for label in &args.labels {
+			crate::target::build::parse_label(label)?;
+		}
+		let labels = (!args.labels.is_empty()).then_some(args.labels);
 		let arg = tg::build::children::get::Arg {
+			labels,
 			position: args.position.map(std::io::SeekFrom::Start),
 			length: args.length,
 			remote,
@@ -0,0 +1,80 @@
+use crate::Cli;
+use futures::{stream::FuturesUnordered, StreamExt as _};
+use tangram_client as tg;
+
+/// Wait for builds to finish.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// Wait for all of the builds to finish. This is the default.
+	#[arg(long, conflicts_with = "any")]
+	pub all: bool,
+
+	/// Return as soon as any of the builds finishes.
+	#[arg(long)]
+	pub any: bool,
+
+	/// The builds to wait for.
+	#[arg(index = 1, num_args = 1.., required = true)]
+	pub builds: Vec<tg::build::Id>,
+}
+
+impl Cli {
+	pub async fn command_build_wait(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Wait for all of the builds unless --any is set.
+		let any = args.any && !args.all;
+
+		// Wait for the builds concurrently.
+		let mut outcomes = args
+			.builds
+			.into_iter()
+			.map(|id| {
+				let handle = handle.clone();
+				async move {
+					let build = tg::Build::with_id(id.clone());
+					let outcome = build.outcome(&handle).await;
+					(id, outcome)
+				}
+			})
+			.collect::<FuturesUnordered<_>>();
+
+		// Print each build's status as it finishes.
+		let mut failed = 0;
+		while let Some((id, outcome)) = outcomes.next().await {
+			let succeeded = match outcome {
+				Ok(tg::build::Outcome::Success(_)) => {
+					println!("{id} succeeded");
+					true
+				},
+				Ok(tg::build::Outcome::Failure(_)) => {
+					println!("{id} failed");
+					false
+				},
+				Ok(tg::build::Outcome::Cancelation(_)) => {
+					println!("{id} canceled");
+					false
+				},
+				Err(error) => {
+					println!("{id} error");
+					Self::print_error(&error, self.config.as_ref());
+					false
+				},
+			};
+			if !succeeded {
+				failed += 1;
+			}
+			if any {
+				break;
+			}
+		}
+
+		// Exit with an error if any of the builds did not succeed.
+		if failed > 0 {
+			return Err(tg::error!(%failed, "not all of the builds succeeded"));
+		}
+
+		Ok(())
+	}
+}
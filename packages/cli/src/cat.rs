@@ -13,8 +13,8 @@ pub struct Args {
 impl Cli {
 	pub async fn command_cat(&self, args: Args) -> tg::Result<()> {
 		let handle = self.handle().await?;
-		for reference in &args.references {
-			let referent = self.get_reference(reference).await?;
+		let referents = self.get_references(&args.references).await?;
+		for referent in referents {
 			let Either::Right(object) = referent.item else {
 				return Err(tg::error!("expected an object"));
 			};
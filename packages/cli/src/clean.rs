@@ -1,15 +1,32 @@
 use crate::Cli;
+use crossterm::style::Stylize as _;
 use tangram_client::{self as tg, Handle as _};
 
 /// Remove unused builds and objects.
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
-pub struct Args {}
+pub struct Args {
+	/// Before cleaning, keep only the N most recently created tags under the tag prefix and delete the rest.
+	#[arg(long, requires = "tag_prefix")]
+	pub keep_last: Option<u64>,
+
+	/// The prefix of the tags that --keep-last applies to.
+	#[arg(long, requires = "keep_last")]
+	pub tag_prefix: Option<String>,
+}
 
 impl Cli {
-	pub async fn command_clean(&self, _args: Args) -> tg::Result<()> {
+	pub async fn command_clean(&self, args: Args) -> tg::Result<()> {
 		let handle = self.handle().await?;
-		handle.clean().await?;
+		let arg = tg::clean::Arg {
+			keep_last: args.keep_last,
+			roots: Vec::new(),
+			tag_prefix: args.tag_prefix,
+		};
+		let output = handle.clean(arg).await?;
+		for tag in &output.pruned {
+			eprintln!("{} pruned tag {tag}", "info".blue().bold());
+		}
 		Ok(())
 	}
 }
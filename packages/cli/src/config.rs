@@ -1,8 +1,28 @@
+use crate::Cli;
 use serde_with::{serde_as, DurationSecondsWithFrac};
-use std::{path::PathBuf, time::Duration};
+use std::{collections::BTreeMap, path::PathBuf, time::Duration};
 use tangram_client::{self as tg, util::serde::is_false};
 use url::Url;
 
+pub mod get;
+pub mod path;
+pub mod set;
+
+/// Manage the config file.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	#[command(subcommand)]
+	pub command: Command,
+}
+
+#[derive(Clone, Debug, clap::Subcommand)]
+pub enum Command {
+	Get(self::get::Args),
+	Path(self::path::Args),
+	Set(self::set::Args),
+}
+
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
@@ -114,6 +134,10 @@ pub struct Advanced {
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub preserve_temp_directories: Option<bool>,
 
+	/// The maximum number of tags the server will write in a single transaction when putting many tags at once.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub tag_batch_size: Option<usize>,
+
 	/// Whether to enable publishing of data to tokio console.
 	#[serde(default, skip_serializing_if = "is_false")]
 	pub tokio_console: bool,
@@ -144,6 +168,10 @@ pub struct BuildHeartbeatMonitor {
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub limit: Option<usize>,
 
+	/// The number of heartbeat intervals a started build may miss before it is canceled. If set, this takes precedence over the timeout.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub max_missed_heartbeats: Option<u32>,
+
 	/// The duration without a heartbeat before a build is canceled.
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	#[serde_as(as = "Option<DurationSecondsWithFrac>")]
@@ -154,11 +182,17 @@ pub struct BuildHeartbeatMonitor {
 #[serde(deny_unknown_fields)]
 pub struct BuildIndexer {}
 
+#[serde_as]
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Authentication {
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub providers: Option<AuthenticationProviders>,
+
+	/// The duration after which issued tokens expire. If this is not set, then tokens do not expire.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	#[serde_as(as = "Option<DurationSecondsWithFrac>")]
+	pub token_ttl: Option<Duration>,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
@@ -196,6 +230,18 @@ pub struct Build {
 	/// The remotes to build for.
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub remotes: Option<Vec<String>>,
+
+	/// Options for each runtime, keyed by host.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub runtimes: Option<BTreeMap<String, Runtime>>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Runtime {
+	/// The maximum number of concurrent builds for this runtime. The build concurrency still bounds the total.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub concurrency: Option<usize>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -205,6 +251,7 @@ pub enum Database {
 	Sqlite(SqliteDatabase),
 }
 
+#[serde_as]
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PostgresDatabase {
@@ -212,11 +259,25 @@ pub struct PostgresDatabase {
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub connections: Option<usize>,
 
+	/// The URL of a read replica to use for read connections.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub read_url: Option<Url>,
+
+	/// The maximum number of prepared statements to cache per connection.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub statement_cache_size: Option<usize>,
+
+	/// The maximum duration of each statement, in seconds.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	#[serde_as(as = "Option<DurationSecondsWithFrac>")]
+	pub timeout: Option<Duration>,
+
 	/// The URL.
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub url: Option<Url>,
 }
 
+#[serde_as]
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct SqliteDatabase {
@@ -227,6 +288,15 @@ pub struct SqliteDatabase {
 	/// The path.
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub path: Option<PathBuf>,
+
+	/// The maximum number of prepared statements to cache per connection.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub statement_cache_size: Option<usize>,
+
+	/// The maximum duration of each statement, in seconds.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	#[serde_as(as = "Option<DurationSecondsWithFrac>")]
+	pub timeout: Option<Duration>,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
@@ -292,4 +362,114 @@ pub struct Vfs {
 	pub cache_ttl: Option<Duration>,
 	pub cache_size: Option<usize>,
 	pub database_connections: Option<usize>,
+
+	/// Whether to mount the VFS read-only. This defaults to `true`. Prior versions mounted the VFS read-write, so set this to `false` to keep the previous mount options. Writes are rejected with `EROFS` either way.
+	pub read_only: Option<bool>,
+}
+
+impl Cli {
+	pub async fn command_config(&self, args: Args) -> tg::Result<()> {
+		match args.command {
+			Command::Get(args) => {
+				self.command_config_get(args).await?;
+			},
+			Command::Path(args) => {
+				self.command_config_path(args).await?;
+			},
+			Command::Set(args) => {
+				self.command_config_set(args).await?;
+			},
+		}
+		Ok(())
+	}
+}
+
+impl Config {
+	/// Get the value of a key, with dots separating the names of nested keys. This returns `None` if the key is valid but not set, and an error if the key is not valid.
+	pub fn get(&self, key: &str) -> tg::Result<Option<serde_json::Value>> {
+		let value = serde_json::to_value(self)
+			.map_err(|source| tg::error!(!source, "failed to serialize the config"))?;
+		let value = key
+			.split('.')
+			.try_fold(&value, |value, name| value.get(name));
+		if let Some(value) = value {
+			return Ok(Some(value.clone()));
+		}
+
+		// Validate the key by setting it to null. A valid key whose type does not accept null, such as a `bool` that is skipped when it is `false`, fails only because of the null value.
+		let config = self.insert(key, serde_json::Value::Null)?;
+		match serde_json::from_value::<Self>(config) {
+			Ok(_) => (),
+			Err(error) if error.to_string().starts_with("invalid type: null") => (),
+			Err(source) => return Err(tg::error!(!source, %key, "invalid key")),
+		}
+
+		Ok(None)
+	}
+
+	/// Set the value of a key, with dots separating the names of nested keys. This returns an error if the key is not valid or the value does not have the key's type.
+	pub fn set(&self, key: &str, value: serde_json::Value) -> tg::Result<Self> {
+		let config = self.insert(key, value)?;
+		let config = serde_json::from_value(config)
+			.map_err(|source| tg::error!(!source, %key, "invalid key or value"))?;
+		Ok(config)
+	}
+
+	/// Serialize the config and insert a value at a key.
+	fn insert(&self, key: &str, value: serde_json::Value) -> tg::Result<serde_json::Value> {
+		let mut config = serde_json::to_value(self)
+			.map_err(|source| tg::error!(!source, "failed to serialize the config"))?;
+		let mut names = key.split('.').peekable();
+		let mut object = &mut config;
+		while let Some(name) = names.next() {
+			if name.is_empty() {
+				return Err(tg::error!(%key, "invalid key"));
+			}
+			if !object.is_object() {
+				*object = serde_json::Value::Object(serde_json::Map::new());
+			}
+			let map = object.as_object_mut().unwrap();
+			if names.peek().is_none() {
+				map.insert(name.to_owned(), value);
+				break;
+			}
+			object = map
+				.entry(name)
+				.or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+		}
+		Ok(config)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Config;
+
+	#[test]
+	fn get_and_set() {
+		let config = Config::default();
+		assert_eq!(config.get("vfs.cache_size").unwrap(), None);
+		let config = config
+			.set("vfs.cache_size", serde_json::json!(1024))
+			.unwrap();
+		assert_eq!(
+			config.get("vfs.cache_size").unwrap(),
+			Some(serde_json::json!(1024))
+		);
+		assert!(config
+			.set("vfs.cache_size", serde_json::json!("big"))
+			.is_err());
+		assert!(config.set("vfs.unknown", serde_json::json!(1)).is_err());
+		assert!(config.get("unknown").is_err());
+		assert_eq!(config.get("advanced.tokio_console").unwrap(), None);
+		assert_eq!(config.get("tracing.filter").unwrap(), None);
+		assert!(config.get("tracing.filter.unknown").is_err());
+		let config = config
+			.set("advanced.tokio_console", serde_json::json!(true))
+			.unwrap();
+		assert_eq!(
+			config.get("advanced.tokio_console").unwrap(),
+			Some(serde_json::json!(true))
+		);
+	}
 }
@@ -0,0 +1,29 @@
+use crate::Cli;
+use tangram_client as tg;
+
+/// Get a value from the config file.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// The key, with dots separating the names of nested keys, such as `vfs.cache_size`.
+	#[arg(index = 1)]
+	pub key: String,
+}
+
+impl Cli {
+	pub async fn command_config_get(&self, args: Args) -> tg::Result<()> {
+		let config = Self::read_config(self.args.config.clone())?.unwrap_or_default();
+		let Some(value) = config.get(&args.key)? else {
+			return Ok(());
+		};
+		match value {
+			serde_json::Value::String(string) => println!("{string}"),
+			value => {
+				let value = serde_json::to_string_pretty(&value)
+					.map_err(|source| tg::error!(!source, "failed to serialize the value"))?;
+				println!("{value}");
+			},
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,15 @@
+use crate::Cli;
+use tangram_client as tg;
+
+/// Print the path of the config file.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {}
+
+impl Cli {
+	pub async fn command_config_path(&self, _args: Args) -> tg::Result<()> {
+		let path = Self::config_path(self.args.config.clone());
+		println!("{}", path.display());
+		Ok(())
+	}
+}
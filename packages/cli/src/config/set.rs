@@ -0,0 +1,26 @@
+use crate::Cli;
+use tangram_client as tg;
+
+/// Set a value in the config file.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// The key, with dots separating the names of nested keys, such as `vfs.cache_size`.
+	#[arg(index = 1)]
+	pub key: String,
+
+	/// The value. If the value is not valid JSON, then it is set as a string.
+	#[arg(index = 2)]
+	pub value: String,
+}
+
+impl Cli {
+	pub async fn command_config_set(&self, args: Args) -> tg::Result<()> {
+		let config = Self::read_config(self.args.config.clone())?.unwrap_or_default();
+		let value = serde_json::from_str(&args.value)
+			.unwrap_or_else(|_| serde_json::Value::String(args.value.clone()));
+		let config = config.set(&args.key, value)?;
+		Self::write_config(&config, self.args.config.clone())?;
+		Ok(())
+	}
+}
@@ -0,0 +1,317 @@
+use crate::Cli;
+use clap::CommandFactory as _;
+use crossterm::style::Stylize as _;
+use std::{os::unix::fs::MetadataExt as _, path::PathBuf, time::Duration};
+use tangram_client as tg;
+
+/// Diagnose common problems with the setup.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {}
+
+#[derive(Clone, Debug)]
+struct Check {
+	name: &'static str,
+	status: Status,
+	message: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Status {
+	Pass,
+	Warn,
+	Fail,
+}
+
+/// The file descriptor limit below which the server is likely to run out of file descriptors.
+const MIN_FILE_DESCRIPTOR_LIMIT: u64 = 4096;
+
+impl Cli {
+	pub async fn command_doctor(&self, _args: Args) -> tg::Result<()> {
+		let mut checks = Vec::new();
+
+		// Check the config.
+		checks.push(self.doctor_check_config());
+
+		// Check the server and its version.
+		checks.extend(self.doctor_check_server().await);
+
+		// Check the VFS.
+		checks.push(self.doctor_check_vfs());
+
+		// Check the file descriptor limit.
+		checks.push(Self::doctor_check_file_descriptor_limit());
+
+		// Print the checks.
+		for check in &checks {
+			let status = match check.status {
+				Status::Pass => "pass".green().bold(),
+				Status::Warn => "warn".yellow().bold(),
+				Status::Fail => "fail".red().bold(),
+			};
+			println!("{status} {}: {}", check.name, check.message);
+		}
+
+		// Exit with a non-zero code if any check failed.
+		let count = checks
+			.iter()
+			.filter(|check| check.status == Status::Fail)
+			.count();
+		if count > 0 {
+			return Err(tg::error!(%count, "some checks failed"));
+		}
+
+		Ok(())
+	}
+
+	fn doctor_check_config(&self) -> Check {
+		let name = "config";
+		let path = Self::config_path(self.args.config.clone());
+		match Self::read_config(self.args.config.clone()) {
+			Ok(Some(_)) => Check {
+				name,
+				status: Status::Pass,
+				message: format!("{} is valid", path.display()),
+			},
+			Ok(None) => Check {
+				name,
+				status: Status::Pass,
+				message: format!("{} does not exist, using the defaults", path.display()),
+			},
+			Err(error) => Check {
+				name,
+				status: Status::Fail,
+				message: Self::doctor_error_message(&error),
+			},
+		}
+	}
+
+	async fn doctor_check_server(&self) -> Vec<Check> {
+		// Get the url.
+		let url = self
+			.args
+			.url
+			.clone()
+			.or(self.config.as_ref().and_then(|config| config.url.clone()))
+			.unwrap_or_else(|| {
+				let path = self.doctor_path().join("socket");
+				let path = path.to_str().unwrap();
+				let path = urlencoding::encode(path);
+				format!("http+unix://{path}").parse().unwrap()
+			});
+		let local =
+			url.scheme() == "http+unix" || matches!(url.host_str(), Some("localhost" | "0.0.0.0"));
+
+		// Attempt to connect to the server without starting it.
+		let client = tg::Client::new(url.clone());
+		let connect = tokio::time::timeout(Duration::from_secs(5), client.connect()).await;
+		let health = match connect {
+			Ok(Ok(())) => tokio::time::timeout(Duration::from_secs(5), client.health())
+				.await
+				.unwrap_or_else(|_| Err(tg::error!("timed out"))),
+			Ok(Err(error)) => Err(error),
+			Err(_) => Err(tg::error!("timed out")),
+		};
+		let health = match health {
+			Ok(health) => health,
+			Err(error) if local => {
+				let message = format!(
+					"failed to connect to {url}, the server will be started when it is needed: {}",
+					Self::doctor_error_message(&error)
+				);
+				let check = Check {
+					name: "server",
+					status: Status::Warn,
+					message,
+				};
+				return vec![check];
+			},
+			Err(error) => {
+				let message = format!(
+					"failed to connect to {url}: {}",
+					Self::doctor_error_message(&error)
+				);
+				let check = Check {
+					name: "server",
+					status: Status::Fail,
+					message,
+				};
+				return vec![check];
+			},
+		};
+		let server = Check {
+			name: "server",
+			status: Status::Pass,
+			message: format!("connected to {url}"),
+		};
+
+		// Compare the client and server versions.
+		let name = "version";
+		let client_version = crate::Args::command().get_version().unwrap().to_owned();
+		let version = match &health.version {
+			None => Check {
+				name,
+				status: Status::Warn,
+				message: "the server did not report its version".to_owned(),
+			},
+			Some(server_version) if *server_version == client_version => Check {
+				name,
+				status: Status::Pass,
+				message: format!("the client and server versions are {client_version}"),
+			},
+			Some(server_version) if local => Check {
+				name,
+				status: Status::Warn,
+				message: format!(
+					"the client version is {client_version} but the server version is {server_version}, the server will be restarted when it is needed"
+				),
+			},
+			Some(server_version) => Check {
+				name,
+				status: Status::Fail,
+				message: format!(
+					"the client version is {client_version} but the server version is {server_version}"
+				),
+			},
+		};
+
+		vec![server, version]
+	}
+
+	fn doctor_check_vfs(&self) -> Check {
+		let name = "vfs";
+
+		// Determine if the VFS is enabled.
+		let enabled = match self.config.as_ref().and_then(|config| config.vfs.as_ref()) {
+			Some(vfs) => vfs.is_some(),
+			None => cfg!(target_os = "linux"),
+		};
+		if !enabled {
+			return Check {
+				name,
+				status: Status::Pass,
+				message: "the VFS is disabled".to_owned(),
+			};
+		}
+		if !cfg!(any(target_os = "linux", target_os = "macos")) {
+			return Check {
+				name,
+				status: Status::Fail,
+				message: "the VFS is not supported on this operating system".to_owned(),
+			};
+		}
+
+		// Check if the artifacts directory is a mount point.
+		let path = self.doctor_path();
+		let artifacts_path = path.join("artifacts");
+		let artifacts = match std::fs::metadata(&artifacts_path) {
+			Ok(metadata) => metadata,
+			Err(error) if error.raw_os_error() == Some(libc::ENOTCONN) => {
+				return Check {
+					name,
+					status: Status::Fail,
+					message: format!(
+						"{} is a stale mount, restart the server with `tg server restart`",
+						artifacts_path.display()
+					),
+				};
+			},
+			Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+				return Check {
+					name,
+					status: Status::Warn,
+					message: format!(
+						"{} does not exist, the VFS is mounted when the server starts",
+						artifacts_path.display()
+					),
+				};
+			},
+			Err(error) => {
+				return Check {
+					name,
+					status: Status::Fail,
+					message: format!("failed to stat {}: {error}", artifacts_path.display()),
+				};
+			},
+		};
+		let parent = match std::fs::metadata(&path) {
+			Ok(metadata) => metadata,
+			Err(error) => {
+				return Check {
+					name,
+					status: Status::Fail,
+					message: format!("failed to stat {}: {error}", path.display()),
+				};
+			},
+		};
+		if artifacts.dev() == parent.dev() {
+			return Check {
+				name,
+				status: Status::Warn,
+				message: format!("the VFS is not mounted at {}", artifacts_path.display()),
+			};
+		}
+
+		Check {
+			name,
+			status: Status::Pass,
+			message: format!("the VFS is mounted at {}", artifacts_path.display()),
+		}
+	}
+
+	fn doctor_check_file_descriptor_limit() -> Check {
+		let name = "file descriptor limit";
+		let mut rlimit_nofile = libc::rlimit {
+			rlim_cur: 0,
+			rlim_max: 0,
+		};
+		let ret = unsafe { libc::getrlimit(libc::RLIMIT_NOFILE, &mut rlimit_nofile) };
+		if ret != 0 {
+			let error = std::io::Error::last_os_error();
+			return Check {
+				name,
+				status: Status::Fail,
+				message: format!("failed to get the file descriptor limit: {error}"),
+			};
+		}
+
+		// The server raises its soft limit to the hard limit, so only the hard limit matters.
+		let limit = rlimit_nofile.rlim_max;
+		if limit < MIN_FILE_DESCRIPTOR_LIMIT {
+			return Check {
+				name,
+				status: Status::Warn,
+				message: format!(
+					"the hard limit is {limit}, which is less than {MIN_FILE_DESCRIPTOR_LIMIT}"
+				),
+			};
+		}
+
+		Check {
+			name,
+			status: Status::Pass,
+			message: format!("the hard limit is {limit}"),
+		}
+	}
+
+	fn doctor_path(&self) -> PathBuf {
+		self.args
+			.path
+			.clone()
+			.or(self.config.as_ref().and_then(|config| config.path.clone()))
+			.unwrap_or_else(|| PathBuf::from(std::env::var("HOME").unwrap()).join(".tangram"))
+	}
+
+	fn doctor_error_message(error: &tg::Error) -> String {
+		let mut message = error.message.clone().unwrap_or_default();
+		let mut source = error.source.as_deref();
+		while let Some(error) = source {
+			if let Some(error_message) = &error.message {
+				message.push_str(": ");
+				message.push_str(error_message);
+			}
+			source = error.source.as_deref();
+		}
+		message
+	}
+}
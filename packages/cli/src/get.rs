@@ -10,6 +10,14 @@ pub struct Args {
 	#[arg(long)]
 	pub format: Option<crate::object::get::Format>,
 
+	/// Print bytes values in full instead of as a preview.
+	#[arg(long)]
+	pub full_bytes: bool,
+
+	/// If the reference resolves to this ID, then print `unchanged` instead of getting the item. This is useful when polling a tag.
+	#[arg(long)]
+	pub if_not: Option<tg::Id>,
+
 	#[arg(long)]
 	pub pretty: Option<bool>,
 
@@ -23,6 +31,37 @@ pub struct Args {
 impl Cli {
 	pub async fn command_get(&self, args: Args) -> tg::Result<()> {
 		let handle = self.handle().await?;
+
+		// If the reference is a tag, then get the tag unless it refers to the ID, so that the server does not send a tag that has not changed.
+		if let (Some(id), tg::reference::Item::Tag(pattern)) = (&args.if_not, args.reference.item())
+		{
+			let subpath = args
+				.reference
+				.options()
+				.and_then(|options| options.subpath.as_ref());
+			if subpath.is_none() {
+				let item = if let Ok(build) = tg::build::Id::try_from(id.clone()) {
+					Either::Left(build)
+				} else {
+					Either::Right(tg::object::Id::try_from(id.clone())?)
+				};
+				let unchanged = match &handle {
+					Either::Left(client) => matches!(
+						client.try_get_tag_if_not(pattern, &item).await?,
+						Some(tg::tag::get::Conditional::NotModified)
+					),
+					Either::Right(server) => server
+						.try_get_tag(pattern)
+						.await?
+						.is_some_and(|output| output.item == item),
+				};
+				if unchanged {
+					println!("unchanged");
+					return Ok(());
+				}
+			}
+		}
+
 		let referent = self.get_reference(&args.reference).await?;
 		eprintln!("{} item {}", "info".blue().bold(), referent.item);
 		if let Some(path) = &referent.path {
@@ -55,8 +94,17 @@ impl Cli {
 			Either::Left(build) => Either::Left(build.id().clone()),
 			Either::Right(object) => Either::Right(object.id(&handle).await?.clone()),
 		};
+
+		// If the item has not changed, then print the sentinel. This handles references that are not tags or that have a subpath.
+		if let Some(id) = &args.if_not {
+			if item.to_string() == id.to_string() {
+				println!("unchanged");
+				return Ok(());
+			}
+		}
 		let Args {
 			format,
+			full_bytes,
 			pretty,
 			recursive,
 			..
@@ -69,6 +117,7 @@ impl Cli {
 			Either::Right(object) => {
 				self.command_object_get(crate::object::get::Args {
 					format,
+					full_bytes,
 					object,
 					pretty,
 					recursive,
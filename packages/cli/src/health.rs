@@ -4,15 +4,349 @@ use tangram_client::{self as tg, Handle as _};
 /// Get the server's health.
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
-pub struct Args {}
+pub struct Args {
+	/// Check only the named subsystem, print nothing, and exit with a non-zero code if it is not healthy. This flag may be repeated, in which case all of the subsystems must be healthy. A subsystem is healthy if its status is `ok`.
+	///
+	/// This is intended for liveness and readiness probes. For example, a Kubernetes liveness probe may run `tg health --check database` with a `periodSeconds` of 10 and a `failureThreshold` of 3, so that a single slow check does not restart the server. A readiness probe may additionally check `file_descriptor_semaphore`, so that a server that is out of file descriptors stops receiving traffic without being restarted.
+	#[arg(long = "check", value_name = "SUBSYSTEM", action = clap::ArgAction::Append)]
+	pub checks: Vec<String>,
+
+	/// Print the health as JSON with a stable schema.
+	#[arg(long)]
+	pub json: bool,
+
+	#[arg(long)]
+	pub pretty: Option<bool>,
+
+	/// Include the server's object storage stats.
+	#[arg(long)]
+	pub verbose: bool,
+}
+
+/// The output of `tg health --json`. The field names are stable across releases. Fields may be added, but existing fields will not be renamed or removed without incrementing `version`.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Output {
+	/// The version of this schema.
+	pub version: u32,
+
+	/// The version of the server.
+	pub server_version: Option<String>,
+
+	/// Diagnostics about the server's health.
+	pub diagnostics: Vec<Diagnostic>,
+
+	/// The status of each of the server's subsystems.
+	pub subsystems: Subsystems,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Diagnostic {
+	pub severity: Severity,
+	pub subsystem: String,
+	pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+	Warning,
+	Error,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Subsystems {
+	pub builds: Subsystem<tg::health::Builds>,
+	pub database: Subsystem<tg::health::Database>,
+	pub file_descriptor_semaphore: Subsystem<tg::health::FileDescriptorSemaphore>,
+
+	/// The object storage stats, which are only present if `--verbose` is set.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub objects: Option<Subsystem<tg::health::Objects>>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Subsystem<T> {
+	pub status: Status,
+	pub details: Option<T>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+	Ok,
+	Degraded,
+	Unavailable,
+}
 
 impl Cli {
-	pub async fn command_health(&self, _args: Args) -> tg::Result<()> {
+	pub async fn command_health(&self, args: Args) -> tg::Result<()> {
 		let handle = self.handle().await?;
-		let health = handle.health().await?;
-		let health = serde_json::to_string_pretty(&health)
-			.map_err(|source| tg::error!(!source, "failed to serialize"))?;
-		println!("{health}");
+		let mut health = handle.health().await?;
+		if !args.checks.is_empty() {
+			let output = Output::new(&health);
+			return output.check(&args.checks);
+		}
+		if !args.verbose {
+			health.objects = None;
+		}
+		let output = Output::new(&health);
+		if args.json {
+			Self::output_json(&output, args.pretty).await?;
+		} else {
+			let health = serde_json::to_string_pretty(&health)
+				.map_err(|source| tg::error!(!source, "failed to serialize"))?;
+			println!("{health}");
+		}
+
+		// Exit with a non-zero code if any diagnostic is an error.
+		output.check_diagnostics()?;
+
+		Ok(())
+	}
+}
+
+impl Output {
+	pub const VERSION: u32 = 1;
+
+	#[must_use]
+	pub fn new(health: &tg::Health) -> Self {
+		let mut diagnostics = Vec::new();
+
+		// Add an error diagnostic for each of the server's failures.
+		for error in &health.errors {
+			diagnostics.push(Diagnostic {
+				severity: Severity::Error,
+				subsystem: error.subsystem.clone(),
+				message: error.message.clone(),
+			});
+		}
+
+		// Get the builds subsystem.
+		let builds = Subsystem::new(health.builds.clone(), Status::Ok);
+
+		// Get the database subsystem.
+		let status = match &health.database {
+			Some(database) if database.available_connections == 0 => {
+				diagnostics.push(Diagnostic {
+					severity: Severity::Warning,
+					subsystem: "database".to_owned(),
+					message: "the database has no available connections".to_owned(),
+				});
+				Status::Degraded
+			},
+			_ => Status::Ok,
+		};
+		let database = Subsystem::new(health.database.clone(), status);
+
+		// Get the file descriptor semaphore subsystem.
+		let status = match &health.file_descriptor_semaphore {
+			Some(semaphore) if semaphore.available_permits == 0 => {
+				diagnostics.push(Diagnostic {
+					severity: Severity::Warning,
+					subsystem: "file_descriptor_semaphore".to_owned(),
+					message: "the file descriptor semaphore has no available permits".to_owned(),
+				});
+				Status::Degraded
+			},
+			_ => Status::Ok,
+		};
+		let file_descriptor_semaphore =
+			Subsystem::new(health.file_descriptor_semaphore.clone(), status);
+
+		// Get the objects subsystem if it is present.
+		let objects = health
+			.objects
+			.clone()
+			.map(|objects| Subsystem::new(Some(objects), Status::Ok));
+
+		Self {
+			version: Self::VERSION,
+			server_version: health.version.clone(),
+			diagnostics,
+			subsystems: Subsystems {
+				builds,
+				database,
+				file_descriptor_semaphore,
+				objects,
+			},
+		}
+	}
+}
+
+impl Output {
+	/// Check that each of the named subsystems is healthy.
+	pub fn check(&self, names: &[String]) -> tg::Result<()> {
+		for name in names {
+			let status = self
+				.subsystems
+				.status(name)
+				.ok_or_else(|| tg::error!(%name, "unknown subsystem"))?;
+			if status != Status::Ok {
+				return Err(tg::error!(%name, ?status, "the subsystem is not healthy"));
+			}
+		}
 		Ok(())
 	}
+
+	/// Check that none of the diagnostics is an error.
+	pub fn check_diagnostics(&self) -> tg::Result<()> {
+		let count = self
+			.diagnostics
+			.iter()
+			.filter(|diagnostic| matches!(diagnostic.severity, Severity::Error))
+			.count();
+		if count > 0 {
+			return Err(tg::error!(%count, "the server has error diagnostics"));
+		}
+		Ok(())
+	}
+}
+
+impl Subsystems {
+	/// Get the status of the subsystem with the specified name.
+	#[must_use]
+	pub fn status(&self, name: &str) -> Option<Status> {
+		match name {
+			"builds" => Some(self.builds.status),
+			"database" => Some(self.database.status),
+			"file_descriptor_semaphore" => Some(self.file_descriptor_semaphore.status),
+			"objects" => self.objects.as_ref().map(|objects| objects.status),
+			_ => None,
+		}
+	}
+}
+
+impl<T> Subsystem<T> {
+	fn new(details: Option<T>, status: Status) -> Self {
+		let status = if details.is_some() {
+			status
+		} else {
+			Status::Unavailable
+		};
+		Self { status, details }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Diagnostic, Output, Severity, Status};
+	use insta::assert_json_snapshot;
+	use tangram_client as tg;
+
+	#[test]
+	fn schema() {
+		let health = tg::Health {
+			builds: Some(tg::health::Builds {
+				created: 1,
+				dequeued: 0,
+				started: 2,
+			}),
+			database: Some(tg::health::Database {
+				available_connections: 0,
+			}),
+			errors: Vec::new(),
+			file_descriptor_semaphore: None,
+			objects: None,
+			version: Some("0.0.0".to_owned()),
+		};
+		let output = Output::new(&health);
+		assert_json_snapshot!(output, @r#"
+  {
+    "version": 1,
+    "server_version": "0.0.0",
+    "diagnostics": [
+      {
+        "severity": "warning",
+        "subsystem": "database",
+        "message": "the database has no available connections"
+      }
+    ],
+    "subsystems": {
+      "builds": {
+        "status": "ok",
+        "details": {
+          "created": 1,
+          "dequeued": 0,
+          "started": 2
+        }
+      },
+      "database": {
+        "status": "degraded",
+        "details": {
+          "available_connections": 0
+        }
+      },
+      "file_descriptor_semaphore": {
+        "status": "unavailable",
+        "details": null
+      }
+    }
+  }
+  "#);
+	}
+
+	#[test]
+	fn check() {
+		let health = tg::Health {
+			builds: None,
+			database: Some(tg::health::Database {
+				available_connections: 1,
+			}),
+			errors: Vec::new(),
+			file_descriptor_semaphore: Some(tg::health::FileDescriptorSemaphore {
+				available_permits: 0,
+			}),
+			objects: None,
+			version: None,
+		};
+		let output = Output::new(&health);
+		assert!(output.check(&["database".to_owned()]).is_ok());
+		assert!(output.check(&["builds".to_owned()]).is_err());
+		assert!(output
+			.check(&[
+				"database".to_owned(),
+				"file_descriptor_semaphore".to_owned()
+			])
+			.is_err());
+		assert!(output.check(&["unknown".to_owned()]).is_err());
+	}
+
+	#[test]
+	fn check_diagnostics() {
+		let health = tg::Health {
+			builds: None,
+			database: Some(tg::health::Database {
+				available_connections: 0,
+			}),
+			errors: Vec::new(),
+			file_descriptor_semaphore: None,
+			objects: None,
+			version: None,
+		};
+		let output = Output::new(&health);
+		assert!(output.check_diagnostics().is_ok());
+
+		let health = tg::Health {
+			builds: None,
+			database: None,
+			errors: vec![tg::health::Error {
+				subsystem: "database".to_owned(),
+				message: "failed to get database connection".to_owned(),
+			}],
+			file_descriptor_semaphore: None,
+			objects: None,
+			version: None,
+		};
+		let output = Output::new(&health);
+		assert!(matches!(
+			output.diagnostics.as_slice(),
+			[Diagnostic {
+				severity: Severity::Error,
+				subsystem,
+				..
+			}] if subsystem == "database"
+		));
+		assert_eq!(output.subsystems.database.status, Status::Unavailable);
+		assert!(output.check_diagnostics().is_err());
+	}
 }
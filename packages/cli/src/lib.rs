@@ -3,7 +3,13 @@ use clap::{CommandFactory as _, Parser as _};
 use crossterm::{style::Stylize as _, tty::IsTty as _};
 use futures::FutureExt as _;
 use num::ToPrimitive as _;
-use std::{fmt::Write as _, path::PathBuf, sync::Mutex, time::Duration};
+use std::{
+	collections::HashMap,
+	fmt::Write as _,
+	path::{Path, PathBuf},
+	sync::Mutex,
+	time::Duration,
+};
 use tangram_client::{self as tg, Client};
 use tangram_either::Either;
 use tangram_server::Server;
@@ -18,6 +24,7 @@ mod cat;
 mod checksum;
 mod clean;
 mod config;
+mod doctor;
 mod get;
 mod health;
 mod lsp;
@@ -121,6 +128,10 @@ enum Command {
 
 	Clean(self::clean::Args),
 
+	Config(self::config::Args),
+
+	Doctor(self::doctor::Args),
+
 	#[command(alias = "doc")]
 	Document(self::package::document::Args),
 
@@ -190,6 +201,10 @@ impl Cli {
 		// Read the config.
 		let config = match Cli::read_config(args.config.clone()) {
 			Ok(config) => config,
+
+			// If the command is `tg doctor`, then report the invalid config instead of exiting.
+			Err(_) if matches!(args.command, Command::Doctor(_)) => None,
+
 			Err(error) => {
 				eprintln!("{} failed to read the config", "error".red().bold());
 				Cli::print_error(&error, None);
@@ -423,6 +438,19 @@ impl Cli {
 	}
 
 	async fn server(&self) -> tg::Result<Server> {
+		// Get the config.
+		let config = self.server_config();
+
+		// Start the server.
+		let server = tangram_server::Server::start(config)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to start the server"))?;
+
+		Ok(server)
+	}
+
+	/// Create the server's config by merging the config file and args into the defaults.
+	fn server_config(&self) -> tangram_server::Config {
 		// Get the path.
 		let path = self
 			.args
@@ -484,6 +512,9 @@ impl Cli {
 			if let Some(preserve_temp_directories) = advanced.preserve_temp_directories {
 				config.advanced.preserve_temp_directories = preserve_temp_directories;
 			}
+			if let Some(tag_batch_size) = advanced.tag_batch_size {
+				config.advanced.tag_batch_size = tag_batch_size;
+			}
 			if let Some(write_blobs_to_blobs_directory) = advanced.write_blobs_to_blobs_directory {
 				config.advanced.write_blobs_to_blobs_directory = write_blobs_to_blobs_directory;
 			}
@@ -518,6 +549,7 @@ impl Cli {
 						});
 					}
 				}
+				authentication_.token_ttl = authentication.token_ttl;
 				config.authentication = Some(authentication_);
 			},
 		}
@@ -542,6 +574,17 @@ impl Cli {
 				if let Some(remotes) = build.remotes.clone() {
 					build_.remotes = remotes;
 				}
+				if let Some(runtimes) = build.runtimes.clone() {
+					build_.runtimes = runtimes
+						.into_iter()
+						.map(|(host, runtime)| {
+							let runtime = tangram_server::config::Runtime {
+								concurrency: runtime.concurrency,
+							};
+							(host, runtime)
+						})
+						.collect();
+				}
 				config.build = Some(build_);
 			},
 		}
@@ -565,6 +608,9 @@ impl Cli {
 				if let Some(limit) = build_heartbeat_monitor.limit {
 					build_heartbeat_monitor_.limit = limit;
 				}
+				if let Some(max_missed_heartbeats) = build_heartbeat_monitor.max_missed_heartbeats {
+					build_heartbeat_monitor_.max_missed_heartbeats = Some(max_missed_heartbeats);
+				}
 				if let Some(timeout) = build_heartbeat_monitor.timeout {
 					build_heartbeat_monitor_.timeout = timeout;
 				}
@@ -604,6 +650,12 @@ impl Cli {
 					if let Some(path) = database.path {
 						database_.path = path;
 					}
+					if let Some(statement_cache_size) = database.statement_cache_size {
+						database_.statement_cache_size = statement_cache_size;
+					}
+					if let Some(timeout) = database.timeout {
+						database_.timeout = Some(timeout);
+					}
 					tangram_server::config::Database::Sqlite(database_)
 				},
 				self::config::Database::Postgres(database) => {
@@ -611,6 +663,15 @@ impl Cli {
 					if let Some(connections) = database.connections {
 						database_.connections = connections;
 					}
+					if let Some(read_url) = database.read_url {
+						database_.read_url = Some(read_url);
+					}
+					if let Some(statement_cache_size) = database.statement_cache_size {
+						database_.statement_cache_size = statement_cache_size;
+					}
+					if let Some(timeout) = database.timeout {
+						database_.timeout = Some(timeout);
+					}
 					if let Some(url) = database.url {
 						database_.url = url;
 					}
@@ -676,16 +737,14 @@ impl Cli {
 				if let Some(database_connections) = vfs.database_connections {
 					vfs_.database_connections = database_connections;
 				}
+				if let Some(read_only) = vfs.read_only {
+					vfs_.read_only = read_only;
+				}
 				config.vfs = Some(vfs_);
 			},
 		}
 
-		// Start the server.
-		let server = tangram_server::Server::start(config)
-			.await
-			.map_err(|source| tg::error!(!source, "failed to start the server"))?;
-
-		Ok(server)
+		config
 	}
 
 	/// Start the server.
@@ -812,6 +871,8 @@ impl Cli {
 			Command::Checkout(args) => self.command_artifact_checkout(args).boxed(),
 			Command::Checksum(args) => self.command_checksum(args).boxed(),
 			Command::Clean(args) => self.command_clean(args).boxed(),
+			Command::Config(args) => self.command_config(args).boxed(),
+			Command::Doctor(args) => self.command_doctor(args).boxed(),
 			Command::Document(args) => self.command_package_document(args).boxed(),
 			Command::Download(args) => self.command_blob_download(args).boxed(),
 			Command::Export(args) => self.command_object_export(args).boxed(),
@@ -844,10 +905,14 @@ impl Cli {
 		.await
 	}
 
-	fn read_config(path: Option<PathBuf>) -> tg::Result<Option<Config>> {
-		let path = path.unwrap_or_else(|| {
+	fn config_path(path: Option<PathBuf>) -> PathBuf {
+		path.unwrap_or_else(|| {
 			PathBuf::from(std::env::var("HOME").unwrap()).join(".config/tangram/config.json")
-		});
+		})
+	}
+
+	fn read_config(path: Option<PathBuf>) -> tg::Result<Option<Config>> {
+		let path = Self::config_path(path);
 		let config = match std::fs::read_to_string(&path) {
 			Ok(config) => config,
 			Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
@@ -863,14 +928,18 @@ impl Cli {
 		Ok(Some(config))
 	}
 
-	fn _write_config(config: &Config, path: Option<PathBuf>) -> tg::Result<()> {
-		let path = path.unwrap_or_else(|| {
-			PathBuf::from(std::env::var("HOME").unwrap()).join(".config/tangram/config.json")
-		});
+	fn write_config(config: &Config, path: Option<PathBuf>) -> tg::Result<()> {
+		let path = Self::config_path(path);
+		if let Some(parent) = path.parent() {
+			std::fs::create_dir_all(parent).map_err(
+				|source| tg::error!(!source, %path = parent.display(), "failed to create the config directory"),
+			)?;
+		}
 		let config = serde_json::to_string_pretty(&config)
 			.map_err(|source| tg::error!(!source, "failed to serialize the config"))?;
-		std::fs::write(path, config)
-			.map_err(|source| tg::error!(!source, "failed to save the config"))?;
+		std::fs::write(&path, config).map_err(
+			|source| tg::error!(!source, %path = path.display(), "failed to save the config"),
+		)?;
 		Ok(())
 	}
 
@@ -981,6 +1050,67 @@ impl Cli {
 		reference: &tg::Reference,
 	) -> tg::Result<tg::Referent<Either<tg::Build, tg::Object>>> {
 		let handle = self.handle().await?;
+		let reference = Self::absolute_reference(reference)?;
+		let referent = reference.get(&handle).await?;
+		Ok(referent)
+	}
+
+	/// Get multiple references. Equivalent references are only resolved once.
+	async fn get_references(
+		&self,
+		references: &[tg::Reference],
+	) -> tg::Result<Vec<tg::Referent<Either<tg::Build, tg::Object>>>> {
+		let handle = self.handle().await?;
+		let mut referents = HashMap::new();
+		let mut output = Vec::with_capacity(references.len());
+		for reference in references {
+			let reference = Self::absolute_reference(reference)?;
+			let key = Self::canonical_reference(&reference).await.normalized();
+			if !referents.contains_key(&key) {
+				let referent = reference.get(&handle).await?;
+				referents.insert(key.clone(), referent);
+			}
+			output.push(referents[&key].clone());
+		}
+		Ok(output)
+	}
+
+	/// Resolve the symlinks and `..` components in the parents of a reference's paths against the file system, so that references to the same path compare equal. The last component of each path is kept, so that a reference to a symlink is not merged with a reference to its target. A path that cannot be resolved is left unchanged.
+	async fn canonical_reference(reference: &tg::Reference) -> tg::Reference {
+		let mut item = reference.item().clone();
+		let mut options = reference.options().cloned();
+		if let tg::reference::Item::Path(path) = &mut item {
+			if let Some(canonical) = Self::canonicalize_parent(path).await {
+				*path = canonical;
+			}
+		}
+		if let Some(path) = options.as_mut().and_then(|options| options.path.as_mut()) {
+			if let Some(canonical) = Self::canonicalize_parent(path).await {
+				*path = canonical;
+			}
+		}
+		tg::Reference::with_item_and_options(&item, options.as_ref())
+	}
+
+	/// Canonicalize an absolute path's parent and append its last component.
+	async fn canonicalize_parent(path: &Path) -> Option<PathBuf> {
+		let parent = path.parent()?;
+		let mut canonical = tokio::fs::canonicalize(parent).await.ok()?;
+		match path.components().last()? {
+			std::path::Component::Prefix(_) | std::path::Component::RootDir => return None,
+			std::path::Component::CurDir => (),
+			std::path::Component::ParentDir => {
+				canonical.pop();
+			},
+			std::path::Component::Normal(component) => {
+				canonical.push(component);
+			},
+		}
+		Some(canonical)
+	}
+
+	/// Make the paths in a reference absolute.
+	fn absolute_reference(reference: &tg::Reference) -> tg::Result<tg::Reference> {
 		let mut item = reference.item().clone();
 		let mut options = reference.options().cloned();
 		if let tg::reference::Item::Path(path) = &mut item {
@@ -992,8 +1122,7 @@ impl Cli {
 				.map_err(|source| tg::error!(!source, "failed to get the absolute path"))?;
 		}
 		let reference = tg::Reference::with_item_and_options(&item, options.as_ref());
-		let referent = reference.get(&handle).await?;
-		Ok(referent)
+		Ok(reference)
 	}
 
 	/// Initialize V8.
@@ -2,12 +2,17 @@ use crate::Cli;
 use tangram_client as tg;
 
 pub mod export;
+pub mod find;
 pub mod get;
+pub mod graph;
 pub mod import;
 pub mod metadata;
+pub mod prune_references;
 pub mod pull;
 pub mod push;
 pub mod put;
+pub mod reencode;
+pub mod show;
 
 /// Manage objects.
 #[derive(Clone, Debug, clap::Args)]
@@ -20,12 +25,17 @@ pub struct Args {
 #[derive(Clone, Debug, clap::Subcommand)]
 pub enum Command {
 	Export(self::export::Args),
+	Find(self::find::Args),
 	Get(self::get::Args),
+	Graph(self::graph::Args),
 	Import(self::import::Args),
 	Metadata(self::metadata::Args),
+	PruneReferences(self::prune_references::Args),
 	Pull(self::pull::Args),
 	Push(self::push::Args),
 	Put(self::put::Args),
+	Reencode(self::reencode::Args),
+	Show(self::show::Args),
 }
 
 impl Cli {
@@ -34,15 +44,24 @@ impl Cli {
 			Command::Export(args) => {
 				self.command_object_export(args).await?;
 			},
+			Command::Find(args) => {
+				self.command_object_find(args).await?;
+			},
 			Command::Get(args) => {
 				self.command_object_get(args).await?;
 			},
+			Command::Graph(args) => {
+				self.command_object_graph(args).await?;
+			},
 			Command::Import(args) => {
 				self.command_object_import(args).await?;
 			},
 			Command::Metadata(args) => {
 				self.command_object_metadata(args).await?;
 			},
+			Command::PruneReferences(args) => {
+				self.command_object_prune_references(args).await?;
+			},
 			Command::Pull(args) => {
 				self.command_object_pull(args).await?;
 			},
@@ -52,6 +71,12 @@ impl Cli {
 			Command::Put(args) => {
 				self.command_object_put(args).await?;
 			},
+			Command::Reencode(args) => {
+				self.command_object_reencode(args).await?;
+			},
+			Command::Show(args) => {
+				self.command_object_show(args).await?;
+			},
 		}
 		Ok(())
 	}
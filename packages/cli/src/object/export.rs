@@ -1,13 +1,25 @@
 use crate::Cli;
-use std::{path::PathBuf, pin::Pin};
+use crossterm::style::Stylize as _;
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	path::{Path, PathBuf},
+	pin::Pin,
+};
 use tangram_client::{self as tg, Handle as _};
 use tangram_either::Either;
-use tokio::io::AsyncWrite;
+use tokio::io::{AsyncWrite, AsyncWriteExt as _};
+
+/// The name of the file that maps tags to objects in a tag export.
+pub const TAGS_FILE_NAME: &str = "tags.json";
+
+/// The name of the archive that contains the objects in a tag export.
+pub const ARCHIVE_FILE_NAME: &str = "objects.tgar";
 
 /// Export an object.
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
 pub struct Args {
+	/// The path to write the export to. If `--tag` is set, this is a directory that will contain an archive of the tagged objects and a tags file. It can be imported with `tg object import`.
 	#[arg(index = 2)]
 	pub output: Option<PathBuf>,
 
@@ -17,6 +29,10 @@ pub struct Args {
 	#[allow(clippy::option_option)]
 	#[arg(short, long)]
 	pub remote: Option<Option<String>>,
+
+	/// Export the objects of all tags matching this pattern, along with the tags.
+	#[arg(long, conflicts_with = "reference", requires = "output")]
+	pub tag: Option<tg::tag::Pattern>,
 }
 
 impl Cli {
@@ -35,6 +51,14 @@ impl Cli {
 			.clone()
 			.map(|remote| remote.unwrap_or_else(|| "default".to_owned()));
 
+		// If a tag pattern is set, then export the matching tags.
+		if let Some(pattern) = args.tag {
+			let path = args.output.unwrap();
+			return self
+				.command_object_export_tags(pattern, &path, remote)
+				.await;
+		}
+
 		// Get the reference.
 		let referent = self.get_reference(&reference).await?;
 		let Either::Right(object) = referent.item else {
@@ -64,7 +88,10 @@ impl Cli {
 		};
 
 		// Create the stream.
-		let arg = tg::object::export::Arg { remote };
+		let arg = tg::object::export::Arg {
+			objects: Vec::new(),
+			remote,
+		};
 		let mut reader = handle.export_object(&id, arg).await?;
 
 		// Copy the reader to the writer.
@@ -74,4 +101,71 @@ impl Cli {
 
 		Ok(())
 	}
+
+	async fn command_object_export_tags(
+		&self,
+		pattern: tg::tag::Pattern,
+		path: &Path,
+		remote: Option<String>,
+	) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Get the matching tags. Tags that refer to builds are skipped.
+		let arg = tg::tag::list::Arg {
+			length: None,
+			pattern,
+			remote: remote.clone(),
+			reverse: false,
+			sort: None,
+		};
+		let output = handle.list_tags(arg).await?;
+		let tags = output
+			.data
+			.into_iter()
+			.filter_map(|output| Some((output.tag.to_string(), output.item.right()?)))
+			.collect::<BTreeMap<_, _>>();
+
+		// Create the directory.
+		tokio::fs::create_dir_all(path).await.map_err(
+			|source| tg::error!(!source, %path = path.display(), "failed to create the directory"),
+		)?;
+
+		// Export the objects to a single archive, so that an object that is in the closure of more than one tagged object is written once.
+		let mut objects = tags.values().cloned().collect::<BTreeSet<_>>().into_iter();
+		let mut size = 0;
+		if let Some(first) = objects.next() {
+			let archive = path.join(ARCHIVE_FILE_NAME);
+			let mut writer = tokio::fs::File::create(&archive).await.map_err(
+				|source| tg::error!(!source, %path = archive.display(), "failed to create the file"),
+			)?;
+			let arg = tg::object::export::Arg {
+				objects: objects.collect(),
+				remote: remote.clone(),
+			};
+			let mut reader = handle.export_object(&first, arg).await?;
+			size = tokio::io::copy(&mut reader, &mut writer)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to copy the reader to the writer"))?;
+			writer
+				.flush()
+				.await
+				.map_err(|source| tg::error!(!source, "failed to flush the file"))?;
+		}
+
+		// Write the tags.
+		let bytes = serde_json::to_vec_pretty(&tags)
+			.map_err(|source| tg::error!(!source, "failed to serialize the tags"))?;
+		tokio::fs::write(path.join(TAGS_FILE_NAME), bytes)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to write the tags"))?;
+
+		// Print the summary. The number of objects is reported when the export is imported.
+		eprintln!(
+			"{} exported {} tags to a {size} byte archive",
+			"info".blue().bold(),
+			tags.len(),
+		);
+
+		Ok(())
+	}
 }
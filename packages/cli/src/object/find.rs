@@ -0,0 +1,46 @@
+use crate::Cli;
+use tangram_client::{self as tg, Handle as _};
+
+/// Find objects by kind and size. This searches the server's database, not remotes.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// Only find objects of this kind.
+	#[arg(long)]
+	pub kind: Option<tg::object::Kind>,
+
+	/// The maximum number of objects to find.
+	#[arg(long)]
+	pub length: Option<u64>,
+
+	/// Only find objects whose serialized size is at most this many bytes.
+	#[arg(long)]
+	pub max_size: Option<u64>,
+
+	/// Only find objects whose serialized size is at least this many bytes.
+	#[arg(long)]
+	pub min_size: Option<u64>,
+
+	/// The number of objects to skip.
+	#[arg(long)]
+	pub position: Option<u64>,
+
+	#[arg(long)]
+	pub pretty: Option<bool>,
+}
+
+impl Cli {
+	pub async fn command_object_find(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+		let arg = tg::object::find::Arg {
+			kind: args.kind,
+			length: args.length,
+			max_size: args.max_size,
+			min_size: args.min_size,
+			position: args.position,
+		};
+		let output = handle.find_objects(arg).await?;
+		Self::output_json(&output, args.pretty).await?;
+		Ok(())
+	}
+}
@@ -10,6 +10,10 @@ pub struct Args {
 	#[arg(long)]
 	pub format: Option<Format>,
 
+	/// Print bytes values in full instead of as a preview.
+	#[arg(long)]
+	pub full_bytes: bool,
+
 	#[arg(index = 1)]
 	pub object: tg::object::Id,
 
@@ -81,7 +85,12 @@ impl Cli {
 				} else {
 					tg::value::print::Style::Compact
 				};
-				let options = tg::value::print::Options { recursive, style };
+				let preview_bytes = if args.full_bytes { None } else { Some(16) };
+				let options = tg::value::print::Options {
+					preview_bytes,
+					recursive,
+					style,
+				};
 				let object = tg::Object::with_id(args.object);
 				if recursive {
 					object.load_recursive(&handle).await?;
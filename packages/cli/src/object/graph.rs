@@ -0,0 +1,36 @@
+use crate::Cli;
+use tangram_client as tg;
+
+/// Collapse an artifact and every artifact it references into a single graph.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	#[arg(index = 1)]
+	pub artifact: tg::artifact::Id,
+}
+
+impl Cli {
+	pub async fn command_object_graph(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Collapse the artifact.
+		let artifact = tg::Artifact::with_id(args.artifact.clone());
+		let (graph, node) = tg::Graph::with_artifact(&handle, &artifact).await.map_err(
+			|source| tg::error!(!source, %id = args.artifact, "failed to collapse the artifact"),
+		)?;
+
+		// Create the artifact for the graph's node.
+		let object = graph.object(&handle).await?;
+		let artifact: tg::Artifact = match object.nodes[node].kind() {
+			tg::artifact::Kind::Directory => tg::Directory::with_graph_and_node(graph, node).into(),
+			tg::artifact::Kind::File => tg::File::with_graph_and_node(graph, node).into(),
+			tg::artifact::Kind::Symlink => tg::Symlink::with_graph_and_node(graph, node).into(),
+		};
+		let id = artifact.id(&handle).await?;
+
+		// Print the artifact.
+		println!("{id}");
+
+		Ok(())
+	}
+}
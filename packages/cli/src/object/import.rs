@@ -1,6 +1,11 @@
 use crate::Cli;
-use std::path::PathBuf;
+use crossterm::style::Stylize as _;
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+};
 use tangram_client as tg;
+use tangram_either::Either;
 use tangram_futures::read::Ext as _;
 use tg::Handle;
 
@@ -8,12 +13,21 @@ use tg::Handle;
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
 pub struct Args {
+	/// Overwrite tags that already exist when importing a directory of tags.
+	#[arg(short, long)]
+	pub force: bool,
+
+	/// The path to import. If this is a directory created by `tg object export --tag`, then its objects and tags are imported.
 	#[arg(index = 1)]
 	pub input: Option<PathBuf>,
 
 	#[allow(clippy::option_option)]
 	#[arg(short, long)]
 	pub remote: Option<Option<String>>,
+
+	/// Rewrite the prefix of imported tags, as `<from>=<to>`. If several are given, then they are applied in order, each to the result of the ones before it. Tags that match none are imported unchanged.
+	#[arg(long = "remap-tags", action = clap::ArgAction::Append)]
+	pub remap_tags: Vec<String>,
 }
 
 impl Cli {
@@ -26,6 +40,35 @@ impl Cli {
 			.clone()
 			.map(|remote| remote.unwrap_or_else(|| "default".to_owned()));
 
+		// Parse the tag remaps.
+		let remaps = args
+			.remap_tags
+			.iter()
+			.map(|remap| {
+				let (from, to) = remap.split_once('=').ok_or_else(
+					|| tg::error!(%remap, "expected a remap of the form <from>=<to>"),
+				)?;
+				Ok::<_, tg::Error>((from.to_owned(), to.to_owned()))
+			})
+			.collect::<tg::Result<Vec<_>>>()?;
+
+		// If the input is a directory, then import the tags it contains.
+		if let Some(path) = &args.input {
+			if tokio::fs::metadata(path)
+				.await
+				.is_ok_and(|metadata| metadata.is_dir())
+			{
+				return self
+					.command_object_import_tags(path, remote, &remaps, args.force)
+					.await;
+			}
+		}
+		if !remaps.is_empty() {
+			return Err(tg::error!(
+				"tags can only be remapped when importing a directory of tags"
+			));
+		}
+
 		// Create the reader.
 		let reader = if let Some(path) = &args.input {
 			tokio::fs::File::open(path)
@@ -50,4 +93,104 @@ impl Cli {
 
 		Ok(())
 	}
+
+	async fn command_object_import_tags(
+		&self,
+		path: &Path,
+		remote: Option<String>,
+		remaps: &[(String, String)],
+		force: bool,
+	) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Read the tags.
+		let tags_path = path.join(crate::object::export::TAGS_FILE_NAME);
+		let bytes = tokio::fs::read(&tags_path).await.map_err(
+			|source| tg::error!(!source, %path = tags_path.display(), "failed to read the tags"),
+		)?;
+		let tags = serde_json::from_slice::<BTreeMap<String, tg::object::Id>>(&bytes)
+			.map_err(|source| tg::error!(!source, "failed to deserialize the tags"))?;
+
+		// Import the objects.
+		let archive = path.join(crate::object::export::ARCHIVE_FILE_NAME);
+		let exists = tokio::fs::try_exists(&archive).await.map_err(
+			|source| tg::error!(!source, %path = archive.display(), "failed to stat the archive"),
+		)?;
+		let mut count = 0;
+		if exists {
+			let reader = tokio::fs::File::open(&archive)
+				.await
+				.map_err(
+					|source| tg::error!(!source, %path = archive.display(), "failed to open the file"),
+				)?
+				.boxed();
+			let arg = tg::object::import::Arg {
+				remote: remote.clone(),
+			};
+			let stream = handle.import_object(arg, reader).await?;
+			let output = self.render_progress_stream(stream).await?;
+			count = output.count;
+		}
+
+		// Put the tags.
+		let mut remapped = 0;
+		for (tag, id) in &tags {
+			let tag = if let Some(remapped_tag) = remap_tag(tag, remaps) {
+				remapped += 1;
+				remapped_tag.parse::<tg::Tag>().map_err(
+					|source| tg::error!(!source, %tag, %remapped_tag, "the remapped tag is invalid"),
+				)?
+			} else {
+				tag.parse::<tg::Tag>()?
+			};
+			let arg = tg::tag::put::Arg {
+				force,
+				item: Either::Right(id.clone()),
+				remote: remote.clone(),
+			};
+			handle.put_tag(&tag, arg).await?;
+		}
+
+		// Print the summary.
+		eprintln!(
+			"{} imported {} tags and {count} objects",
+			"info".blue().bold(),
+			tags.len(),
+		);
+		if !remaps.is_empty() {
+			eprintln!("{} remapped {remapped} tags", "info".blue().bold());
+		}
+
+		Ok(())
+	}
+}
+
+/// Apply each remap whose prefix matches to the result of the ones before it. Return `None` if no remap matches.
+fn remap_tag(tag: &str, remaps: &[(String, String)]) -> Option<String> {
+	let mut output = None::<String>;
+	for (from, to) in remaps {
+		let current = output.as_deref().unwrap_or(tag);
+		if let Some(rest) = current.strip_prefix(from.as_str()) {
+			output = Some(format!("{to}{rest}"));
+		}
+	}
+	output
+}
+
+#[cfg(test)]
+mod tests {
+	use super::remap_tag;
+
+	#[test]
+	fn remap() {
+		let remaps = vec![
+			("a/".to_owned(), "b/".to_owned()),
+			("b/".to_owned(), "c/".to_owned()),
+			("x/".to_owned(), "y/".to_owned()),
+		];
+		assert_eq!(remap_tag("a/1", &remaps).as_deref(), Some("c/1"));
+		assert_eq!(remap_tag("b/1", &remaps).as_deref(), Some("c/1"));
+		assert_eq!(remap_tag("x/1", &remaps).as_deref(), Some("y/1"));
+		assert_eq!(remap_tag("z/1", &remaps), None);
+	}
 }
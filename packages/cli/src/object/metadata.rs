@@ -1,6 +1,9 @@
 use crate::Cli;
+use futures::{stream::FuturesUnordered, TryStreamExt as _};
+use std::collections::{BTreeMap, BTreeSet};
 use tangram_client::{self as tg, handle::Ext as _};
-/// Get an object.
+
+/// Get an object's metadata.
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
 pub struct Args {
@@ -9,15 +12,60 @@ pub struct Args {
 
 	#[arg(long)]
 	pub pretty: Option<bool>,
+
+	/// Get the metadata of the object and all of its descendants, keyed by ID.
+	#[arg(long)]
+	pub recursive: bool,
 }
 
+/// The number of objects to request at a time when getting metadata recursively.
+const BATCH_SIZE: usize = 64;
+
 impl Cli {
 	pub async fn command_object_metadata(&self, args: Args) -> tg::Result<()> {
 		let handle = self.handle().await?;
-		let metadata = handle.get_object_metadata(&args.object).await.map_err(
-			|source| tg::error!(!source, %id = args.object, "failed to get the object metadata"),
-		)?;
-		Self::output_json(&metadata, args.pretty).await?;
+
+		// If the recursive flag is not set, then print the object's metadata.
+		if !args.recursive {
+			let metadata = handle.get_object_metadata(&args.object).await.map_err(
+				|source| tg::error!(!source, %id = args.object, "failed to get the object metadata"),
+			)?;
+			Self::output_json(&metadata, args.pretty).await?;
+			return Ok(());
+		}
+
+		// Get the metadata of the object and its descendants in batches.
+		let mut output = BTreeMap::new();
+		let mut visited = BTreeSet::from([args.object.clone()]);
+		let mut queue = vec![args.object.clone()];
+		while !queue.is_empty() {
+			let batch = queue.split_off(queue.len().saturating_sub(BATCH_SIZE));
+			let outputs = batch
+				.into_iter()
+				.map(|id| {
+					let handle = handle.clone();
+					async move {
+						let output = handle.get_object(&id).await.map_err(
+							|source| tg::error!(!source, %id, "failed to get the object"),
+						)?;
+						Ok::<_, tg::Error>((id, output))
+					}
+				})
+				.collect::<FuturesUnordered<_>>()
+				.try_collect::<Vec<_>>()
+				.await?;
+			for (id, tg::object::get::Output { bytes, metadata }) in outputs {
+				let data = tg::object::Data::deserialize(id.kind(), &bytes)?;
+				for child in data.children() {
+					if visited.insert(child.clone()) {
+						queue.push(child);
+					}
+				}
+				output.insert(id, metadata);
+			}
+		}
+		Self::output_json(&output, args.pretty).await?;
+
 		Ok(())
 	}
 }
@@ -0,0 +1,77 @@
+use crate::Cli;
+use tangram_client::{self as tg, Handle as _};
+
+/// Create a new file with some of its dependencies removed.
+///
+/// By default, the dependencies whose objects cannot be found are removed. The file's contents are not changed, but because its dependencies are part of its data, the new file has a different ID.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// Remove the dependencies with these references.
+	#[arg(long, num_args = 1.., conflicts_with = "keep")]
+	pub drop: Vec<tg::Reference>,
+
+	/// The file.
+	#[arg(index = 1)]
+	pub file: tg::file::Id,
+
+	/// Remove every dependency except the ones with these references.
+	#[arg(long, num_args = 1..)]
+	pub keep: Vec<tg::Reference>,
+}
+
+impl Cli {
+	pub async fn command_object_prune_references(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Get the file.
+		let file = tg::File::with_id(args.file);
+		let object = file.object(&handle).await?;
+		let tg::file::Object::Normal {
+			contents,
+			dependencies,
+			executable,
+		} = object.as_ref()
+		else {
+			return Err(tg::error!(
+				"expected a normal file, the dependencies of a file in a graph are stored in the graph"
+			));
+		};
+
+		// Filter the dependencies.
+		let mut dependencies_ = dependencies.clone();
+		if !args.keep.is_empty() {
+			dependencies_.retain(|reference, _| args.keep.contains(reference));
+		} else if !args.drop.is_empty() {
+			dependencies_.retain(|reference, _| !args.drop.contains(reference));
+		} else {
+			for (reference, referent) in dependencies {
+				let id = referent.item.id(&handle).await?;
+				if handle.try_get_object(&id).await?.is_none() {
+					dependencies_.remove(reference);
+				}
+			}
+		}
+
+		// Print the removed dependencies.
+		for reference in dependencies.keys() {
+			if !dependencies_.contains_key(reference) {
+				eprintln!("removed {reference}");
+			}
+		}
+
+		// Create the new file.
+		let object = tg::file::Object::Normal {
+			contents: contents.clone(),
+			dependencies: dependencies_,
+			executable: *executable,
+		};
+		let file = tg::File::with_object(object);
+		let id = file.store(&handle).await?;
+
+		// Print the ID.
+		println!("{id}");
+
+		Ok(())
+	}
+}
@@ -10,6 +10,10 @@ pub struct Args {
 
 	#[arg(short, long)]
 	pub remote: Option<String>,
+
+	/// Show the progress of each object in addition to the totals.
+	#[arg(long)]
+	pub verbose: bool,
 }
 
 impl Cli {
@@ -20,7 +24,10 @@ impl Cli {
 		let remote = args.remote.unwrap_or_else(|| "default".to_owned());
 
 		// Push the object.
-		let arg = tg::object::push::Arg { remote };
+		let arg = tg::object::push::Arg {
+			remote,
+			verbose: args.verbose,
+		};
 		let stream = handle.push_object(&args.object, arg).await?;
 		self.render_progress_stream(stream).await?;
 
@@ -0,0 +1,82 @@
+use crate::Cli;
+use crossterm::style::Stylize as _;
+use std::collections::{BTreeSet, VecDeque};
+use tangram_client::{self as tg, Handle as _};
+
+/// Re-encode objects with the current serialization.
+///
+/// An object's ID is the hash of its serialized bytes, so an object whose serialization changes would get a new ID and the objects that refer to it would be left pointing at the old one. Such objects are reported as `<old> -> <new>` and are not rewritten, and the command fails unless `--dry-run` is set.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// Print the objects that would change without failing.
+	#[arg(long)]
+	pub dry_run: bool,
+
+	/// The object to re-encode.
+	#[arg(index = 1)]
+	pub object: tg::object::Id,
+
+	/// Re-encode the object's children recursively.
+	#[arg(long)]
+	pub recursive: bool,
+}
+
+impl Cli {
+	pub async fn command_object_reencode(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Re-encode the objects, reporting each one whose serialization changes.
+		let mut count = 0;
+		let mut visited = BTreeSet::new();
+		let mut queue = VecDeque::from([args.object]);
+		while let Some(id) = queue.pop_front() {
+			if !visited.insert(id.clone()) {
+				continue;
+			}
+
+			// Get the object.
+			let output = handle
+				.get_object(&id)
+				.await
+				.map_err(|source| tg::error!(!source, %id, "failed to get the object"))?;
+
+			// Re-encode the object.
+			let data = tg::object::Data::deserialize(id.kind(), &output.bytes)
+				.map_err(|source| tg::error!(!source, %id, "failed to deserialize the object"))?;
+			let bytes = data
+				.serialize()
+				.map_err(|source| tg::error!(!source, %id, "failed to serialize the object"))?;
+
+			// Enqueue the object's children if requested.
+			if args.recursive {
+				queue.extend(data.children());
+			}
+
+			// Skip the object if its serialization is unchanged.
+			if bytes == output.bytes {
+				if args.dry_run {
+					println!("{id} unchanged");
+				}
+				continue;
+			}
+
+			// Report the object.
+			let new = tg::object::Id::new(id.kind(), &bytes);
+			println!("{id} -> {new}");
+			count += 1;
+		}
+
+		// Print the summary.
+		if args.dry_run {
+			eprintln!("{} {count} objects would change", "info".blue().bold());
+		} else if count > 0 {
+			return Err(tg::error!(
+				%count,
+				"re-encoding would change the IDs of some objects"
+			));
+		}
+
+		Ok(())
+	}
+}
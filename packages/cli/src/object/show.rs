@@ -0,0 +1,143 @@
+use crate::Cli;
+use crossterm::style::Stylize as _;
+use tangram_client::{self as tg, handle::Ext as _};
+use tokio::io::AsyncWriteExt as _;
+
+/// Show an object in a form specific to its kind.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// Print the object's raw data as JSON.
+	#[arg(long)]
+	pub json: bool,
+
+	#[arg(index = 1)]
+	pub object: tg::object::Id,
+
+	#[arg(long, requires = "json")]
+	pub pretty: Option<bool>,
+}
+
+impl Cli {
+	pub async fn command_object_show(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// If the json flag is set, then print the object's raw data.
+		if args.json {
+			let tg::object::get::Output { bytes, .. } = handle.get_object(&args.object).await?;
+			if matches!(args.object, tg::object::Id::Leaf(_)) {
+				let mut stdout = tokio::io::stdout();
+				stdout
+					.write_all(&bytes)
+					.await
+					.map_err(|source| tg::error!(!source, "failed to write the output"))?;
+				stdout
+					.flush()
+					.await
+					.map_err(|source| tg::error!(!source, "failed to flush the output"))?;
+			} else {
+				let output = serde_json::from_slice::<serde_json::Value>(&bytes)
+					.map_err(|source| tg::error!(!source, "failed to deserialize the object"))?;
+				Self::output_json(&output, args.pretty).await?;
+			}
+			return Ok(());
+		}
+
+		// Print the object's kind and ID.
+		println!("{} {}", args.object.kind().to_string().bold(), args.object);
+
+		// Print the object.
+		match tg::Object::with_id(args.object) {
+			tg::Object::Leaf(leaf) => {
+				let size = tg::Blob::from(leaf).size(&handle).await?;
+				println!("size {size}");
+			},
+			tg::Object::Branch(branch) => {
+				let children = branch.children(&handle).await?.len();
+				let size = tg::Blob::from(branch).size(&handle).await?;
+				println!("size {size}");
+				println!("children {children}");
+			},
+			tg::Object::Directory(directory) => {
+				for (name, artifact) in directory.entries(&handle).await? {
+					let id = artifact.id(&handle).await?;
+					println!("{} {name}", artifact_kind(&artifact).dim());
+					println!("  {id}");
+				}
+			},
+			tg::Object::File(file) => {
+				let contents = file.contents(&handle).await?;
+				println!("size {}", contents.size(&handle).await?);
+				println!("executable {}", file.executable(&handle).await?);
+				println!("contents {}", contents.id(&handle).await?);
+				let dependencies = file.dependencies(&handle).await?;
+				if !dependencies.is_empty() {
+					println!("dependencies");
+					for (reference, referent) in dependencies {
+						let id = referent.item.id(&handle).await?;
+						println!("  {reference} {id}");
+					}
+				}
+			},
+			tg::Object::Symlink(symlink) => {
+				if let Some(artifact) = symlink.artifact(&handle).await? {
+					println!("artifact {}", artifact.id(&handle).await?);
+				}
+				if let Some(subpath) = symlink.subpath(&handle).await? {
+					println!("subpath {}", subpath.display());
+				}
+				if let Some(target) = symlink.target(&handle).await? {
+					println!("target {}", target.display());
+				}
+			},
+			tg::Object::Graph(graph) => {
+				let object = graph.object(&handle).await?;
+				println!("nodes {}", object.nodes.len());
+			},
+			tg::Object::Target(target) => {
+				let object = target.object(&handle).await?;
+				let options = tg::value::print::Options {
+					preview_bytes: Some(16),
+					recursive: false,
+					style: tg::value::print::Style::Compact,
+				};
+				println!("host {}", object.host);
+				match &object.executable {
+					Some(tg::target::Executable::Artifact(artifact)) => {
+						println!("executable {}", artifact.id(&handle).await?);
+					},
+					Some(tg::target::Executable::Module(module)) => {
+						let id = module.referent.item.id(&handle).await?;
+						println!("executable {id} ({})", module.kind);
+					},
+					None => (),
+				}
+				if let Some(checksum) = &object.checksum {
+					println!("checksum {checksum}");
+				}
+				if !object.args.is_empty() {
+					println!("args");
+					for arg in &object.args {
+						println!("  {}", arg.print(options.clone()));
+					}
+				}
+				if !object.env.is_empty() {
+					println!("env");
+					for (key, value) in &object.env {
+						println!("  {key}={}", value.print(options.clone()));
+					}
+				}
+			},
+		}
+
+		Ok(())
+	}
+}
+
+fn artifact_kind(artifact: &tg::Artifact) -> &'static str {
+	match artifact {
+		tg::Artifact::Directory(_) => "directory",
+		tg::Artifact::File(_) => "file",
+		tg::Artifact::Symlink(_) => "symlink",
+	}
+}
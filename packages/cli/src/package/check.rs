@@ -2,6 +2,8 @@ use crate::Cli;
 use tangram_client::{self as tg, Handle as _};
 use tangram_either::Either;
 
+mod sarif;
+
 /// Check a package.
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
@@ -10,6 +12,10 @@ pub struct Args {
 	#[arg(long)]
 	pub locked: bool,
 
+	/// The format to print the diagnostics in.
+	#[arg(long)]
+	pub output: Option<Format>,
+
 	#[arg(index = 1, default_value = ".")]
 	pub reference: tg::Reference,
 
@@ -18,6 +24,13 @@ pub struct Args {
 	pub remote: Option<Option<String>>,
 }
 
+#[derive(Clone, Copy, Debug, Default, clap::ValueEnum)]
+pub enum Format {
+	#[default]
+	Human,
+	Sarif,
+}
+
 impl Cli {
 	pub async fn command_package_check(&self, args: Args) -> tg::Result<()> {
 		let handle = self.handle().await?;
@@ -51,8 +64,34 @@ impl Cli {
 		let output = handle.check_package(arg).await?;
 
 		// Print the diagnostics.
-		for diagnostic in &output.diagnostics {
-			Self::print_diagnostic(diagnostic);
+		match args.output.unwrap_or_default() {
+			Format::Human => {
+				for diagnostic in &output.diagnostics {
+					Self::print_diagnostic(diagnostic);
+				}
+			},
+			Format::Sarif => {
+				// Get the package's path. The root is the nearest package path at or above it, so that the URIs do not depend on the current directory.
+				let package = match args.reference.item() {
+					tg::reference::Item::Path(path) => {
+						Some(std::path::absolute(path).map_err(|source| {
+							tg::error!(!source, "failed to get the absolute path")
+						})?)
+					},
+					_ => None,
+				};
+				let root = if let Some(package) = &package {
+					let root = tg::package::try_get_nearest_package_path_for_path(package)
+						.await?
+						.unwrap_or(package.as_path());
+					Some(root.to_owned())
+				} else {
+					None
+				};
+				let log =
+					self::sarif::Log::new(&output.diagnostics, package.as_deref(), root.as_deref());
+				Self::output_json(&log, None).await?;
+			},
 		}
 
 		if !output.diagnostics.is_empty() {
@@ -0,0 +1,373 @@
+use std::{
+	collections::BTreeMap,
+	path::{Path, PathBuf},
+};
+use tangram_client as tg;
+
+/// The ID of the base URI that relative artifact locations are resolved against.
+pub const ROOT_URI_BASE_ID: &str = "SRCROOT";
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Log {
+	#[serde(rename = "$schema")]
+	pub schema: String,
+	pub version: String,
+	pub runs: Vec<Run>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Run {
+	pub tool: Tool,
+	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
+	pub original_uri_base_ids: BTreeMap<String, ArtifactLocation>,
+	pub results: Vec<Result>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Tool {
+	pub driver: Driver,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Driver {
+	pub name: String,
+	pub information_uri: String,
+	pub version: String,
+	pub rules: Vec<Rule>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rule {
+	pub id: String,
+	pub short_description: Message,
+	pub default_configuration: Configuration,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Configuration {
+	pub level: Level,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Result {
+	pub rule_id: String,
+	pub level: Level,
+	pub message: Message,
+	#[serde(skip_serializing_if = "Vec::is_empty")]
+	pub locations: Vec<Location>,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Level {
+	None,
+	Note,
+	Warning,
+	Error,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Message {
+	pub text: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+	pub physical_location: PhysicalLocation,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+	pub artifact_location: ArtifactLocation,
+	pub region: Region,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactLocation {
+	pub uri: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub uri_base_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+	pub start_line: u32,
+	pub start_column: u32,
+	pub end_line: u32,
+	pub end_column: u32,
+}
+
+impl Log {
+	/// Create a SARIF log from a list of diagnostics. Module paths are resolved against the package's path. If a root is provided, then it is the `SRCROOT` base URI, and paths within it are made relative to it.
+	pub fn new(
+		diagnostics: &[tg::Diagnostic],
+		package: Option<&Path>,
+		root: Option<&Path>,
+	) -> Self {
+		let severities = [
+			tg::diagnostic::Severity::Error,
+			tg::diagnostic::Severity::Warning,
+			tg::diagnostic::Severity::Info,
+			tg::diagnostic::Severity::Hint,
+		];
+		let rules = severities
+			.into_iter()
+			.map(|severity| Rule {
+				id: rule_id(severity),
+				short_description: Message {
+					text: format!("A tangram check {severity}."),
+				},
+				default_configuration: Configuration {
+					level: severity.into(),
+				},
+			})
+			.collect();
+		let results = diagnostics
+			.iter()
+			.map(|diagnostic| {
+				let locations = diagnostic
+					.location
+					.as_ref()
+					.and_then(|location| {
+						let artifact_location = artifact_location(location, package, root)?;
+						let range = location.range;
+						let region = Region {
+							start_line: range.start.line + 1,
+							start_column: range.start.character + 1,
+							end_line: range.end.line + 1,
+							end_column: range.end.character + 1,
+						};
+						let location = Location {
+							physical_location: PhysicalLocation {
+								artifact_location,
+								region,
+							},
+						};
+						Some(location)
+					})
+					.into_iter()
+					.collect();
+				Result {
+					rule_id: rule_id(diagnostic.severity),
+					level: diagnostic.severity.into(),
+					message: Message {
+						text: diagnostic.message.clone(),
+					},
+					locations,
+				}
+			})
+			.collect();
+		let driver = Driver {
+			name: "tangram".to_owned(),
+			information_uri: "https://www.tangram.dev".to_owned(),
+			version: env!("CARGO_PKG_VERSION").to_owned(),
+			rules,
+		};
+		let original_uri_base_ids = root
+			.map(|root| {
+				let uri = format!("{}/", file_uri(root));
+				let location = ArtifactLocation {
+					uri,
+					uri_base_id: None,
+				};
+				(ROOT_URI_BASE_ID.to_owned(), location)
+			})
+			.into_iter()
+			.collect();
+		let run = Run {
+			tool: Tool { driver },
+			original_uri_base_ids,
+			results,
+		};
+		Self {
+			schema: "https://json.schemastore.org/sarif-2.1.0.json".to_owned(),
+			version: "2.1.0".to_owned(),
+			runs: vec![run],
+		}
+	}
+}
+
+impl From<tg::diagnostic::Severity> for Level {
+	fn from(value: tg::diagnostic::Severity) -> Self {
+		match value {
+			tg::diagnostic::Severity::Error => Self::Error,
+			tg::diagnostic::Severity::Warning => Self::Warning,
+			tg::diagnostic::Severity::Info => Self::Note,
+			tg::diagnostic::Severity::Hint => Self::None,
+		}
+	}
+}
+
+fn rule_id(severity: tg::diagnostic::Severity) -> String {
+	format!("tangram/{severity}")
+}
+
+fn artifact_location(
+	location: &tg::Location,
+	package: Option<&Path>,
+	root: Option<&Path>,
+) -> Option<ArtifactLocation> {
+	let referent = &location.module.referent;
+	let mut path = match &referent.item {
+		tg::module::Item::Path(path) => path.clone(),
+		tg::module::Item::Object(_) => {
+			let mut path = package.map(Path::to_owned).unwrap_or_default();
+			if let Some(referent_path) = &referent.path {
+				path.push(referent_path);
+			}
+			path
+		},
+	};
+	if let Some(subpath) = &referent.subpath {
+		path.push(subpath);
+	}
+	if path.as_os_str().is_empty() {
+		return None;
+	}
+	let path = normalize(&path);
+	if let Some(relative) = root.and_then(|root| path.strip_prefix(root).ok()) {
+		let location = ArtifactLocation {
+			uri: relative_uri(relative),
+			uri_base_id: Some(ROOT_URI_BASE_ID.to_owned()),
+		};
+		return Some(location);
+	}
+	let uri = if path.is_absolute() {
+		file_uri(&path)
+	} else {
+		relative_uri(&path)
+	};
+	let location = ArtifactLocation {
+		uri,
+		uri_base_id: None,
+	};
+	Some(location)
+}
+
+fn file_uri(path: &Path) -> String {
+	format!("file:///{}", relative_uri(path))
+}
+
+fn relative_uri(path: &Path) -> String {
+	path.components()
+		.filter_map(|component| match component {
+			std::path::Component::Normal(name) => {
+				Some(urlencoding::encode(&name.to_string_lossy()).into_owned())
+			},
+			std::path::Component::ParentDir => Some("..".to_owned()),
+			_ => None,
+		})
+		.collect::<Vec<_>>()
+		.join("/")
+}
+
+fn normalize(path: &Path) -> PathBuf {
+	let mut output = PathBuf::new();
+	for component in path.components() {
+		match component {
+			std::path::Component::CurDir => (),
+			std::path::Component::ParentDir if output.file_name().is_some() => {
+				output.pop();
+			},
+			component => output.push(component),
+		}
+	}
+	output
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Log;
+	use std::path::Path;
+	use tangram_client as tg;
+
+	#[test]
+	fn shape() {
+		let range = tg::Range {
+			start: tg::Position {
+				line: 1,
+				character: 2,
+			},
+			end: tg::Position {
+				line: 1,
+				character: 5,
+			},
+		};
+		let diagnostic = |item, path: Option<&str>| tg::Diagnostic {
+			location: Some(tg::Location {
+				module: tg::Module {
+					kind: tg::module::Kind::Ts,
+					referent: tg::Referent {
+						item,
+						path: path.map(Into::into),
+						subpath: None,
+						tag: None,
+					},
+				},
+				range,
+			}),
+			severity: tg::diagnostic::Severity::Error,
+			message: "error".to_owned(),
+		};
+		let diagnostics = [
+			// A module in a subdirectory of the package.
+			diagnostic(
+				tg::module::Item::Path("/home/user/package/sub/foo.tg.ts".into()),
+				None,
+			),
+			// A module of the package's object, which is resolved against the package's path.
+			diagnostic(
+				tg::module::Item::Object(
+					"dir_01neb60wpaemmf2p87vzyvbs1r4c06a664fek83685stzycqp4t9z0"
+						.parse()
+						.unwrap(),
+				),
+				Some("tangram.ts"),
+			),
+			// A module outside the root.
+			diagnostic(
+				tg::module::Item::Path("/home/user/dependency/tangram.ts".into()),
+				None,
+			),
+		];
+		let package = Path::new("/home/user/package/sub");
+		let root = Path::new("/home/user/package");
+		let log = Log::new(&diagnostics, Some(package), Some(root));
+		let value = serde_json::to_value(&log).unwrap();
+		let run = &value["runs"][0];
+		assert_eq!(
+			run["originalUriBaseIds"],
+			serde_json::json!({ "SRCROOT": { "uri": "file:///home/user/package/" } })
+		);
+		let locations = run["results"]
+			.as_array()
+			.unwrap()
+			.iter()
+			.map(|result| result["locations"][0]["physicalLocation"]["artifactLocation"].clone())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			locations,
+			[
+				serde_json::json!({ "uri": "sub/foo.tg.ts", "uriBaseId": "SRCROOT" }),
+				serde_json::json!({ "uri": "sub/tangram.ts", "uriBaseId": "SRCROOT" }),
+				serde_json::json!({ "uri": "file:///home/user/dependency/tangram.ts" }),
+			]
+		);
+		let result = &run["results"][0];
+		assert_eq!(result["ruleId"], "tangram/error");
+		assert_eq!(result["level"], "error");
+		assert_eq!(result["message"]["text"], "error");
+		assert_eq!(
+			result["locations"][0]["physicalLocation"]["region"],
+			serde_json::json!({ "startLine": 2, "startColumn": 3, "endLine": 2, "endColumn": 6 })
+		);
+	}
+}
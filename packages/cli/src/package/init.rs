@@ -7,6 +7,14 @@ use tangram_client as tg;
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
 pub struct Args {
+	/// Create a binary package, whose default target builds the package. This is the default.
+	#[arg(long, conflicts_with = "lib")]
+	pub bin: bool,
+
+	/// Create a library package, which exports targets for other packages to use.
+	#[arg(long)]
+	pub lib: bool,
+
 	#[arg(index = 1)]
 	pub path: Option<PathBuf>,
 }
@@ -49,8 +57,13 @@ impl Cli {
 
 		// Define the files to generate.
 		let mut files = Vec::new();
-		files.push((
-			path.join("tangram.ts"),
+		let root_module = if args.lib {
+			formatdoc!(
+				r#"
+					export const greet = tg.target((name: string) => `Hello, ${{name}}!`);
+				"#,
+			)
+		} else {
 			formatdoc!(
 				r#"
 					import * as autobuild from "{autobuild_reference}";
@@ -59,8 +72,9 @@ impl Cli {
 					export default tg.target(() => autobuild.build({{ env: env(), source }}));
 					export const env = tg.target(() => std.env(autobuild.env({{ source }})));
 				"#,
-			),
-		));
+			)
+		};
+		files.push((path.join("tangram.ts"), root_module));
 
 		// Write the files.
 		for (path, contents) in files {
@@ -6,13 +6,25 @@ use tangram_client as tg;
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
 pub struct Args {
+	/// Create a binary package, whose default target builds the package. This is the default.
+	#[arg(long, conflicts_with = "lib")]
+	pub bin: bool,
+
+	/// Create a library package, which exports targets for other packages to use.
+	#[arg(long)]
+	pub lib: bool,
+
 	#[arg(index = 1)]
 	pub path: Option<PathBuf>,
 }
 
 impl Cli {
 	pub async fn command_package_new(&self, args: Args) -> tg::Result<()> {
-		let args = crate::package::init::Args { path: args.path };
+		let args = crate::package::init::Args {
+			bin: args.bin,
+			lib: args.lib,
+			path: args.path,
+		};
 		self.command_package_init(args).await?;
 		Ok(())
 	}
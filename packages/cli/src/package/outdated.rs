@@ -1,20 +1,279 @@
 use crate::Cli;
-use tangram_client as tg;
+use tangram_client::{self as tg, Handle as _};
+use tangram_either::Either;
 
 /// Get a package's outdated dependencies.
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
 pub struct Args {
-	/// If this flag is set, the package's lockfile will not be updated.
+	/// Print the dependencies as JSON with a stable schema.
 	#[arg(long)]
-	pub locked: bool,
+	pub json: bool,
 
 	#[arg(index = 1, default_value = ".")]
 	pub package: tg::Reference,
+
+	#[arg(long)]
+	pub pretty: Option<bool>,
+}
+
+/// The output of `tg package outdated --json`. The field names are stable across releases. Fields may be added, but existing fields will not be renamed or removed without incrementing `version`.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Output {
+	/// The version of this schema.
+	pub version: u32,
+
+	/// The package's direct dependencies.
+	pub dependencies: Vec<Dependency>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct Dependency {
+	/// The reference the package uses to import the dependency.
+	pub reference: tg::Reference,
+
+	/// The kind of the dependency.
+	pub kind: Kind,
+
+	/// The tag the dependency is currently resolved to.
+	pub current: Option<tg::Tag>,
+
+	/// The newest tag that satisfies the reference's constraint.
+	pub compatible: Option<tg::Tag>,
+
+	/// The newest tag with any version.
+	pub latest: Option<tg::Tag>,
+}
+
+/// The kind of a dependency. Only tag dependencies have versions, so the other kinds never have a `current`, `compatible`, or `latest` tag.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+	Tag,
+	Path,
+	Object,
 }
 
 impl Cli {
-	pub async fn command_package_outdated(&self, _args: Args) -> tg::Result<()> {
-		Err(tg::error!("unimplemented"))
+	pub async fn command_package_outdated(&self, args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Get the package.
+		let referent = self.get_reference(&args.package).await?;
+		let Either::Right(object) = referent.item else {
+			return Err(tg::error!("expected an object"));
+		};
+		let object = if let Some(subpath) = &referent.subpath {
+			let directory = object
+				.try_unwrap_directory()
+				.ok()
+				.ok_or_else(|| tg::error!("expected a directory"))?;
+			directory.get(&handle, subpath).await?.into()
+		} else {
+			object
+		};
+
+		// Get the root module.
+		let name = tg::package::try_get_root_module_file_name(&handle, Either::Left(&object))
+			.await?
+			.ok_or_else(|| tg::error!("failed to find the package's root module"))?;
+		let Ok(package) = tg::Directory::try_from(object) else {
+			return Err(tg::error!("expected a directory"));
+		};
+		let file = package
+			.get(&handle, name)
+			.await?
+			.try_unwrap_file()
+			.ok()
+			.ok_or_else(|| tg::error!("expected the root module to be a file"))?;
+
+		// Get the dependencies.
+		let mut dependencies = Vec::new();
+		for (reference, referent) in file.dependencies(&handle).await? {
+			let dependency = self.get_outdated_dependency(reference, referent).await?;
+			dependencies.push(dependency);
+		}
+		let output = Output {
+			version: Output::VERSION,
+			dependencies,
+		};
+
+		// Print the output.
+		if args.json {
+			Self::output_json(&output, args.pretty).await?;
+			return Ok(());
+		}
+		for dependency in &output.dependencies {
+			if dependency.kind != Kind::Tag || dependency.current == dependency.latest {
+				continue;
+			}
+			let tag = |tag: &Option<tg::Tag>| {
+				tag.as_ref()
+					.map_or_else(|| "-".to_owned(), ToString::to_string)
+			};
+			println!(
+				"{} {} {} {}",
+				dependency.reference,
+				tag(&dependency.current),
+				tag(&dependency.compatible),
+				tag(&dependency.latest),
+			);
+		}
+
+		Ok(())
+	}
+
+	async fn get_outdated_dependency(
+		&self,
+		reference: tg::Reference,
+		referent: tg::Referent<tg::Object>,
+	) -> tg::Result<Dependency> {
+		let handle = self.handle().await?;
+
+		// Path dependencies are not versioned.
+		let is_path = reference.item().try_unwrap_path_ref().is_ok()
+			|| reference
+				.options()
+				.is_some_and(|options| options.path.is_some());
+		if is_path {
+			return Ok(Dependency {
+				reference,
+				kind: Kind::Path,
+				current: None,
+				compatible: None,
+				latest: None,
+			});
+		}
+
+		// Dependencies by ID are not versioned.
+		let Ok(pattern) = reference.item().try_unwrap_tag_ref() else {
+			return Ok(Dependency {
+				reference,
+				kind: Kind::Object,
+				current: None,
+				compatible: None,
+				latest: None,
+			});
+		};
+		let remote = reference
+			.options()
+			.and_then(|options| options.remote.clone());
+
+		// Get the newest version that satisfies the constraint.
+		let compatible = self
+			.get_newest_version_tag(pattern.clone(), remote.clone())
+			.await?;
+
+		// Get the newest tag with any version, if the constraint is on a version.
+		let latest = if pattern.components().last().is_some_and(|component| {
+			matches!(
+				component,
+				tg::tag::pattern::Component::Version(_)
+					| tg::tag::pattern::Component::Normal(tg::tag::Component::Version(_))
+			)
+		}) {
+			let mut components = pattern.components().clone();
+			*components.last_mut().unwrap() = tg::tag::pattern::Component::Wildcard;
+			let pattern = tg::tag::Pattern::with_components(components);
+			self.get_newest_version_tag(pattern, remote).await?
+		} else {
+			compatible.clone()
+		};
+
+		Ok(Dependency {
+			reference,
+			kind: Kind::Tag,
+			current: referent.tag,
+			compatible,
+			latest,
+		})
+	}
+
+	/// Get the newest tag matching a pattern whose last component is a version, preferring releases over prereleases the way checkin does.
+	async fn get_newest_version_tag(
+		&self,
+		pattern: tg::tag::Pattern,
+		remote: Option<String>,
+	) -> tg::Result<Option<tg::Tag>> {
+		let handle = self.handle().await?;
+		let arg = tg::tag::list::Arg {
+			length: None,
+			pattern: pattern.clone(),
+			remote,
+			reverse: false,
+			sort: Some(tg::tag::list::Sort::Semver),
+		};
+		let tags = handle
+			.list_tags(arg)
+			.await
+			.map_err(|source| tg::error!(!source, %pattern, "failed to list the tags"))?
+			.data
+			.into_iter()
+			.map(|output| output.tag)
+			.filter(|tag| {
+				matches!(
+					tag.components().last(),
+					Some(tg::tag::Component::Version(_))
+				)
+			})
+			.collect::<Vec<_>>();
+		let index = tg::artifact::checkin::Strategy::Newest
+			.select_candidate(&tags.iter().collect::<Vec<_>>());
+		let tag = index.map(|index| tags[index].clone());
+		Ok(tag)
+	}
+}
+
+impl Output {
+	pub const VERSION: u32 = 1;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Dependency, Kind, Output};
+	use insta::assert_json_snapshot;
+
+	#[test]
+	fn schema() {
+		let output = Output {
+			version: Output::VERSION,
+			dependencies: vec![
+				Dependency {
+					reference: "dep/1.0.0".parse().unwrap(),
+					kind: Kind::Tag,
+					current: Some("dep/1.0.0".parse().unwrap()),
+					compatible: Some("dep/1.0.0".parse().unwrap()),
+					latest: Some("dep/2.0.0".parse().unwrap()),
+				},
+				Dependency {
+					reference: "./dep".parse().unwrap(),
+					kind: Kind::Path,
+					current: None,
+					compatible: None,
+					latest: None,
+				},
+			],
+		};
+		assert_json_snapshot!(output, @r#"
+  {
+    "version": 1,
+    "dependencies": [
+      {
+        "reference": "dep/1.0.0",
+        "kind": "tag",
+        "current": "dep/1.0.0",
+        "compatible": "dep/1.0.0",
+        "latest": "dep/2.0.0"
+      },
+      {
+        "reference": "./dep",
+        "kind": "path",
+        "current": null,
+        "compatible": null,
+        "latest": null
+      }
+    ]
+  }
+  "#);
 	}
 }
@@ -29,10 +29,13 @@ impl Cli {
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
 			path,
+			strategy: None,
 		};
 		let stream = handle.check_in_artifact(arg).await?;
 		stream.map_ok(|_| ()).try_collect::<()>().await?;
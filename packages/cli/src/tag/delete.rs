@@ -1,10 +1,15 @@
 use crate::Cli;
+use crossterm::style::Stylize as _;
 use tangram_client::{self as tg, Handle as _};
 
 /// Delete a tag.
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
 pub struct Args {
+	/// After deleting the tag, remove the tagged item and the items in its closure that are no longer referenced.
+	#[arg(long)]
+	pub closure: bool,
+
 	#[arg(index = 1)]
 	pub tag: tg::Tag,
 }
@@ -12,7 +17,32 @@ pub struct Args {
 impl Cli {
 	pub async fn command_tag_delete(&self, args: Args) -> tg::Result<()> {
 		let handle = self.handle().await?;
+
+		// Get the tagged item before deleting the tag.
+		let item = if args.closure {
+			let pattern = args.tag.clone().into();
+			let output = handle
+				.try_get_tag(&pattern)
+				.await?
+				.ok_or_else(|| tg::error!(%tag = args.tag, "failed to find the tag"))?;
+			Some(output.item)
+		} else {
+			None
+		};
+
+		// Delete the tag.
 		handle.delete_tag(&args.tag).await?;
+
+		// Clean the item's closure.
+		if let Some(item) = item {
+			let arg = tg::clean::Arg {
+				roots: vec![item],
+				..Default::default()
+			};
+			let output = handle.clean(arg).await?;
+			eprintln!("{} freed {} bytes", "info".blue().bold(), output.bytes);
+		}
+
 		Ok(())
 	}
 }
@@ -14,6 +14,10 @@ pub struct Args {
 
 	#[arg(long, action = clap::ArgAction::Set)]
 	pub reverse: bool,
+
+	/// How to sort the tags, either `lexical` or `semver`.
+	#[arg(long)]
+	pub sort: Option<tg::tag::list::Sort>,
 }
 
 impl Cli {
@@ -31,6 +35,7 @@ impl Cli {
 			pattern: args.pattern,
 			remote,
 			reverse: args.reverse,
+			sort: args.sort,
 		};
 		let output = handle.list_tags(arg).await?;
 
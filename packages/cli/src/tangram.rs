@@ -1,6 +1,7 @@
 use crate::Cli;
 use tangram_client as tg;
 
+pub mod completions;
 pub mod update;
 
 /// Manage Tangram.
@@ -13,12 +14,17 @@ pub struct Args {
 
 #[derive(Clone, Debug, clap::Subcommand)]
 pub enum Command {
+	Completions(self::completions::Args),
+
 	Update(self::update::Args),
 }
 
 impl Cli {
 	pub async fn command_tangram(&self, args: Args) -> tg::Result<()> {
 		match args.command {
+			Command::Completions(args) => {
+				self.command_tangram_completions(args).await?;
+			},
 			Command::Update(args) => {
 				self.command_tangram_update(args).await?;
 			},
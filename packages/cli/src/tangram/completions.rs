@@ -0,0 +1,31 @@
+use crate::Cli;
+use clap::CommandFactory as _;
+use tangram_client as tg;
+
+/// Generate shell completions.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// The shell to generate completions for.
+	#[arg(index = 1)]
+	pub shell: clap_complete::Shell,
+}
+
+impl Cli {
+	pub async fn command_tangram_completions(&self, args: Args) -> tg::Result<()> {
+		// Make the subcommands' aliases visible so that they are completed.
+		let mut command = crate::Args::command().mut_subcommands(|subcommand| {
+			let aliases = subcommand
+				.get_all_aliases()
+				.map(ToOwned::to_owned)
+				.collect::<Vec<_>>();
+			subcommand.visible_aliases(aliases)
+		});
+
+		// Generate the completions.
+		let name = command.get_name().to_owned();
+		clap_complete::generate(args.shell, &mut command, name, &mut std::io::stdout());
+
+		Ok(())
+	}
+}
@@ -0,0 +1,733 @@
+use crate::Cli;
+use crossterm::style::Stylize as _;
+use futures::TryStreamExt as _;
+use itertools::Itertools as _;
+use std::{
+	collections::BTreeMap,
+	io::IsTerminal as _,
+	path::{Path, PathBuf},
+	pin::pin,
+};
+use tangram_client::{self as tg, handle::Ext as _, Handle};
+use tangram_either::Either;
+
+/// Build a target.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	/// Set the arguments.
+	#[arg(short, long, num_args = 1.., action = clap::ArgAction::Append)]
+	pub arg: Vec<Vec<String>>,
+
+	/// Whether to check out the output. The output must be an artifact. A path to check out to may be provided.
+	#[allow(clippy::option_option)]
+	#[arg(short, long)]
+	pub checkout: Option<Option<PathBuf>>,
+
+	/// If false, don't create a new build.
+	#[arg(default_value = "true", long, action = clap::ArgAction::Set)]
+	pub create: bool,
+
+	/// If this flag is set, then the command will exit immediately instead of waiting for the build to finish.
+	#[arg(short, long, conflicts_with = "checkout")]
+	pub detach: bool,
+
+	/// Print whether an existing build was found for the target, and if not, why.
+	#[arg(long)]
+	pub explain_cache: bool,
+
+	/// Set the environment variables.
+	#[arg(short, long, num_args = 1.., action = clap::ArgAction::Append)]
+	pub env: Vec<Vec<String>>,
+
+	/// Print each progress value that the build reports to stderr as a line of JSON while waiting for the build to finish.
+	#[arg(long, conflicts_with = "detach")]
+	pub follow_output: bool,
+
+	/// Set the host.
+	#[arg(long)]
+	pub host: Option<String>,
+
+	/// Pass an artifact to the target as a named input, formatted as `name=reference`. Each input is set in the target's environment under its name as a template that references the artifact.
+	#[arg(long = "input", action = clap::ArgAction::Append)]
+	pub inputs: Vec<String>,
+
+	/// Attach a label to the build, formatted as `key=value`. Labels do not affect which build is used. If an existing build is used, then the label is added to it.
+	#[arg(long = "label", action = clap::ArgAction::Append)]
+	pub labels: Vec<String>,
+
+	/// If this flag is set, the package's lockfile will not be updated.
+	#[arg(long)]
+	pub locked: bool,
+
+	/// Print the build's ID to stderr as `build: <id>` on its own line as soon as the build is created.
+	#[arg(long)]
+	pub print_build_id: bool,
+
+	/// Whether to suppress printing the tree.
+	#[arg(short, long)]
+	pub quiet: bool,
+
+	/// The reference to the target to build.
+	#[arg(index = 1)]
+	pub reference: Option<tg::Reference>,
+
+	/// The remote to use.
+	#[allow(clippy::option_option)]
+	#[arg(short, long)]
+	pub remote: Option<Option<String>>,
+
+	/// The retry strategy to use.
+	#[allow(clippy::option_option)]
+	#[arg(long)]
+	pub retry: Option<Option<tg::build::Retry>>,
+
+	/// Create a tag for this build.
+	#[arg(long)]
+	pub tag: Option<tg::Tag>,
+
+	/// If the build fails, then print the last N lines of its log to stderr.
+	#[arg(long, value_name = "N")]
+	pub tail_on_exit: Option<usize>,
+}
+
+#[derive(Clone, Debug, derive_more::Unwrap)]
+pub enum InnerOutput {
+	Detached(tg::build::Id),
+	Failed(tg::Error),
+	Path(PathBuf),
+	Value(tg::Value),
+}
+
+impl Cli {
+	pub async fn command_target_build(&self, args: Args) -> tg::Result<()> {
+		// Build.
+		let output = self.command_target_build_inner(args).await?;
+
+		// Print the output.
+		match output {
+			InnerOutput::Detached(build) => {
+				println!("{build}");
+			},
+			InnerOutput::Failed(error) => {
+				return Err(error);
+			},
+			InnerOutput::Path(path) => {
+				println!("{}", path.display());
+			},
+			InnerOutput::Value(value) => {
+				let stdout = std::io::stdout();
+				let value = if stdout.is_terminal() {
+					let options = tg::value::print::Options {
+						preview_bytes: None,
+						recursive: false,
+						style: tg::value::print::Style::Pretty { indentation: "\t" },
+					};
+					value.print(options)
+				} else {
+					value.to_string()
+				};
+				println!("{value}");
+			},
+		}
+
+		Ok(())
+	}
+
+	pub(crate) async fn command_target_build_inner(&self, args: Args) -> tg::Result<InnerOutput> {
+		let handle = self.handle().await?;
+
+		// Get the reference.
+		let reference = args
+			.reference
+			.clone()
+			.unwrap_or_else(|| ".".parse().unwrap());
+
+		// Get the remote.
+		let remote = args
+			.remote
+			.clone()
+			.map(|remote| remote.unwrap_or_else(|| "default".to_owned()));
+
+		// If the reference is a path to a directory and the path does not contain a root module, then init.
+		if let Ok(path) = reference.item().try_unwrap_path_ref() {
+			let path = if let Some(subpath) = reference
+				.options()
+				.and_then(|options| options.subpath.as_ref())
+			{
+				path.join(subpath)
+			} else {
+				path.clone()
+			};
+			let metadata = tokio::fs::metadata(&path).await.map_err(
+				|source| tg::error!(!source, ?path = path.display(), "failed to get the metadata"),
+			)?;
+			if metadata.is_dir() {
+				let mut exists = false;
+				for name in tg::package::ROOT_MODULE_FILE_NAMES {
+					let module_path = path.join(name);
+					exists = tokio::fs::try_exists(&module_path)
+						.await
+						.map_err(|source| {
+							tg::error!(!source, ?path, "failed to check if the path exists")
+						})?;
+					if exists {
+						break;
+					}
+				}
+				if !exists {
+					self.command_package_init(crate::package::init::Args {
+						bin: false,
+						lib: false,
+						path: Some(path.clone()),
+					})
+					.await?;
+				}
+			}
+		}
+
+		// Get the reference.
+		let referent = self.get_reference(&reference).await?;
+		let Either::Right(object) = referent.item else {
+			return Err(tg::error!("expected an object"));
+		};
+		let object = if let Some(subpath) = &referent.subpath {
+			let directory = object
+				.try_unwrap_directory()
+				.ok()
+				.ok_or_else(|| tg::error!("expected a directory"))?;
+			directory.get(&handle, subpath).await?.into()
+		} else {
+			object
+		};
+
+		// Create the target.
+		let target = if let tg::Object::Target(target) = object {
+			// If the object is a target, then use it.
+			target
+		} else {
+			// Otherwise, the object must be a directory containing a root module, or a file.
+			let executable = match object {
+				tg::Object::Directory(directory) => {
+					let mut name = None;
+					for name_ in tg::package::ROOT_MODULE_FILE_NAMES {
+						if directory.try_get_entry(&handle, name_).await?.is_some() {
+							name = Some(name_);
+							break;
+						}
+					}
+					let name = name.ok_or_else(|| tg::error!("no root module found"))?;
+					let kind = if Path::new(name)
+						.extension()
+						.is_some_and(|extension| extension == "js")
+					{
+						tg::module::Kind::Js
+					} else if Path::new(name)
+						.extension()
+						.is_some_and(|extension| extension == "ts")
+					{
+						tg::module::Kind::Ts
+					} else {
+						unreachable!();
+					};
+					let item = directory.clone().into();
+					let subpath = Some(name.parse().unwrap());
+					let referent = tg::Referent {
+						item,
+						path: referent.path,
+						subpath,
+						tag: referent.tag,
+					};
+					let module = tg::target::Module { kind, referent };
+					tg::target::Executable::Module(module)
+				},
+
+				tg::Object::File(file) => {
+					let kind = if let Ok(path) = reference.item().try_unwrap_path_ref() {
+						let path = if let Some(subpath) = reference
+							.options()
+							.and_then(|options| options.subpath.as_ref())
+						{
+							path.join(subpath)
+						} else {
+							path.clone()
+						};
+						if path.extension().is_some_and(|extension| extension == "js") {
+							tg::module::Kind::Js
+						} else if path.extension().is_some_and(|extension| extension == "ts") {
+							tg::module::Kind::Ts
+						} else {
+							return Err(tg::error!("invalid file extension"));
+						}
+					} else {
+						return Err(tg::error!("cannot determine the file's kind"));
+					};
+					let referent = tg::Referent::with_item(file.into());
+					tg::target::Executable::Module(tg::target::Module { kind, referent })
+				},
+
+				_ => {
+					return Err(tg::error!("expected a directory or a file"));
+				},
+			};
+
+			// Get the target.
+			let target = reference
+				.uri()
+				.fragment()
+				.map_or("default", |fragment| fragment);
+
+			// Get the args.
+			let mut args_: Vec<tg::Value> = args
+				.arg
+				.into_iter()
+				.map(|arg| {
+					arg.into_iter()
+						.map(|arg| arg.parse())
+						.collect::<Result<tg::value::Array, tg::Error>>()
+						.map(Into::into)
+				})
+				.try_collect()?;
+			args_.insert(0, target.into());
+
+			// Get the env.
+			let mut env: tg::value::Map = args
+				.env
+				.into_iter()
+				.flatten()
+				.map(|env| {
+					let map = env
+						.parse::<tg::Value>()?
+						.try_unwrap_map()
+						.map_err(|_| tg::error!("expected a map"))?
+						.into_iter();
+					Ok::<_, tg::Error>(map)
+				})
+				.try_fold(tg::value::Map::new(), |mut map, item| {
+					map.extend(item?);
+					Ok::<_, tg::Error>(map)
+				})?;
+
+			// Add the inputs to the env.
+			for input in &args.inputs {
+				let (name, value) = self.get_input(input).await?;
+				env.insert(name, value);
+			}
+
+			// Set the TANGRAM_HOST environment variable if it is not set.
+			if !env.contains_key("TANGRAM_HOST") {
+				let host = if let Some(host) = args.host {
+					host
+				} else {
+					tg::host().to_owned()
+				};
+				env.insert("TANGRAM_HOST".to_owned(), host.to_string().into());
+			}
+
+			// Choose the host.
+			let host = "js";
+
+			// Create the target.
+			tg::target::Builder::new(host)
+				.executable(Some(executable))
+				.args(args_)
+				.env(env)
+				.build()
+		};
+
+		// Determine the retry.
+		let retry = match args.retry {
+			None => tg::build::Retry::default(),
+			Some(None) => tg::build::Retry::Succeeded,
+			Some(Some(retry)) => retry,
+		};
+
+		// Print the target.
+		eprintln!(
+			"{} target {}",
+			"info".blue().bold(),
+			target.id(&handle).await?
+		);
+
+		// If the remote is set, then push the target.
+		if let Some(remote) = remote.clone() {
+			let id = target.id(&handle).await?;
+			let arg = tg::object::push::Arg {
+				remote,
+				verbose: false,
+			};
+			let stream = handle.push_object(&id.into(), arg).await?;
+			self.render_progress_stream(stream).await?;
+		}
+
+		// Get the labels.
+		let labels = args
+			.labels
+			.iter()
+			.map(|label| parse_label(label))
+			.collect::<tg::Result<BTreeMap<_, _>>>()?;
+
+		// Build the target.
+		let id = target.id(&handle).await?;
+		let arg = tg::target::build::Arg {
+			create: args.create,
+			labels,
+			parent: None,
+			remote: remote.clone(),
+			retry,
+		};
+		let output = handle.build_target(&id, arg).await?;
+
+		// Explain the cache lookup if requested.
+		if args.explain_cache {
+			Self::explain_build_cache(&id, &output);
+		}
+
+		let build = tg::Build::with_id(output.build);
+
+		// Tag the build if requested.
+		if let Some(tag) = args.tag {
+			let item = Either::Left(build.id().clone());
+			let arg = tg::tag::put::Arg {
+				force: false,
+				item,
+				remote: remote.clone(),
+			};
+			handle.put_tag(&tag, arg).await?;
+		}
+
+		// Print the build's ID if requested.
+		if args.print_build_id {
+			eprintln!("build: {}", build.id());
+		}
+
+		// If the detach flag is set, then return the build.
+		if args.detach {
+			return Ok(InnerOutput::Detached(build.id().clone()));
+		}
+
+		// Print the build.
+		eprintln!("{} build {}", "info".blue().bold(), build.id());
+
+		// Spawn a task to print the build's progress values if requested.
+		let progress_task = if args.follow_output {
+			let stream = build.progress(&handle).await?;
+			let task = tokio::spawn(async move {
+				let mut stream = pin!(stream);
+				while let Some(value) = stream.try_next().await? {
+					eprintln!("{value}");
+				}
+				Ok::<_, tg::Error>(())
+			});
+			Some(task)
+		} else {
+			None
+		};
+
+		// Get the build's status.
+		let status = build
+			.status(&handle)
+			.await?
+			.try_next()
+			.await?
+			.ok_or_else(|| tg::error!("failed to get the status"))?;
+
+		// If the build is finished, then get the build's outcome.
+		let outcome = if status == tg::build::Status::Finished {
+			let outcome = build
+				.outcome(&handle)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to get the outcome"))?;
+			Some(outcome)
+		} else {
+			None
+		};
+
+		// If the build is not finished, then wait for it to finish while showing the TUI if enabled.
+		let outcome = if let Some(outcome) = outcome {
+			outcome
+		} else {
+			// Spawn the tree task.
+			let tree_task = (!args.quiet).then(|| {
+				let handle = handle.clone();
+				let build = build.clone();
+				let options = crate::view::tree::Options {
+					depth: None,
+					objects: false,
+					builds: true,
+					collapse_builds_on_success: true,
+				};
+				tokio::spawn(Self::tree_inner(handle, Either::Left(build), options))
+			});
+
+			// Spawn a task to attempt to cancel the build on the first interrupt signal and exit the process on the second.
+			let cancel_task = tokio::spawn({
+				let handle = handle.clone();
+				let build = build.clone();
+				let remote = remote.clone();
+				async move {
+					tokio::signal::ctrl_c().await.unwrap();
+					tokio::spawn(async move {
+						let outcome = tg::build::outcome::Data::Cancelation(
+							tg::build::outcome::data::Cancelation {
+								reason: Some("the build was explicitly canceled".to_owned()),
+							},
+						);
+						let arg = tg::build::finish::Arg { outcome, remote };
+						build.finish(&handle, arg).await.ok();
+					});
+					tokio::signal::ctrl_c().await.unwrap();
+					std::process::exit(130);
+				}
+			});
+
+			// Wait for the build's outcome.
+			let outcome = build.outcome(&handle).await;
+
+			// Abort the cancel task.
+			cancel_task.abort();
+
+			// Wait for the tree task to finish.
+			if let Some(tree_task) = tree_task {
+				tree_task.await.unwrap()?;
+			}
+
+			outcome.map_err(|source| tg::error!(!source, "failed to get the build outcome"))?
+		};
+
+		// Wait for the progress task to finish.
+		if let Some(progress_task) = progress_task {
+			progress_task
+				.await
+				.unwrap()
+				.inspect_err(|error| {
+					eprintln!(
+						"{} failed to follow the build's progress",
+						"warning".yellow().bold()
+					);
+					Self::print_error(error, None);
+				})
+				.ok();
+		}
+
+		// Handle a failed build.
+		let output = match outcome.into_result() {
+			Ok(output) => output,
+			Err(source) => {
+				// Print the tail of the log if requested.
+				if let Some(lines) = args.tail_on_exit {
+					Self::print_build_log_tail(&handle, &build, lines, remote)
+						.await
+						.inspect_err(|error| {
+							eprintln!(
+								"{} failed to print the build log",
+								"warning".yellow().bold()
+							);
+							Self::print_error(error, None);
+						})
+						.ok();
+				}
+				let error = tg::error!(!source, "the build failed");
+				return Ok(InnerOutput::Failed(error));
+			},
+		};
+
+		// Check out the output if requested.
+		if let Some(path) = args.checkout {
+			// Get the artifact.
+			let artifact = tg::Artifact::try_from(output.clone())
+				.map_err(|source| tg::error!(!source, "expected the output to be an artifact"))?;
+
+			// Get the path.
+			let path = if let Some(path) = path {
+				let path = std::path::absolute(path)
+					.map_err(|source| tg::error!(!source, "failed to get the path"))?;
+				Some(path)
+			} else {
+				None
+			};
+
+			// Check out the artifact.
+			let arg = tg::artifact::checkout::Arg {
+				cache: true,
+				dependencies: path.is_some(),
+				force: false,
+				lockfile: false,
+				path,
+			};
+			let output = artifact
+				.check_out(&handle, arg)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to check out the artifact"))?;
+
+			return Ok(InnerOutput::Path(output));
+		}
+
+		Ok(InnerOutput::Value(output))
+	}
+}
+
+impl Cli {
+	async fn get_input(&self, input: &str) -> tg::Result<(String, tg::Value)> {
+		let handle = self.handle().await?;
+
+		// Parse the input.
+		let (name, reference) = input.split_once('=').ok_or_else(
+			|| tg::error!(%input, "expected the input to be formatted as name=reference"),
+		)?;
+		if name.is_empty() {
+			return Err(tg::error!(%input, "expected the input to have a name"));
+		}
+		let reference = reference
+			.parse::<tg::Reference>()
+			.map_err(|source| tg::error!(!source, %input, "failed to parse the reference"))?;
+
+		// Get the artifact.
+		let referent = self.get_reference(&reference).await?;
+		let Either::Right(object) = referent.item else {
+			return Err(tg::error!(%input, "expected an object"));
+		};
+		let object = if let Some(subpath) = &referent.subpath {
+			let directory = object
+				.try_unwrap_directory()
+				.ok()
+				.ok_or_else(|| tg::error!(%input, "expected a directory"))?;
+			directory.get(&handle, subpath).await?.into()
+		} else {
+			object
+		};
+		let artifact = tg::Artifact::try_from(object)
+			.map_err(|source| tg::error!(!source, %input, "expected an artifact"))?;
+
+		// Create the value.
+		let value: tg::Value = tg::Template::from(artifact).into();
+
+		Ok((name.to_owned(), value))
+	}
+
+	/// Print the last lines of a build's log to stderr. At most `TAIL_BYTES` bytes are read from the end of the log.
+	async fn print_build_log_tail(
+		handle: &impl Handle,
+		build: &tg::Build,
+		lines: usize,
+		remote: Option<String>,
+	) -> tg::Result<()> {
+		const TAIL_BYTES: i64 = 1 << 16;
+
+		// Read the end of the log backwards.
+		let arg = tg::build::log::get::Arg {
+			length: Some(-TAIL_BYTES),
+			position: Some(std::io::SeekFrom::End(0)),
+			remote,
+			size: None,
+		};
+		let mut chunks = build
+			.log(handle, arg)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to get the build log"))?
+			.try_collect::<Vec<_>>()
+			.await?;
+		chunks.sort_by_key(|chunk| chunk.position);
+		let bytes = chunks
+			.iter()
+			.flat_map(|chunk| chunk.bytes.iter().copied())
+			.collect::<Vec<_>>();
+
+		// Print the last lines.
+		let text = String::from_utf8_lossy(&bytes);
+		let tail = text.lines().collect::<Vec<_>>();
+		let tail = &tail[tail.len().saturating_sub(lines)..];
+		if tail.is_empty() {
+			return Ok(());
+		}
+		eprintln!(
+			"{} the last {} lines of the log of build {}",
+			"info".blue().bold(),
+			tail.len(),
+			build.id()
+		);
+		for line in tail {
+			eprintln!("{line}");
+		}
+
+		Ok(())
+	}
+
+	fn explain_build_cache(target: &tg::target::Id, output: &tg::target::build::Output) {
+		let info = "info".blue().bold();
+		match &output.cache {
+			Some(tg::target::build::Cache::Local) => {
+				eprintln!(
+					"{info} cache hit: found build {} for target {target}",
+					output.build
+				);
+			},
+			Some(tg::target::build::Cache::Remote { remote }) => {
+				eprintln!(
+					"{info} cache hit: found build {} for target {target} on remote {remote}",
+					output.build
+				);
+			},
+			Some(tg::target::build::Cache::Retry {
+				build,
+				outcome,
+				retry,
+			}) => {
+				eprintln!(
+					"{info} cache miss: found build {build} for target {target}, but its outcome did not satisfy the retry constraint"
+				);
+				eprintln!(
+					"{info} the build's outcome was {outcome}, and the retry constraint {retry} retries builds whose outcome is {retry} or lower"
+				);
+			},
+			Some(tg::target::build::Cache::Miss) => {
+				eprintln!("{info} cache miss: found no build for target {target}");
+				eprintln!(
+					"{info} a target's ID is a hash of its host, executable, args, env, and checksum, so at least one of them differs from every previous build"
+				);
+			},
+			None => {
+				eprintln!("{info} the server did not report the result of the cache lookup");
+			},
+		}
+	}
+}
+
+impl Default for Args {
+	fn default() -> Self {
+		Self {
+			arg: vec![],
+			checkout: None,
+			create: true,
+			detach: false,
+			env: vec![],
+			explain_cache: false,
+			follow_output: false,
+			host: None,
+			inputs: vec![],
+			labels: vec![],
+			locked: false,
+			print_build_id: false,
+			quiet: false,
+			reference: None,
+			remote: None,
+			retry: None,
+			tag: None,
+			tail_on_exit: None,
+		}
+	}
+}
+
+pub(crate) fn parse_label(label: &str) -> tg::Result<(String, String)> {
+	let (key, value) = label
+		.split_once('=')
+		.ok_or_else(|| tg::error!(%label, "expected the label to be formatted as key=value"))?;
+	if key.is_empty() {
+		return Err(tg::error!(%label, "expected the label to have a key"));
+	}
+	if label.contains(',') {
+		return Err(tg::error!(%label, "labels must not contain commas"));
+	}
+	Ok((key.to_owned(), value.to_owned()))
+}
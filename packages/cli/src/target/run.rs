@@ -0,0 +1,225 @@
+use crate::Cli;
+use crossterm::style::Stylize as _;
+use std::{
+	os::unix::process::CommandExt as _,
+	path::{Path, PathBuf},
+};
+use tangram_client as tg;
+use tokio::io::{AsyncRead, AsyncReadExt as _, AsyncWrite, AsyncWriteExt as _};
+
+/// Build a target and run a command.
+#[derive(Clone, Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+	#[command(flatten)]
+	pub build: crate::target::build::Args,
+
+	/// Write the command's stdout to this path as well as the terminal.
+	#[arg(long)]
+	pub capture_output: Option<PathBuf>,
+
+	/// Write the command's stderr to this path as well as the terminal.
+	#[arg(long)]
+	pub capture_stderr: Option<PathBuf>,
+
+	/// The path to the executable in the artifact to run.
+	#[arg(short = 'x', long)]
+	pub executable: Option<std::path::PathBuf>,
+
+	/// The number of times to build the target again in a new build if the build fails.
+	#[arg(long, value_name = "N", default_value = "0")]
+	pub retries: u64,
+
+	/// Arguments to pass to the executable.
+	#[arg(index = 2, trailing_var_arg = true)]
+	pub trailing: Vec<String>,
+}
+
+impl Cli {
+	pub async fn command_target_run(&self, mut args: Args) -> tg::Result<()> {
+		let handle = self.handle().await?;
+
+		// Check out the output.
+		args.build.checkout = Some(None);
+
+		// Build the target, building it again on failure up to the number of retries.
+		let mut attempt = 1;
+		let output = loop {
+			let output = self.command_target_build_inner(args.build.clone()).await?;
+			match output {
+				crate::target::build::InnerOutput::Failed(_) if attempt <= args.retries => {
+					eprintln!(
+						"{} attempt {attempt} failed, retrying",
+						"info".blue().bold()
+					);
+					attempt += 1;
+
+					// Retry failed builds so that the next attempt is a new build.
+					args.build.retry = Some(Some(tg::build::Retry::Failed));
+				},
+				crate::target::build::InnerOutput::Failed(error) => {
+					return Err(error);
+				},
+				output => {
+					break output;
+				},
+			}
+		};
+		if attempt > 1 {
+			eprintln!("{} attempt {attempt} succeeded", "info".blue().bold());
+		}
+
+		// Get the path to the artifact.
+		let mut artifact_path = match output {
+			crate::target::build::InnerOutput::Detached(_)
+			| crate::target::build::InnerOutput::Failed(_) => unreachable!(),
+			crate::target::build::InnerOutput::Path(path) => path,
+			crate::target::build::InnerOutput::Value(value) => {
+				let artifact: tg::Artifact = value.try_into().map_err(|source| {
+					tg::error!(!source, "expected the output to be an artifact")
+				})?;
+				let path = self
+					.config
+					.as_ref()
+					.and_then(|config| config.path.clone())
+					.unwrap_or_else(|| {
+						PathBuf::from(std::env::var("HOME").unwrap()).join(".tangram")
+					});
+				path.join("artifacts")
+					.join(artifact.id(&handle).await?.to_string())
+			},
+		};
+
+		// Get the executable path.
+		let executable_path = if let Some(executable_path) = args.executable {
+			// Resolve the argument as a path relative to the artifact.
+			artifact_path.join(executable_path)
+		} else {
+			// If the artifact is a directory, then the executable path should be `.tangram/run`.
+			let metadata = tokio::fs::metadata(&artifact_path)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to stat the artifact"))?;
+			if metadata.is_dir() {
+				artifact_path = artifact_path.join(".tangram/run");
+			}
+			artifact_path
+		};
+
+		// If the output is captured, then spawn the command and tee its output.
+		if args.capture_output.is_some() || args.capture_stderr.is_some() {
+			let code = Self::run_and_capture(
+				&executable_path,
+				args.trailing,
+				args.capture_output,
+				args.capture_stderr,
+			)
+			.await?;
+			std::process::exit(code);
+		}
+
+		// Exec.
+		let error = std::process::Command::new(&executable_path)
+			.args(args.trailing)
+			.exec();
+		Err(
+			tg::error!(source = error, %executable_path = executable_path.display(), "failed to execute the command"),
+		)
+	}
+
+	async fn run_and_capture(
+		executable_path: &Path,
+		args: Vec<String>,
+		stdout_path: Option<PathBuf>,
+		stderr_path: Option<PathBuf>,
+	) -> tg::Result<i32> {
+		// Spawn the command.
+		let stdout = if stdout_path.is_some() {
+			std::process::Stdio::piped()
+		} else {
+			std::process::Stdio::inherit()
+		};
+		let stderr = if stderr_path.is_some() {
+			std::process::Stdio::piped()
+		} else {
+			std::process::Stdio::inherit()
+		};
+		let mut child = tokio::process::Command::new(executable_path)
+			.args(args)
+			.stdin(std::process::Stdio::inherit())
+			.stdout(stdout)
+			.stderr(stderr)
+			.spawn()
+			.map_err(
+				|source| tg::error!(!source, %executable_path = executable_path.display(), "failed to spawn the command"),
+			)?;
+
+		// Tee stdout and stderr.
+		let stdout = child.stdout.take();
+		let stderr = child.stderr.take();
+		let stdout_future = async {
+			if let (Some(path), Some(reader)) = (stdout_path, stdout) {
+				tee(reader, tokio::io::stdout(), &path).await?;
+			}
+			Ok::<_, tg::Error>(())
+		};
+		let stderr_future = async {
+			if let (Some(path), Some(reader)) = (stderr_path, stderr) {
+				tee(reader, tokio::io::stderr(), &path).await?;
+			}
+			Ok::<_, tg::Error>(())
+		};
+		let result = futures::try_join!(stdout_future, stderr_future);
+
+		// If the output could not be written, then kill the command and wait for it to exit, so that it is not left running or unreaped.
+		if let Err(error) = result {
+			child.kill().await.ok();
+			return Err(error);
+		}
+
+		// Wait for the command to exit.
+		let status = child
+			.wait()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to wait for the command"))?;
+		let code = status.code().unwrap_or(1);
+
+		Ok(code)
+	}
+}
+
+/// Copy the reader to the writer and the file at the path. Each chunk is written to the file as it is read, so the file contains all output read so far if the command crashes.
+async fn tee(
+	mut reader: impl AsyncRead + Unpin,
+	mut writer: impl AsyncWrite + Unpin,
+	path: &Path,
+) -> tg::Result<()> {
+	let mut file = tokio::fs::File::create(path).await.map_err(
+		|source| tg::error!(!source, %path = path.display(), "failed to create the file"),
+	)?;
+	let mut buffer = vec![0u8; 8192];
+	loop {
+		let n = reader
+			.read(&mut buffer)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to read the output"))?;
+		if n == 0 {
+			break;
+		}
+		let chunk = &buffer[..n];
+		writer
+			.write_all(chunk)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to write the output"))?;
+		writer
+			.flush()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to flush the output"))?;
+		file.write_all(chunk).await.map_err(
+			|source| tg::error!(!source, %path = path.display(), "failed to write the file"),
+		)?;
+	}
+	file.flush().await.map_err(
+		|source| tg::error!(!source, %path = path.display(), "failed to flush the file"),
+	)?;
+	Ok(())
+}
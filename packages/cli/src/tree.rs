@@ -1,5 +1,8 @@
 use crate::Cli;
-use tangram_client as tg;
+use crossterm::style::Stylize as _;
+use futures::TryStreamExt as _;
+use std::{pin::pin, time::Duration};
+use tangram_client::{self as tg, handle::Ext as _};
 use tangram_either::Either;
 
 /// Display a tree for a build or value.
@@ -10,20 +13,75 @@ pub struct Args {
 	#[arg(long)]
 	pub depth: Option<u32>,
 
+	/// Print the build tree with the time each build took, highlighting builds that took longer than this many seconds.
+	#[arg(long)]
+	pub duration_threshold: Option<f64>,
+
+	/// Print only the builds that took longer than the duration threshold as JSON.
+	#[arg(long, requires = "duration_threshold")]
+	pub json: bool,
+
 	/// If this flag is set, the package's lockfile will not be updated.
 	#[arg(long, default_value = "false")]
 	pub locked: bool,
 
+	/// Only print builds that took longer than the duration threshold and their ancestors.
+	#[arg(long, requires = "duration_threshold")]
+	pub only_slow: bool,
+
+	#[arg(long, requires = "json")]
+	pub pretty: Option<bool>,
+
 	/// The reference to display a tree for.
 	#[arg(index = 1)]
 	pub reference: tg::Reference,
 }
 
+/// A build in a profile of a build tree.
+struct Profile {
+	build: tg::build::Id,
+	children: Vec<Profile>,
+	depth: u32,
+	duration: Option<Duration>,
+	host: String,
+	target: tg::target::Id,
+}
+
+/// A build that took longer than the duration threshold.
+#[derive(Clone, Debug, serde::Serialize)]
+struct Slow {
+	build: tg::build::Id,
+	depth: u32,
+	duration: f64,
+	host: String,
+	target: tg::target::Id,
+}
+
 impl Cli {
 	pub async fn command_tree(&self, args: Args) -> tg::Result<()> {
 		let referent = self.get_reference(&args.reference).await.map_err(
 			|source| tg::error!(!source, %reference = args.reference, "failed to get the reference"),
 		)?;
+
+		// If a duration threshold is set, then print the build tree's profile.
+		if let Some(threshold) = args.duration_threshold {
+			let Either::Left(build) = referent.item else {
+				return Err(tg::error!("expected a build"));
+			};
+			let threshold = Duration::try_from_secs_f64(threshold)
+				.map_err(|source| tg::error!(!source, "invalid duration threshold"))?;
+			let handle = self.handle().await?;
+			let profile = Self::get_build_profile(&handle, build.id(), 0, args.depth).await?;
+			if args.json {
+				let mut output = Vec::new();
+				profile.slow(threshold, &mut output);
+				Self::output_json(&output, args.pretty).await?;
+			} else {
+				profile.print(threshold, args.only_slow);
+			}
+			return Ok(());
+		}
+
 		let options = crate::view::tree::Options {
 			depth: args.depth,
 			objects: true,
@@ -75,4 +133,101 @@ impl Cli {
 
 		Ok(())
 	}
+
+	async fn get_build_profile<H>(
+		handle: &H,
+		build: &tg::build::Id,
+		depth: u32,
+		max_depth: Option<u32>,
+	) -> tg::Result<Profile>
+	where
+		H: tg::Handle,
+	{
+		// Get the build.
+		let output = handle.get_build(build).await?;
+		let duration = output
+			.started_at
+			.zip(output.finished_at)
+			.and_then(|(started_at, finished_at)| (finished_at - started_at).try_into().ok());
+
+		// Get the children's profiles.
+		let mut children = Vec::new();
+		if max_depth.is_none_or(|max_depth| depth < max_depth) {
+			let arg = tg::build::children::get::Arg {
+				position: Some(std::io::SeekFrom::Start(0)),
+				..Default::default()
+			};
+			let stream = handle.get_build_children(build, arg).await?;
+			let mut stream = pin!(stream);
+			while let Some(chunk) = stream.try_next().await? {
+				for child in chunk.data {
+					let profile = Box::pin(Self::get_build_profile(
+						handle,
+						&child,
+						depth + 1,
+						max_depth,
+					))
+					.await?;
+					children.push(profile);
+				}
+			}
+		}
+
+		Ok(Profile {
+			build: build.clone(),
+			children,
+			depth,
+			duration,
+			host: output.host,
+			target: output.target,
+		})
+	}
+}
+
+impl Profile {
+	fn is_slow(&self, threshold: Duration) -> bool {
+		self.duration.is_some_and(|duration| duration > threshold)
+	}
+
+	fn contains_slow(&self, threshold: Duration) -> bool {
+		self.is_slow(threshold)
+			|| self
+				.children
+				.iter()
+				.any(|child| child.contains_slow(threshold))
+	}
+
+	fn print(&self, threshold: Duration, only_slow: bool) {
+		let indent = "  ".repeat(self.depth.try_into().unwrap());
+		let duration = self.duration.map_or_else(
+			|| "∅".to_owned(),
+			|duration| format!("{:.2}s", duration.as_secs_f64()),
+		);
+		let line = format!("{} {} {duration}", self.build, self.host);
+		if self.is_slow(threshold) {
+			println!("{indent}{}", line.red().bold());
+		} else {
+			println!("{indent}{}", line.dim());
+		}
+		for child in &self.children {
+			if !only_slow || child.contains_slow(threshold) {
+				child.print(threshold, only_slow);
+			}
+		}
+	}
+
+	fn slow(&self, threshold: Duration, output: &mut Vec<Slow>) {
+		if self.is_slow(threshold) {
+			output.push(Slow {
+				build: self.build.clone(),
+				depth: self.depth,
+				duration: self.duration.unwrap().as_secs_f64(),
+				host: self.host.clone(),
+				target: self.target.clone(),
+			});
+		}
+		for child in &self.children {
+			child.slow(threshold, output);
+		}
+	}
 }
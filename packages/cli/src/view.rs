@@ -2,7 +2,7 @@ use self::app::App;
 use crate::Cli;
 use crossterm as ct;
 use ratatui as tui;
-use std::{sync::Arc, time::Duration};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 use tangram_client as tg;
 use tangram_either::Either;
 
@@ -10,6 +10,7 @@ mod app;
 mod commands;
 mod data;
 mod detail;
+mod export;
 mod info;
 mod log;
 mod util;
@@ -19,6 +20,14 @@ pub mod tree;
 #[derive(Clone, Debug, clap::Args)]
 #[group(skip)]
 pub struct Args {
+	/// Instead of starting the viewer, write the tree as `tg tree` would print it to this path as an SVG.
+	#[arg(long)]
+	pub export: Option<PathBuf>,
+
+	/// Hide objects of this kind from the tree. Press `f` in the viewer to toggle the filter.
+	#[arg(long)]
+	pub filter: Vec<tree::Filter>,
+
 	/// If this flag is set, the package's lockfile will not be updated.
 	#[arg(long)]
 	pub locked: bool,
@@ -65,7 +74,32 @@ impl Cli {
 			builds: false,
 			collapse_builds_on_success: false,
 		};
+		// If the export arg is set, then write the tree to the path instead of starting the viewer.
+		if let Some(path) = args.export {
+			if path.extension().is_none_or(|extension| extension != "svg") {
+				return Err(
+					tg::error!(%path = path.display(), "only exporting to an SVG file is supported"),
+				);
+			}
+			let options = tree::Options {
+				depth: None,
+				objects: true,
+				builds: true,
+				collapse_builds_on_success: false,
+			};
+			let tree = tree::Tree::new(&handle, item, options);
+			while !tree.is_finished() {
+				tokio::time::sleep(Duration::from_millis(100)).await;
+			}
+			let svg = export::svg(&tree.display().to_string());
+			tokio::fs::write(&path, svg).await.map_err(
+				|source| tg::error!(!source, %path = path.display(), "failed to write the export"),
+			)?;
+			return Ok(());
+		}
+
 		let tree = tree::Tree::new(&handle, item, options);
+		tree.set_filter(args.filter);
 
 		// Start the viewer.
 		let viewer = Viewer::start(&handle, tree).await?;
@@ -303,6 +303,13 @@ where
 		self.tree.collapse_children();
 	}
 
+	pub fn toggle_filter(&self) {
+		let mut state = self.state.write().unwrap();
+		self.tree.toggle_filter();
+		let selected = self.tree.get_selected();
+		state.detail = Detail::new(&self.handle, selected, state.detail_area);
+	}
+
 	pub fn copy_selected_to_clipboard(&self) {
 		let Ok(mut context) = copypasta::ClipboardContext::new() else {
 			return;
@@ -350,11 +357,13 @@ where
 			let rects = layout.split(view_area);
 			let (tree_area, detail_area) = (rects[0], rects[1]);
 			let tree_focus = matches!(state.focus, Focus::Tree);
-			let tree_area = render_block_and_get_area("Tree", tree_focus, tree_area, buf);
+			let title = self.tree.title();
+			let tree_area = render_block_and_get_area(&title, tree_focus, tree_area, buf);
 			self.tree.render(tree_area, buf);
 			state.detail.render(detail_area, buf);
 		} else if matches!(state.focus, Focus::Tree) {
-			let view_area = render_block_and_get_area("Tree", true, view_area, buf);
+			let title = self.tree.title();
+			let view_area = render_block_and_get_area(&title, true, view_area, buf);
 			self.tree.render(view_area, buf);
 		} else {
 			state.detail.render(rect, buf);
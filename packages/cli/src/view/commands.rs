@@ -203,6 +203,15 @@ where
 					app.collapse_children();
 				}),
 			},
+			Command {
+				name: "Filter".to_owned(),
+				description: "Toggle hiding filtered object kinds.".to_owned(),
+				keybindings: vec![KeyBinding {
+					keycode: KeyCode::Char('f'),
+					modifiers: None,
+				}],
+				callback: Box::new(App::toggle_filter),
+			},
 			Command {
 				name: "Push".to_owned(),
 				description: "Replace the root with the selected item.".to_owned(),
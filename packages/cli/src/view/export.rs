@@ -0,0 +1,89 @@
+use std::fmt::Write as _;
+
+/// The font size of the exported text.
+const FONT_SIZE: usize = 14;
+
+/// The height of a line of exported text.
+const LINE_HEIGHT: usize = 18;
+
+/// The approximate width of a character of the monospace font.
+const CHARACTER_WIDTH: f64 = 8.4;
+
+/// The padding around the exported text.
+const PADDING: usize = 8;
+
+/// Render a displayed tree as an SVG, with one line of monospace text per node. Terminal styling is removed.
+pub fn svg(text: &str) -> String {
+	let lines = text.lines().map(strip_ansi).collect::<Vec<_>>();
+	let columns = lines
+		.iter()
+		.map(|line| line.chars().count())
+		.max()
+		.unwrap_or_default();
+	#[allow(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		clippy::cast_precision_loss
+	)]
+	let width = (columns as f64 * CHARACTER_WIDTH).ceil() as usize + 2 * PADDING;
+	let height = lines.len() * LINE_HEIGHT + 2 * PADDING;
+	let mut svg = String::new();
+	writeln!(
+		svg,
+		r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+	)
+	.unwrap();
+	writeln!(
+		svg,
+		r#"<rect width="100%" height="100%" fill="white"/><text font-family="monospace" font-size="{FONT_SIZE}" xml:space="preserve">"#
+	)
+	.unwrap();
+	for (n, line) in lines.iter().enumerate() {
+		let y = PADDING + (n + 1) * LINE_HEIGHT - (LINE_HEIGHT - FONT_SIZE);
+		let line = escape(line);
+		writeln!(svg, r#"<tspan x="{PADDING}" y="{y}">{line}</tspan>"#).unwrap();
+	}
+	svg.push_str("</text>\n</svg>\n");
+	svg
+}
+
+/// Remove ANSI escape sequences from a line.
+fn strip_ansi(line: &str) -> String {
+	let mut output = String::with_capacity(line.len());
+	let mut chars = line.chars();
+	while let Some(c) = chars.next() {
+		if c == '\x1b' {
+			// Skip the escape sequence through its final byte.
+			if chars.next() == Some('[') {
+				for c in chars.by_ref() {
+					if ('@'..='~').contains(&c) {
+						break;
+					}
+				}
+			}
+		} else {
+			output.push(c);
+		}
+	}
+	output
+}
+
+fn escape(line: &str) -> String {
+	line.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn svg_lines() {
+		let text = "\x1b[32m✓\x1b[39m dir_01\n└ a: <file>";
+		let svg = svg(text);
+		assert!(svg.contains(r#"<tspan x="8" y="22">✓ dir_01</tspan>"#));
+		assert!(svg.contains(r#"<tspan x="8" y="40">└ a: &lt;file&gt;</tspan>"#));
+		assert!(!svg.contains('\x1b'));
+	}
+}
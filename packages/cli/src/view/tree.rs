@@ -20,6 +20,9 @@ pub struct Tree<H> {
 }
 
 struct State<H> {
+	filter: Vec<Filter>,
+	filter_enabled: bool,
+	hidden: usize,
 	rect: Rect,
 	roots: Vec<Arc<RwLock<Node<H>>>>,
 	scroll: usize,
@@ -34,6 +37,17 @@ pub struct Options {
 	pub objects: bool,
 }
 
+/// A kind of object to hide from the tree.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub enum Filter {
+	Blob,
+	Directory,
+	File,
+	Symlink,
+	Graph,
+	Target,
+}
+
 type Method<A, R> = Box<dyn FnMut(A) -> BoxFuture<'static, R> + Send + Sync>;
 
 impl<H> Tree<H>
@@ -92,8 +106,24 @@ where
 	}
 
 	fn expanded_nodes(&self) -> Vec<Arc<RwLock<Node<H>>>> {
+		let (nodes, hidden) = self.expanded_nodes_and_hidden_count();
+		self.state.write().unwrap().hidden = hidden;
+		nodes
+	}
+
+	fn expanded_nodes_and_hidden_count(&self) -> (Vec<Arc<RwLock<Node<H>>>>, usize) {
 		let mut nodes = Vec::new();
-		let root = self.state.read().unwrap().roots.last().unwrap().clone();
+		let mut hidden = 0;
+		let (root, filter) = {
+			let state = self.state.read().unwrap();
+			let root = state.roots.last().unwrap().clone();
+			let filter = if state.filter_enabled {
+				state.filter.clone()
+			} else {
+				Vec::new()
+			};
+			(root, filter)
+		};
 		let mut stack = vec![root];
 
 		while let Some(node) = stack.pop() {
@@ -101,10 +131,15 @@ where
 
 			let node = node.read().unwrap();
 
-			// Add the object children.
-			let object_children = node.object_children.iter().flatten().rev().cloned();
+			// Add the object children that are not hidden by the filter.
 			if node.options.objects {
-				stack.extend(object_children);
+				for child in node.object_children.iter().flatten().rev() {
+					if Self::is_hidden(child, &filter) {
+						hidden += 1;
+					} else {
+						stack.push(child.clone());
+					}
+				}
 			}
 
 			// Add build children.
@@ -114,7 +149,15 @@ where
 			}
 		}
 
-		nodes
+		(nodes, hidden)
+	}
+
+	fn is_hidden(node: &Arc<RwLock<Node<H>>>, filter: &[Filter]) -> bool {
+		let node = node.read().unwrap();
+		let Some(Either::Right(tg::Value::Object(object))) = &node.provider.item else {
+			return false;
+		};
+		filter.iter().any(|filter| filter.matches(object))
 	}
 
 	pub fn get_selected(&self) -> Either<tg::Build, tg::Value> {
@@ -127,6 +170,40 @@ where
 			.unwrap()
 	}
 
+	/// Set the kinds of objects to hide. The filter is enabled if any kinds are provided.
+	pub fn set_filter(&self, filter: Vec<Filter>) {
+		let mut state = self.state.write().unwrap();
+		state.filter_enabled = !filter.is_empty();
+		state.filter = filter;
+	}
+
+	/// Toggle hiding objects matched by the filter. If no filter was set, then blobs are hidden.
+	pub fn toggle_filter(&self) {
+		{
+			let mut state = self.state.write().unwrap();
+			if state.filter.is_empty() {
+				state.filter = vec![Filter::Blob];
+			}
+			state.filter_enabled = !state.filter_enabled;
+		}
+
+		// If the selected node is now hidden, then select the root.
+		let nodes = self.expanded_nodes();
+		let selected = self.selected();
+		if !nodes.iter().any(|node| Arc::ptr_eq(node, &selected)) {
+			self.top();
+		}
+	}
+
+	pub fn title(&self) -> String {
+		let state = self.state.read().unwrap();
+		if state.filter_enabled && state.hidden > 0 {
+			format!("Tree ({} hidden)", state.hidden)
+		} else {
+			"Tree".to_owned()
+		}
+	}
+
 	pub fn hit_test(&self, x: u16, y: u16) -> bool {
 		self.state.read().unwrap().rect.contains(Position { x, y })
 	}
@@ -160,6 +237,9 @@ where
 		selected.write().unwrap().selected = true;
 		selected.write().unwrap().is_root = true;
 		let state = State {
+			filter: Vec::new(),
+			filter_enabled: false,
+			hidden: 0,
 			rect: Rect::default(),
 			scroll: 0,
 			roots,
@@ -300,3 +380,17 @@ where
 			.await;
 	}
 }
+
+impl Filter {
+	fn matches(self, object: &tg::Object) -> bool {
+		matches!(
+			(self, object),
+			(Self::Blob, tg::Object::Leaf(_) | tg::Object::Branch(_))
+				| (Self::Directory, tg::Object::Directory(_))
+				| (Self::File, tg::Object::File(_))
+				| (Self::Symlink, tg::Object::Symlink(_))
+				| (Self::Graph, tg::Object::Graph(_))
+				| (Self::Target, tg::Object::Target(_))
+		)
+	}
+}
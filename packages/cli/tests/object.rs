@@ -0,0 +1,208 @@
+use tangram_cli::{assert_output_success, test::test};
+use tangram_temp::{self as temp, Temp};
+
+const TG: &str = env!("CARGO_BIN_EXE_tangram");
+
+/// Test exporting tags from one server and importing them into another.
+#[tokio::test]
+async fn export_and_import_tags() {
+	test(TG, |context| async move {
+		let mut context = context.lock().await;
+
+		// Start the servers.
+		let server1 = context.spawn_server().await.unwrap();
+		let server2 = context.spawn_server().await.unwrap();
+
+		// Create two directories that share a file.
+		let temp = Temp::new();
+		let directory = temp::directory! {
+			"a" => temp::directory! {
+				"a.txt" => "a",
+				"shared.txt" => "shared",
+			},
+			"b" => temp::directory! {
+				"b.txt" => "b",
+				"shared.txt" => "shared",
+			},
+		};
+		directory.to_path(temp.as_ref()).await.unwrap();
+
+		// Tag the directories.
+		for name in ["a", "b"] {
+			let output = server1
+				.tg()
+				.arg("tag")
+				.arg(format!("test/{name}"))
+				.arg(temp.path().join(name))
+				.output()
+				.await
+				.unwrap();
+			assert_output_success!(output);
+		}
+
+		// Export the tags.
+		let export = Temp::new();
+		let output = server1
+			.tg()
+			.arg("object")
+			.arg("export")
+			.arg("--tag")
+			.arg("test/*")
+			.arg(export.path())
+			.output()
+			.await
+			.unwrap();
+		assert_output_success!(output);
+		let stderr = std::str::from_utf8(&output.stderr).unwrap();
+		assert!(stderr.contains("exported 2 tags"), "{stderr}");
+
+		// Import the tags into the other server. Each directory has a file and its blob, and the shared file and its blob are written once.
+		let output = server2
+			.tg()
+			.arg("object")
+			.arg("import")
+			.arg(export.path())
+			.output()
+			.await
+			.unwrap();
+		assert_output_success!(output);
+		let stderr = std::str::from_utf8(&output.stderr).unwrap();
+		assert!(stderr.contains("imported 2 tags and 8 objects"), "{stderr}");
+
+		// Import the tags again, overwriting the existing tags.
+		let output = server2
+			.tg()
+			.arg("object")
+			.arg("import")
+			.arg("--force")
+			.arg(export.path())
+			.output()
+			.await
+			.unwrap();
+		assert_output_success!(output);
+
+		// Verify that the tags refer to the same objects on both servers.
+		for name in ["a", "b"] {
+			let tag = format!("test/{name}");
+			let output1 = server1
+				.tg()
+				.arg("tag")
+				.arg("get")
+				.arg(&tag)
+				.output()
+				.await
+				.unwrap();
+			assert_output_success!(output1);
+			let output2 = server2
+				.tg()
+				.arg("tag")
+				.arg("get")
+				.arg(&tag)
+				.output()
+				.await
+				.unwrap();
+			assert_output_success!(output2);
+			assert_eq!(output1.stdout, output2.stdout);
+		}
+	})
+	.await;
+}
+
+/// Test that re-encoding reports an object whose serialization has changed without rewriting it.
+#[tokio::test]
+async fn reencode_reports_legacy_object() {
+	test(TG, |context| async move {
+		let mut context = context.lock().await;
+
+		// Start the server.
+		let server = context.spawn_server().await.unwrap();
+
+		// Put a directory with a non-canonical encoding.
+		let output = server
+			.tg()
+			.arg("object")
+			.arg("put")
+			.arg("--kind")
+			.arg("directory")
+			.arg(r#"{ "entries": {} }"#)
+			.output()
+			.await
+			.unwrap();
+		assert_output_success!(output);
+		let id = std::str::from_utf8(&output.stdout)
+			.unwrap()
+			.trim()
+			.to_owned();
+
+		// Re-encode the object with a dry run.
+		let output = server
+			.tg()
+			.arg("object")
+			.arg("reencode")
+			.arg("--dry-run")
+			.arg(&id)
+			.output()
+			.await
+			.unwrap();
+		assert_output_success!(output);
+		let stdout = std::str::from_utf8(&output.stdout).unwrap();
+		let (_, new) = stdout
+			.trim()
+			.split_once(" -> ")
+			.unwrap_or_else(|| panic!("{stdout}"));
+		let new = new.to_owned();
+
+		// Verify that re-encoding the object reports the changed ID and fails.
+		let output = server
+			.tg()
+			.arg("object")
+			.arg("reencode")
+			.arg(&id)
+			.output()
+			.await
+			.unwrap();
+		assert!(!output.status.success());
+		let stdout = std::str::from_utf8(&output.stdout).unwrap();
+		assert_eq!(stdout.trim(), format!("{id} -> {new}"));
+
+		// Verify that the new object was not written.
+		let output = server
+			.tg()
+			.arg("object")
+			.arg("get")
+			.arg(&new)
+			.output()
+			.await
+			.unwrap();
+		assert!(!output.status.success());
+
+		// Check in an empty directory, which has the current encoding.
+		let temp = Temp::new();
+		temp::directory! {}.to_path(temp.as_ref()).await.unwrap();
+		let output = server
+			.tg()
+			.arg("checkin")
+			.arg(temp.path())
+			.output()
+			.await
+			.unwrap();
+		assert_output_success!(output);
+		let id = std::str::from_utf8(&output.stdout)
+			.unwrap()
+			.trim()
+			.to_owned();
+
+		// Verify that an object with the current encoding is unchanged.
+		let output = server
+			.tg()
+			.arg("object")
+			.arg("reencode")
+			.arg(&id)
+			.output()
+			.await
+			.unwrap();
+		assert_output_success!(output);
+		assert!(output.stdout.is_empty());
+	})
+	.await;
+}
@@ -1,8 +1,11 @@
 use crate as tg;
 
+pub mod list;
+
 #[derive(Clone, Copy, Debug, serde_with::DeserializeFromStr, serde_with::SerializeDisplay)]
 pub enum Format {
 	Tar,
+	Tgar,
 	Zip,
 }
 
@@ -36,6 +39,9 @@ impl std::fmt::Display for Format {
 			Self::Tar => {
 				write!(f, "tar")?;
 			},
+			Self::Tgar => {
+				write!(f, "tgar")?;
+			},
 			Self::Zip => {
 				write!(f, "zip")?;
 			},
@@ -50,6 +56,7 @@ impl std::str::FromStr for Format {
 	fn from_str(s: &str) -> tg::Result<Self, Self::Err> {
 		match s {
 			"tar" => Ok(Self::Tar),
+			"tgar" => Ok(Self::Tgar),
 			"zip" => Ok(Self::Zip),
 			extension => Err(tg::error!(%extension, "invalid format")),
 		}
@@ -0,0 +1,44 @@
+use crate as tg;
+use std::path::PathBuf;
+use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Arg {
+	pub format: tg::artifact::archive::Format,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Output {
+	pub entries: Vec<Entry>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Entry {
+	pub kind: tg::artifact::Kind,
+	pub path: PathBuf,
+	pub size: u64,
+}
+
+impl tg::Client {
+	pub async fn list_archive_entries(
+		&self,
+		blob: &tg::blob::Id,
+		arg: tg::artifact::archive::list::Arg,
+	) -> tg::Result<tg::artifact::archive::list::Output> {
+		let method = http::Method::GET;
+		let query = serde_urlencoded::to_string(&arg).unwrap();
+		let uri = format!("/blobs/{blob}/entries?{query}");
+		let request = http::request::Builder::default()
+			.method(method)
+			.uri(uri)
+			.empty()
+			.unwrap();
+		let response = self.send(request).await?;
+		if !response.status().is_success() {
+			let error = response.json().await?;
+			return Err(error);
+		}
+		let output = response.json().await?;
+		Ok(output)
+	}
+}
@@ -19,6 +19,13 @@ pub struct Arg {
 	#[serde(default, skip_serializing_if = "is_false")]
 	pub deterministic: bool,
 
+	/// If this is set, then symlinks are replaced by the artifacts they point to instead of being checked in as symlinks.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub follow_symlinks: Option<FollowSymlinks>,
+
+	#[serde(default, skip_serializing_if = "is_false")]
+	pub hash_only: bool,
+
 	#[serde(default = "return_true", skip_serializing_if = "is_true")]
 	pub ignore: bool,
 
@@ -29,6 +36,50 @@ pub struct Arg {
 	pub lockfile: bool,
 
 	pub path: PathBuf,
+
+	/// Which versions of tagged dependencies to prefer when solving. If this is not set, then the newest versions are preferred.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub strategy: Option<Strategy>,
+}
+
+/// Which symlinks to follow during checkin.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Default,
+	Eq,
+	PartialEq,
+	serde_with::DeserializeFromStr,
+	serde_with::SerializeDisplay,
+)]
+pub enum FollowSymlinks {
+	/// Follow symlinks whose targets are within the path being checked in, and error on the rest.
+	#[default]
+	Root,
+
+	/// Follow all symlinks, including those whose targets are outside the path being checked in.
+	All,
+}
+
+/// Which versions of tagged dependencies to prefer when solving.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Default,
+	Eq,
+	PartialEq,
+	serde_with::DeserializeFromStr,
+	serde_with::SerializeDisplay,
+)]
+pub enum Strategy {
+	/// Prefer the newest version that satisfies every constraint.
+	#[default]
+	Newest,
+
+	/// Prefer the oldest version that satisfies every constraint, so that the solution only changes when a constraint does.
+	Minimal,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -102,3 +153,100 @@ impl tg::Client {
 		Ok(stream)
 	}
 }
+
+impl std::fmt::Display for FollowSymlinks {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Root => write!(f, "root"),
+			Self::All => write!(f, "all"),
+		}
+	}
+}
+
+impl std::str::FromStr for FollowSymlinks {
+	type Err = tg::Error;
+
+	fn from_str(s: &str) -> tg::Result<Self, Self::Err> {
+		match s {
+			"root" => Ok(Self::Root),
+			"all" => Ok(Self::All),
+			follow_symlinks => Err(tg::error!(%follow_symlinks, "invalid value")),
+		}
+	}
+}
+
+impl Strategy {
+	/// Select the index of the next candidate to try from tags sorted from oldest to newest. Tags whose version is not a prerelease are tried first, newest first or oldest first if the strategy is minimal. Prereleases are only tried once every release has been tried.
+	#[must_use]
+	pub fn select_candidate(self, tags: &[&tg::Tag]) -> Option<usize> {
+		let is_release = |index: &usize| {
+			!matches!(
+				tags[*index].components().last(),
+				Some(tg::tag::Component::Version(version)) if version.is_prerelease()
+			)
+		};
+		match self {
+			Self::Newest => (0..tags.len())
+				.rev()
+				.find(is_release)
+				.or(tags.len().checked_sub(1)),
+			Self::Minimal => (0..tags.len())
+				.find(is_release)
+				.or((!tags.is_empty()).then_some(0)),
+		}
+	}
+}
+
+impl std::fmt::Display for Strategy {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Newest => write!(f, "newest"),
+			Self::Minimal => write!(f, "minimal"),
+		}
+	}
+}
+
+impl std::str::FromStr for Strategy {
+	type Err = tg::Error;
+
+	fn from_str(s: &str) -> tg::Result<Self, Self::Err> {
+		match s {
+			"newest" => Ok(Self::Newest),
+			"minimal" => Ok(Self::Minimal),
+			strategy => Err(tg::error!(%strategy, "invalid value")),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Strategy;
+	use crate as tg;
+
+	#[test]
+	fn select_candidate_prefers_releases() {
+		let tags = ["a/1.0.0", "a/1.1.0", "a/2.0.0-beta.1", "a/2.0.0-beta.2"]
+			.into_iter()
+			.map(|tag| tag.parse::<tg::Tag>().unwrap())
+			.collect::<Vec<_>>();
+		let tags = tags.iter().collect::<Vec<_>>();
+
+		// The newest release is preferred over newer prereleases.
+		let index = Strategy::Newest.select_candidate(&tags);
+		assert_eq!(index, Some(1));
+
+		// The oldest release is preferred if the strategy is minimal.
+		let index = Strategy::Minimal.select_candidate(&tags);
+		assert_eq!(index, Some(0));
+
+		// Prereleases are tried once there are no releases.
+		let index = Strategy::Newest.select_candidate(&tags[2..]);
+		assert_eq!(index, Some(1));
+		let index = Strategy::Minimal.select_candidate(&tags[2..]);
+		assert_eq!(index, Some(0));
+
+		// There is no candidate if there are no tags.
+		let index = Strategy::Newest.select_candidate(&[]);
+		assert_eq!(index, None);
+	}
+}
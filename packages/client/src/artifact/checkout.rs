@@ -9,6 +9,10 @@ use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct Arg {
+	/// Whether to cache the artifact in the artifacts directory if no path is set. If this is false, then the checkout fails if the artifact is not already in the artifacts directory.
+	#[serde(default = "return_true", skip_serializing_if = "is_true")]
+	pub cache: bool,
+
 	/// Whether to check out the artifact's dependencies.
 	#[serde(default = "return_true", skip_serializing_if = "is_true")]
 	pub dependencies: bool,
@@ -99,6 +103,7 @@ impl tg::Client {
 impl Default for Arg {
 	fn default() -> Self {
 		Self {
+			cache: true,
 			force: false,
 			path: None,
 			dependencies: true,
@@ -1,7 +1,9 @@
 use crate as tg;
 
 /// An artifact kind.
-#[derive(Clone, Copy, Debug)]
+#[derive(
+	Clone, Copy, Debug, Eq, PartialEq, serde_with::DeserializeFromStr, serde_with::SerializeDisplay,
+)]
 pub enum Kind {
 	Directory,
 	File,
@@ -9,6 +9,7 @@ pub mod get;
 pub mod heartbeat;
 pub mod log;
 pub mod outcome;
+pub mod progress;
 pub mod pull;
 pub mod push;
 pub mod put;
@@ -1,11 +1,15 @@
 use crate::{self as tg, handle::Ext as _, util::serde::SeekFromString};
 use futures::{future, stream, Stream, StreamExt as _, TryStreamExt as _};
-use serde_with::serde_as;
+use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
 use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
 
 #[serde_as]
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
 pub struct Arg {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	#[serde_as(as = "Option<StringWithSeparator<CommaSeparator, String>>")]
+	pub labels: Option<Vec<String>>,
+
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub length: Option<u64>,
 
@@ -1,5 +1,6 @@
 use crate as tg;
 use serde_with::serde_as;
+use std::collections::BTreeMap;
 use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
 use time::format_description::well_known::Rfc3339;
 
@@ -15,6 +16,9 @@ pub struct Output {
 
 	pub host: String,
 
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub labels: BTreeMap<String, String>,
+
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub log: Option<tg::blob::Id>,
 
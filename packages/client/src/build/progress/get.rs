@@ -0,0 +1,121 @@
+use crate as tg;
+use futures::{future, Stream, TryStreamExt as _};
+use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
+
+/// An event in a build's progress stream. Progress values are not stored, so a stream only contains the values that are added after it is created, and it ends when the build finishes.
+#[derive(Clone, Debug)]
+pub enum Event {
+	Value(serde_json::Value),
+	End,
+}
+
+impl tg::Build {
+	pub async fn progress<H>(
+		&self,
+		handle: &H,
+	) -> tg::Result<impl Stream<Item = tg::Result<serde_json::Value>> + Send + 'static>
+	where
+		H: tg::Handle,
+	{
+		let stream = handle
+			.try_get_build_progress_stream(self.id())
+			.await?
+			.ok_or_else(|| tg::error!("failed to get the build"))?
+			.try_take_while(|event| future::ok(matches!(event, Event::Value(_))))
+			.map_ok(|event| match event {
+				Event::Value(value) => value,
+				Event::End => unreachable!(),
+			});
+		Ok(stream)
+	}
+}
+
+impl tg::Client {
+	pub async fn try_get_build_progress_stream(
+		&self,
+		id: &tg::build::Id,
+	) -> tg::Result<
+		Option<impl Stream<Item = tg::Result<tg::build::progress::get::Event>> + Send + 'static>,
+	> {
+		let method = http::Method::GET;
+		let uri = format!("/builds/{id}/progress");
+		let request = http::request::Builder::default()
+			.method(method)
+			.uri(uri)
+			.header(http::header::ACCEPT, mime::TEXT_EVENT_STREAM.to_string())
+			.empty()
+			.unwrap();
+		let response = self.send(request).await?;
+		if response.status() == http::StatusCode::NOT_FOUND {
+			return Ok(None);
+		}
+		if !response.status().is_success() {
+			let error = response.json().await?;
+			return Err(error);
+		}
+		let content_type = response
+			.parse_header::<mime::Mime, _>(http::header::CONTENT_TYPE)
+			.transpose()?;
+		if !matches!(
+			content_type
+				.as_ref()
+				.map(|content_type| (content_type.type_(), content_type.subtype())),
+			Some((mime::TEXT, mime::EVENT_STREAM)),
+		) {
+			return Err(tg::error!(?content_type, "invalid content type"));
+		}
+		let stream = response
+			.sse()
+			.map_err(|source| tg::error!(!source, "failed to read an event"))
+			.and_then(|event| {
+				future::ready(
+					if event.event.as_deref().is_some_and(|event| event == "error") {
+						match event.try_into() {
+							Ok(error) | Err(error) => Err(error),
+						}
+					} else {
+						event.try_into()
+					},
+				)
+			});
+		Ok(Some(stream))
+	}
+}
+
+impl TryFrom<Event> for tangram_http::sse::Event {
+	type Error = tg::Error;
+
+	fn try_from(value: Event) -> Result<Self, Self::Error> {
+		let event = match value {
+			Event::Value(value) => {
+				let data = serde_json::to_string(&value)
+					.map_err(|source| tg::error!(!source, "failed to serialize the event"))?;
+				tangram_http::sse::Event {
+					data,
+					..Default::default()
+				}
+			},
+			Event::End => tangram_http::sse::Event {
+				event: Some("end".to_owned()),
+				..Default::default()
+			},
+		};
+		Ok(event)
+	}
+}
+
+impl TryFrom<tangram_http::sse::Event> for Event {
+	type Error = tg::Error;
+
+	fn try_from(value: tangram_http::sse::Event) -> tg::Result<Self> {
+		match value.event.as_deref() {
+			None => {
+				let value = serde_json::from_str(&value.data)
+					.map_err(|source| tg::error!(!source, "failed to deserialize the event"))?;
+				Ok(Self::Value(value))
+			},
+			Some("end") => Ok(Self::End),
+			_ => Err(tg::error!("invalid event")),
+		}
+	}
+}
@@ -0,0 +1,48 @@
+use crate as tg;
+use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Arg {
+	pub value: serde_json::Value,
+}
+
+impl tg::Build {
+	pub async fn add_progress<H>(
+		&self,
+		handle: &H,
+		arg: tg::build::progress::post::Arg,
+	) -> tg::Result<()>
+	where
+		H: tg::Handle,
+	{
+		let id = self.id();
+		handle.add_build_progress(id, arg).await?;
+		Ok(())
+	}
+}
+
+impl tg::Client {
+	pub async fn add_build_progress(
+		&self,
+		id: &tg::build::Id,
+		arg: tg::build::progress::post::Arg,
+	) -> tg::Result<()> {
+		let method = http::Method::POST;
+		let uri = format!("/builds/{id}/progress");
+		let request = http::request::Builder::default()
+			.method(method)
+			.uri(uri)
+			.header(
+				http::header::CONTENT_TYPE,
+				mime::APPLICATION_JSON.to_string(),
+			)
+			.json(arg)
+			.unwrap();
+		let response = self.send(request).await?;
+		if !response.status().is_success() {
+			let error = response.json().await?;
+			return Err(error);
+		}
+		Ok(())
+	}
+}
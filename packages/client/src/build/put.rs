@@ -11,6 +11,8 @@ pub struct Arg {
 	pub children: Vec<tg::build::Id>,
 	pub depth: u64,
 	pub host: String,
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub labels: BTreeMap<String, String>,
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub log: Option<tg::blob::Id>,
 	#[serde(default, skip_serializing_if = "Option::is_none")]
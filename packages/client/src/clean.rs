@@ -1,20 +1,48 @@
 use crate as tg;
+use tangram_either::Either;
 use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
 
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct Arg {
+	/// Before cleaning, keep only this many of the most recently created tags under `tag_prefix` and delete the rest.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub keep_last: Option<u64>,
+
+	/// If this is not empty, then only remove the items and the items in their closures that are no longer referenced, instead of all unused builds and objects.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub roots: Vec<Either<tg::build::Id, tg::object::Id>>,
+
+	/// The prefix of the tags that `keep_last` applies to.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub tag_prefix: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct Output {
+	/// The number of bytes of object data that were removed.
+	#[serde(default)]
+	pub bytes: u64,
+
+	/// The tags that were deleted in order to honor `keep_last`.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub pruned: Vec<tg::Tag>,
+}
+
 impl tg::Client {
-	pub async fn clean(&self) -> tg::Result<()> {
+	pub async fn clean(&self, arg: tg::clean::Arg) -> tg::Result<tg::clean::Output> {
 		let method = http::Method::POST;
 		let uri = "/clean";
 		let request = http::request::Builder::default()
 			.method(method)
 			.uri(uri)
-			.empty()
+			.json(arg)
 			.unwrap();
 		let response = self.send(request).await?;
 		if !response.status().is_success() {
 			let error = response.json().await?;
 			return Err(error);
 		}
-		Ok(())
+		let output = response.json().await?;
+		Ok(output)
 	}
 }
@@ -1,7 +1,11 @@
 use super::{Data, Id, Object};
 use crate as tg;
 use futures::{stream::FuturesOrdered, TryStreamExt as _};
-use std::sync::Arc;
+use std::{
+	collections::{BTreeMap, HashMap},
+	sync::Arc,
+};
+use tangram_either::Either;
 
 #[derive(Clone, Debug)]
 pub struct Graph {
@@ -123,6 +127,232 @@ impl Graph {
 			.await?;
 		Ok(Data { nodes })
 	}
+
+	/// Create a graph that contains the artifact and every artifact it references. Return the graph and the index of the artifact's node.
+	pub async fn with_artifact<H>(handle: &H, artifact: &tg::Artifact) -> tg::Result<(Self, usize)>
+	where
+		H: tg::Handle,
+	{
+		let mut state = CollapseState::default();
+		let node = Self::collapse_artifact(handle, &mut state, artifact).await?;
+		let nodes = state
+			.nodes
+			.into_iter()
+			.map(|node| node.ok_or_else(|| tg::error!("expected the node to be set")))
+			.collect::<tg::Result<_>>()?;
+		let graph = Self::with_object(Object { nodes });
+		Ok((graph, node))
+	}
+
+	async fn collapse_artifact<H>(
+		handle: &H,
+		state: &mut CollapseState,
+		artifact: &tg::Artifact,
+	) -> tg::Result<usize>
+	where
+		H: tg::Handle,
+	{
+		// If the artifact is a node of a graph, then collapse the graph's node.
+		let graph_and_node = match artifact {
+			tg::Artifact::Directory(directory) => match directory.object(handle).await?.as_ref() {
+				tg::directory::Object::Graph { graph, node } => Some((graph.clone(), *node)),
+				tg::directory::Object::Normal { .. } => None,
+			},
+			tg::Artifact::File(file) => match file.object(handle).await?.as_ref() {
+				tg::file::Object::Graph { graph, node } => Some((graph.clone(), *node)),
+				tg::file::Object::Normal { .. } => None,
+			},
+			tg::Artifact::Symlink(symlink) => match symlink.object(handle).await?.as_ref() {
+				tg::symlink::Object::Graph { graph, node } => Some((graph.clone(), *node)),
+				tg::symlink::Object::Target { .. } | tg::symlink::Object::Artifact { .. } => None,
+			},
+		};
+		if let Some((graph, node)) = graph_and_node {
+			return Box::pin(Self::collapse_graph_node(handle, state, &graph, node)).await;
+		}
+
+		// Reserve the artifact's node.
+		let key = CollapseKey::Artifact(artifact.id(handle).await?);
+		if let Some(index) = state.visited.get(&key) {
+			return Ok(*index);
+		}
+		let index = state.nodes.len();
+		state.nodes.push(None);
+		state.visited.insert(key, index);
+
+		// Create the node.
+		let node = match artifact {
+			tg::Artifact::Directory(directory) => {
+				let mut entries = BTreeMap::new();
+				for (name, artifact) in directory.entries(handle).await? {
+					let index = Box::pin(Self::collapse_artifact(handle, state, &artifact)).await?;
+					entries.insert(name, Either::Left(index));
+				}
+				tg::graph::Node::Directory(tg::graph::object::Directory { entries })
+			},
+			tg::Artifact::File(file) => {
+				let object = file.object(handle).await?;
+				let tg::file::Object::Normal {
+					contents,
+					dependencies,
+					executable,
+				} = object.as_ref()
+				else {
+					unreachable!()
+				};
+				let mut dependencies_ = BTreeMap::new();
+				for (reference, referent) in dependencies {
+					let item = match tg::Artifact::try_from(referent.item.clone()) {
+						Ok(artifact) => Either::Left(
+							Box::pin(Self::collapse_artifact(handle, state, &artifact)).await?,
+						),
+						Err(_) => Either::Right(referent.item.clone()),
+					};
+					let referent = tg::Referent {
+						item,
+						path: referent.path.clone(),
+						subpath: referent.subpath.clone(),
+						tag: referent.tag.clone(),
+					};
+					dependencies_.insert(reference.clone(), referent);
+				}
+				tg::graph::Node::File(tg::graph::object::File {
+					contents: contents.clone(),
+					dependencies: dependencies_,
+					executable: *executable,
+				})
+			},
+			tg::Artifact::Symlink(symlink) => match symlink.object(handle).await?.as_ref() {
+				tg::symlink::Object::Target { target } => {
+					tg::graph::Node::Symlink(tg::graph::object::Symlink::Target {
+						target: target.clone(),
+					})
+				},
+				tg::symlink::Object::Artifact { artifact, subpath } => {
+					let index = Box::pin(Self::collapse_artifact(handle, state, artifact)).await?;
+					tg::graph::Node::Symlink(tg::graph::object::Symlink::Artifact {
+						artifact: Either::Left(index),
+						subpath: subpath.clone(),
+					})
+				},
+				tg::symlink::Object::Graph { .. } => unreachable!(),
+			},
+		};
+		state.nodes[index] = Some(node);
+
+		Ok(index)
+	}
+
+	async fn collapse_graph_node<H>(
+		handle: &H,
+		state: &mut CollapseState,
+		graph: &Self,
+		node: usize,
+	) -> tg::Result<usize>
+	where
+		H: tg::Handle,
+	{
+		// Reserve the graph node's node.
+		let key = CollapseKey::Node(graph.id(handle).await?, node);
+		if let Some(index) = state.visited.get(&key) {
+			return Ok(*index);
+		}
+		let index = state.nodes.len();
+		state.nodes.push(None);
+		state.visited.insert(key, index);
+
+		// Get the graph's node.
+		let object = graph.object(handle).await?;
+		let node = object
+			.nodes
+			.get(node)
+			.ok_or_else(|| tg::error!("invalid graph node"))?;
+
+		// Create the node.
+		let node = match node {
+			tg::graph::Node::Directory(directory) => {
+				let mut entries = BTreeMap::new();
+				for (name, either) in &directory.entries {
+					let index = match either {
+						Either::Left(node) => {
+							Box::pin(Self::collapse_graph_node(handle, state, graph, *node)).await?
+						},
+						Either::Right(artifact) => {
+							Box::pin(Self::collapse_artifact(handle, state, artifact)).await?
+						},
+					};
+					entries.insert(name.clone(), Either::Left(index));
+				}
+				tg::graph::Node::Directory(tg::graph::object::Directory { entries })
+			},
+			tg::graph::Node::File(file) => {
+				let mut dependencies = BTreeMap::new();
+				for (reference, referent) in &file.dependencies {
+					let item = match &referent.item {
+						Either::Left(node) => Either::Left(
+							Box::pin(Self::collapse_graph_node(handle, state, graph, *node))
+								.await?,
+						),
+						Either::Right(object) => match tg::Artifact::try_from(object.clone()) {
+							Ok(artifact) => Either::Left(
+								Box::pin(Self::collapse_artifact(handle, state, &artifact)).await?,
+							),
+							Err(_) => Either::Right(object.clone()),
+						},
+					};
+					let referent = tg::Referent {
+						item,
+						path: referent.path.clone(),
+						subpath: referent.subpath.clone(),
+						tag: referent.tag.clone(),
+					};
+					dependencies.insert(reference.clone(), referent);
+				}
+				tg::graph::Node::File(tg::graph::object::File {
+					contents: file.contents.clone(),
+					dependencies,
+					executable: file.executable,
+				})
+			},
+			tg::graph::Node::Symlink(tg::graph::object::Symlink::Target { target }) => {
+				tg::graph::Node::Symlink(tg::graph::object::Symlink::Target {
+					target: target.clone(),
+				})
+			},
+			tg::graph::Node::Symlink(tg::graph::object::Symlink::Artifact {
+				artifact,
+				subpath,
+			}) => {
+				let index = match artifact {
+					Either::Left(node) => {
+						Box::pin(Self::collapse_graph_node(handle, state, graph, *node)).await?
+					},
+					Either::Right(artifact) => {
+						Box::pin(Self::collapse_artifact(handle, state, artifact)).await?
+					},
+				};
+				tg::graph::Node::Symlink(tg::graph::object::Symlink::Artifact {
+					artifact: Either::Left(index),
+					subpath: subpath.clone(),
+				})
+			},
+		};
+		state.nodes[index] = Some(node);
+
+		Ok(index)
+	}
+}
+
+#[derive(Default)]
+struct CollapseState {
+	nodes: Vec<Option<tg::graph::Node>>,
+	visited: HashMap<CollapseKey, usize>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum CollapseKey {
+	Artifact(tg::artifact::Id),
+	Node(Id, usize),
 }
 
 impl std::fmt::Display for Graph {
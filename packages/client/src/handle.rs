@@ -47,6 +47,12 @@ pub trait Handle: Clone + Unpin + Send + Sync + 'static {
 		>,
 	> + Send;
 
+	fn list_archive_entries(
+		&self,
+		blob: &tg::blob::Id,
+		arg: tg::artifact::archive::list::Arg,
+	) -> impl Future<Output = tg::Result<tg::artifact::archive::list::Output>> + Send;
+
 	fn try_get_build(
 		&self,
 		id: &tg::build::Id,
@@ -126,6 +132,23 @@ pub trait Handle: Clone + Unpin + Send + Sync + 'static {
 		arg: tg::build::log::post::Arg,
 	) -> impl Future<Output = tg::Result<()>> + Send;
 
+	fn try_get_build_progress_stream(
+		&self,
+		id: &tg::build::Id,
+	) -> impl Future<
+		Output = tg::Result<
+			Option<
+				impl Stream<Item = tg::Result<tg::build::progress::get::Event>> + Send + 'static,
+			>,
+		>,
+	> + Send;
+
+	fn add_build_progress(
+		&self,
+		id: &tg::build::Id,
+		arg: tg::build::progress::post::Arg,
+	) -> impl Future<Output = tg::Result<()>> + Send;
+
 	fn try_get_build_outcome_future(
 		&self,
 		id: &tg::build::Id,
@@ -159,6 +182,11 @@ pub trait Handle: Clone + Unpin + Send + Sync + 'static {
 		output: impl AsyncWrite + Send + Unpin + 'static,
 	) -> impl Future<Output = tg::Result<()>> + Send;
 
+	fn find_objects(
+		&self,
+		arg: tg::object::find::Arg,
+	) -> impl Future<Output = tg::Result<tg::object::find::Output>> + Send;
+
 	fn try_get_object_metadata(
 		&self,
 		id: &tg::object::Id,
@@ -256,7 +284,10 @@ pub trait Handle: Clone + Unpin + Send + Sync + 'static {
 
 	fn health(&self) -> impl Future<Output = tg::Result<tg::Health>> + Send;
 
-	fn clean(&self) -> impl Future<Output = tg::Result<()>> + Send;
+	fn clean(
+		&self,
+		arg: tg::clean::Arg,
+	) -> impl Future<Output = tg::Result<tg::clean::Output>> + Send;
 
 	fn list_tags(
 		&self,
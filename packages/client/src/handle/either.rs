@@ -84,6 +84,17 @@ where
 		}
 	}
 
+	fn list_archive_entries(
+		&self,
+		blob: &tg::blob::Id,
+		arg: tg::artifact::archive::list::Arg,
+	) -> impl Future<Output = tg::Result<tg::artifact::archive::list::Output>> {
+		match self {
+			Either::Left(s) => s.list_archive_entries(blob, arg).left_future(),
+			Either::Right(s) => s.list_archive_entries(blob, arg).right_future(),
+		}
+	}
+
 	fn try_get_build(
 		&self,
 		id: &tg::build::Id,
@@ -241,6 +252,35 @@ where
 		}
 	}
 
+	async fn try_get_build_progress_stream(
+		&self,
+		id: &tg::build::Id,
+	) -> tg::Result<
+		Option<impl Stream<Item = tg::Result<tg::build::progress::get::Event>> + Send + 'static>,
+	> {
+		match self {
+			Either::Left(s) => s
+				.try_get_build_progress_stream(id)
+				.await
+				.map(|option| option.map(futures::StreamExt::left_stream)),
+			Either::Right(s) => s
+				.try_get_build_progress_stream(id)
+				.await
+				.map(|option| option.map(futures::StreamExt::right_stream)),
+		}
+	}
+
+	fn add_build_progress(
+		&self,
+		id: &tg::build::Id,
+		arg: tg::build::progress::post::Arg,
+	) -> impl Future<Output = tg::Result<()>> {
+		match self {
+			Either::Left(s) => s.add_build_progress(id, arg).left_future(),
+			Either::Right(s) => s.add_build_progress(id, arg).right_future(),
+		}
+	}
+
 	fn try_get_build_outcome_future(
 		&self,
 		id: &tg::build::Id,
@@ -305,6 +345,16 @@ where
 		}
 	}
 
+	fn find_objects(
+		&self,
+		arg: tg::object::find::Arg,
+	) -> impl Future<Output = tg::Result<tg::object::find::Output>> {
+		match self {
+			Either::Left(s) => s.find_objects(arg).left_future(),
+			Either::Right(s) => s.find_objects(arg).right_future(),
+		}
+	}
+
 	fn try_get_object_metadata(
 		&self,
 		id: &tg::object::Id,
@@ -511,10 +561,10 @@ where
 		}
 	}
 
-	fn clean(&self) -> impl Future<Output = tg::Result<()>> {
+	fn clean(&self, arg: tg::clean::Arg) -> impl Future<Output = tg::Result<tg::clean::Output>> {
 		match self {
-			Either::Left(s) => s.clean().left_future(),
-			Either::Right(s) => s.clean().right_future(),
+			Either::Left(s) => s.clean(arg).left_future(),
+			Either::Right(s) => s.clean(arg).right_future(),
 		}
 	}
 
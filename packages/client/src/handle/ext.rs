@@ -1,8 +1,9 @@
 use crate as tg;
+use bytes::Bytes;
 use futures::{
 	future,
 	stream::{self, BoxStream},
-	Future, FutureExt as _, Stream, StreamExt as _, TryStreamExt as _,
+	Future, FutureExt as _, Stream, StreamExt as _, TryFutureExt as _, TryStreamExt as _,
 };
 use num::ToPrimitive as _;
 use std::{
@@ -443,6 +444,25 @@ pub trait Ext: tg::Handle {
 		})
 	}
 
+	/// Get an object's bytes exactly as they are stored. These are the object's content-addressed representation, so `tg::object::Id::new(id.kind(), &bytes)` equals `id`, and a proxy can cache and serve them verbatim.
+	fn try_get_object_bytes(
+		&self,
+		id: &tg::object::Id,
+	) -> impl Future<Output = tg::Result<Option<Bytes>>> + Send {
+		self.try_get_object(id)
+			.map_ok(|option| option.map(|output| output.bytes))
+	}
+
+	/// Get an object's bytes exactly as they are stored. See `try_get_object_bytes`.
+	fn get_object_bytes(
+		&self,
+		id: &tg::object::Id,
+	) -> impl Future<Output = tg::Result<Bytes>> + Send {
+		self.try_get_object_bytes(id).map(|result| {
+			result.and_then(|option| option.ok_or_else(|| tg::error!("failed to get the object")))
+		})
+	}
+
 	fn get_reference(
 		&self,
 		reference: &tg::Reference,
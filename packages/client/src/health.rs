@@ -5,7 +5,11 @@ use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
 pub struct Health {
 	pub builds: Option<Builds>,
 	pub database: Option<Database>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub errors: Vec<Error>,
 	pub file_descriptor_semaphore: Option<FileDescriptorSemaphore>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub objects: Option<Objects>,
 	pub version: Option<String>,
 }
 
@@ -21,11 +25,28 @@ pub struct Database {
 	pub available_connections: u64,
 }
 
+/// A failure in one of the server's subsystems.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Error {
+	pub subsystem: String,
+	pub message: String,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct FileDescriptorSemaphore {
 	pub available_permits: u64,
 }
 
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct Objects {
+	pub bytes_read: u64,
+	pub bytes_written: u64,
+	pub get_hits: u64,
+	pub get_misses: u64,
+	pub gets: u64,
+	pub puts: u64,
+}
+
 impl tg::Client {
 	pub async fn health(&self) -> tg::Result<Health> {
 		let method = http::Method::GET;
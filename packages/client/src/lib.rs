@@ -1,7 +1,6 @@
 use crate as tg;
 use futures::{Future, FutureExt as _, Stream};
 use std::{
-	collections::VecDeque,
 	path::{Path, PathBuf},
 	sync::Arc,
 	time::Duration,
@@ -92,14 +91,34 @@ pub struct Client(Arc<Inner>);
 #[derive(Debug)]
 pub struct Inner {
 	url: Url,
+	retries: Retries,
 	sender: tokio::sync::Mutex<Option<hyper::client::conn::http2::SendRequest<Outgoing>>>,
 }
 
+/// The number of times the client retries a request that fails with a connection error or a server error.
+#[derive(Clone, Copy, Debug)]
+pub struct Retries {
+	/// The number of retries for idempotent requests, which are those with a `GET` or `HEAD` method.
+	pub idempotent: u32,
+
+	/// The number of retries for all other requests.
+	pub other: u32,
+}
+
 impl Client {
 	#[must_use]
 	pub fn new(url: Url) -> Self {
+		Self::with_retries(url, Retries::default())
+	}
+
+	#[must_use]
+	pub fn with_retries(url: Url, retries: Retries) -> Self {
 		let sender = tokio::sync::Mutex::new(None);
-		Self(Arc::new(Inner { url, sender }))
+		Self(Arc::new(Inner {
+			url,
+			retries,
+			sender,
+		}))
 	}
 
 	pub fn with_env() -> tg::Result<Self> {
@@ -525,8 +544,14 @@ impl Client {
 	}
 
 	async fn send(&self, request: http::Request<Outgoing>) -> tg::Result<http::Response<Incoming>> {
-		if request.body().try_clone().is_some() {
-			self.send_with_retry(request).await
+		let idempotent = matches!(*request.method(), http::Method::GET | http::Method::HEAD);
+		let retries = if idempotent {
+			self.retries.idempotent
+		} else {
+			self.retries.other
+		};
+		if retries > 0 && request.body().try_clone().is_some() {
+			self.send_with_retry(request, retries).await
 		} else {
 			self.send_without_retry(request).await
 		}
@@ -535,21 +560,23 @@ impl Client {
 	async fn send_with_retry(
 		&self,
 		request: http::Request<Outgoing>,
+		retries: u32,
 	) -> tg::Result<http::Response<Incoming>> {
-		let mut retries = VecDeque::from([100, 1000]);
 		let (head, body) = request.into_parts();
+		let mut attempt = 0;
 		loop {
 			let request = http::Request::from_parts(head.clone(), body.try_clone().unwrap());
 			let result = self.send_without_retry(request).await;
 			let is_error = result.is_err();
 			let is_server_error =
 				matches!(&result, Ok(response) if response.status().is_server_error());
-			if is_error || is_server_error {
-				if let Some(duration) = retries.pop_front() {
-					let duration = Duration::from_millis(duration);
-					tokio::time::sleep(duration).await;
-					continue;
-				}
+			if (is_error || is_server_error) && attempt < retries {
+				let duration = Duration::from_millis(100)
+					.saturating_mul(10u32.saturating_pow(attempt))
+					.min(Duration::from_secs(10));
+				tokio::time::sleep(duration).await;
+				attempt += 1;
+				continue;
 			}
 			return result;
 		}
@@ -568,6 +595,15 @@ impl Client {
 	}
 }
 
+impl Default for Retries {
+	fn default() -> Self {
+		Self {
+			idempotent: 2,
+			other: 0,
+		}
+	}
+}
+
 impl tg::Handle for Client {
 	fn check_in_artifact(
 		&self,
@@ -615,6 +651,14 @@ impl tg::Handle for Client {
 		self.try_read_blob_stream(id, arg)
 	}
 
+	fn list_archive_entries(
+		&self,
+		blob: &tg::blob::Id,
+		arg: tg::artifact::archive::list::Arg,
+	) -> impl Future<Output = tg::Result<tg::artifact::archive::list::Output>> {
+		self.list_archive_entries(blob, arg)
+	}
+
 	fn try_get_build(
 		&self,
 		id: &tg::build::Id,
@@ -720,6 +764,25 @@ impl tg::Handle for Client {
 		self.add_build_log(id, arg)
 	}
 
+	fn try_get_build_progress_stream(
+		&self,
+		id: &tg::build::Id,
+	) -> impl Future<
+		Output = tg::Result<
+			Option<impl Stream<Item = Result<tg::build::progress::get::Event>> + Send + 'static>,
+		>,
+	> {
+		self.try_get_build_progress_stream(id)
+	}
+
+	fn add_build_progress(
+		&self,
+		id: &tg::build::Id,
+		arg: tg::build::progress::post::Arg,
+	) -> impl Future<Output = tg::Result<()>> {
+		self.add_build_progress(id, arg)
+	}
+
 	fn try_get_build_outcome_future(
 		&self,
 		id: &tg::build::Id,
@@ -755,6 +818,13 @@ impl tg::Handle for Client {
 		self.lsp(input, output)
 	}
 
+	fn find_objects(
+		&self,
+		arg: tg::object::find::Arg,
+	) -> impl Future<Output = tg::Result<tg::object::find::Output>> {
+		self.find_objects(arg)
+	}
+
 	fn try_get_object_metadata(
 		&self,
 		id: &tg::object::Id,
@@ -886,8 +956,8 @@ impl tg::Handle for Client {
 		self.health()
 	}
 
-	fn clean(&self) -> impl Future<Output = tg::Result<()>> {
-		self.clean()
+	fn clean(&self, arg: tg::clean::Arg) -> impl Future<Output = tg::Result<tg::clean::Output>> {
+		self.clean(arg)
 	}
 
 	fn list_tags(
@@ -5,6 +5,7 @@ pub use self::{
 
 pub mod data;
 pub mod export;
+pub mod find;
 pub mod get;
 pub mod handle;
 pub mod id;
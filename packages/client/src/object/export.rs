@@ -4,6 +4,10 @@ use tokio::io::AsyncRead;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Arg {
+	/// Other objects to write to the archive after the object. An object that is in the closure of more than one is written once.
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub objects: Vec<tg::object::Id>,
+
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	pub remote: Option<String>,
 }
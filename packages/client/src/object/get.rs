@@ -6,6 +6,7 @@ use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
 #[serde_as]
 #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Hash, serde::Deserialize, serde::Serialize)]
 pub struct Output {
+	/// The object's bytes exactly as they are stored, which is the content-addressed representation from which the object's ID is computed.
 	#[serde_as(as = "BytesBase64")]
 	pub bytes: Bytes,
 	pub metadata: tg::object::Metadata,
@@ -12,6 +12,10 @@ pub struct Arg {
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Output {
 	pub object: tg::object::Id,
+
+	/// The number of objects in the archive.
+	#[serde(default)]
+	pub count: u64,
 }
 
 impl tg::Client {
@@ -1,6 +1,8 @@
 use crate as tg;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(
+	Clone, Copy, Debug, Eq, PartialEq, serde_with::DeserializeFromStr, serde_with::SerializeDisplay,
+)]
 pub enum Kind {
 	Leaf,
 	Branch,
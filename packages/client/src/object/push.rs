@@ -1,10 +1,14 @@
-use crate as tg;
+use crate::{self as tg, util::serde::is_false};
 use futures::{future, Stream, StreamExt as _, TryStreamExt as _};
 use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct Arg {
 	pub remote: String,
+
+	/// Whether to report the progress of each object in addition to the totals.
+	#[serde(default, skip_serializing_if = "is_false")]
+	pub verbose: bool,
 }
 
 impl tg::Object {
@@ -95,11 +95,17 @@ impl Reference {
 
 	#[must_use]
 	pub fn with_path(path: impl AsRef<Path>) -> Self {
+		let path = path.as_ref();
 		let mut string = path
-			.as_ref()
 			.components()
-			.map(|component| urlencoding::encode_binary(component.as_os_str().as_bytes()))
+			.map(|component| match component {
+				std::path::Component::RootDir => "".into(),
+				component => urlencoding::encode_binary(component.as_os_str().as_bytes()),
+			})
 			.join("/");
+		if string.is_empty() && path.has_root() {
+			string.push('/');
+		}
 		if !(string.starts_with('.') || string.starts_with('/')) {
 			string.insert_str(0, "./");
 		}
@@ -133,6 +139,17 @@ impl Reference {
 		self.options.as_ref()
 	}
 
+	/// Normalize this reference, so that equivalent references compare equal. Paths are normalized by removing `.` components. `..` components are kept, because a `..` that follows a symlink can only be resolved against the file system or the artifact tree, so relative paths are not made absolute. Options that are not set are removed.
+	#[must_use]
+	pub fn normalized(&self) -> Self {
+		let item = match &self.item {
+			Item::Path(path) => Item::Path(normalize_path(path)),
+			item => item.clone(),
+		};
+		let options = self.options.as_ref().and_then(Options::normalized);
+		Self::with_item_and_options(&item, options.as_ref())
+	}
+
 	pub async fn get<H>(
 		&self,
 		handle: &H,
@@ -155,6 +172,53 @@ impl Reference {
 	}
 }
 
+impl Options {
+	fn normalized(&self) -> Option<Self> {
+		let overrides = self.overrides.as_ref().map(|overrides| {
+			overrides
+				.iter()
+				.map(|(name, reference)| (name.clone(), reference.normalized()))
+				.collect()
+		});
+		let path = self.path.as_deref().map(normalize_path);
+		let subpath = self
+			.subpath
+			.as_deref()
+			.map(normalize_path)
+			.filter(|subpath| subpath != Path::new("."));
+		let options = Self {
+			name: self.name.clone(),
+			overrides,
+			path,
+			remote: self.remote.clone(),
+			subpath,
+		};
+		if options.name.is_none()
+			&& options.overrides.is_none()
+			&& options.path.is_none()
+			&& options.remote.is_none()
+			&& options.subpath.is_none()
+		{
+			return None;
+		}
+		Some(options)
+	}
+}
+
+fn normalize_path(path: &Path) -> PathBuf {
+	let mut output = PathBuf::new();
+	for component in path.components() {
+		match component {
+			std::path::Component::CurDir => (),
+			component => output.push(component),
+		}
+	}
+	if output.as_os_str().is_empty() {
+		output.push(".");
+	}
+	output
+}
+
 impl std::fmt::Display for Reference {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		write!(f, "{}", self.uri)
@@ -245,3 +309,87 @@ impl std::cmp::PartialOrd for Reference {
 		Some(self.cmp(other))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate as tg;
+	use bytes::Bytes;
+	use std::path::Path;
+
+	#[test]
+	fn with_path() {
+		let reference = tg::Reference::with_path("foo/bar");
+		assert_eq!(reference.to_string(), "./foo/bar");
+		assert!(
+			matches!(reference.item(), tg::reference::Item::Path(path) if path == Path::new("foo/bar"))
+		);
+
+		let reference = tg::Reference::with_path("../foo");
+		assert_eq!(reference.to_string(), "../foo");
+
+		let reference = tg::Reference::with_path("/foo/bar baz");
+		assert_eq!(reference.to_string(), "/foo/bar%20baz");
+		assert!(
+			matches!(reference.item(), tg::reference::Item::Path(path) if path == Path::new("/foo/bar baz"))
+		);
+
+		let reference = tg::Reference::with_path("/");
+		assert_eq!(reference.to_string(), "/");
+	}
+
+	#[test]
+	fn with_object() {
+		let id: tg::object::Id = tg::leaf::Id::new(&Bytes::from("hello")).into();
+		let reference = tg::Reference::with_object(&id);
+		assert_eq!(reference.to_string(), id.to_string());
+		assert!(matches!(reference.item(), tg::reference::Item::Object(object) if object == &id));
+	}
+
+	#[test]
+	fn with_build() {
+		let id = tg::build::Id::new();
+		let reference = tg::Reference::with_build(&id);
+		assert_eq!(reference.to_string(), id.to_string());
+		assert!(matches!(reference.item(), tg::reference::Item::Build(build) if build == &id));
+	}
+
+	#[test]
+	fn normalized() {
+		let a: tg::Reference = "./foo/bar".parse().unwrap();
+		let b: tg::Reference = "./foo/./bar/.".parse().unwrap();
+		assert_ne!(a, b);
+		assert_eq!(a.normalized(), b.normalized());
+		assert_eq!(b.normalized().to_string(), "./foo/bar");
+
+		// A `..` component is kept, because `baz` may be a symlink.
+		let reference: tg::Reference = "./foo/baz/../bar".parse().unwrap();
+		assert_ne!(reference.normalized(), a.normalized());
+
+		let a: tg::Reference = "/foo/bar".parse().unwrap();
+		let b: tg::Reference = "/foo/bar/.?subpath=.".parse().unwrap();
+		assert_eq!(a.normalized(), b.normalized());
+
+		let reference: tg::Reference = "./foo/.".parse().unwrap();
+		assert_eq!(reference.normalized().to_string(), "./foo");
+
+		let reference: tg::Reference = "../foo".parse().unwrap();
+		assert_eq!(reference.normalized().to_string(), "../foo");
+
+		let reference: tg::Reference = "./foo?subpath=./bar".parse().unwrap();
+		assert_eq!(reference.normalized().to_string(), "./foo?subpath=bar");
+	}
+
+	#[test]
+	fn with_tag() {
+		let tag: tg::tag::Pattern = "foo".parse().unwrap();
+		let reference = tg::Reference::with_tag(&tag);
+		assert_eq!(reference.to_string(), "foo");
+
+		let tag: tg::tag::Pattern = "foo/1.0.0".parse().unwrap();
+		let reference = tg::Reference::with_tag(&tag);
+		assert_eq!(reference.to_string(), "foo%2F1.0.0");
+		assert!(
+			matches!(reference.item(), tg::reference::Item::Tag(pattern) if pattern.as_str() == "foo/1.0.0")
+		);
+	}
+}
@@ -9,6 +9,16 @@ pub struct Output {
 	pub item: Either<tg::build::Id, tg::object::Id>,
 }
 
+/// The output of a conditional tag get.
+#[derive(Clone, Debug)]
+pub enum Conditional {
+	/// The tag refers to a different item.
+	Modified(Output),
+
+	/// The tag refers to the item.
+	NotModified,
+}
+
 impl tg::Client {
 	pub async fn try_get_tag(
 		&self,
@@ -32,4 +42,33 @@ impl tg::Client {
 		let output = response.json().await?;
 		Ok(Some(output))
 	}
+
+	/// Get a tag unless it refers to `item`. The item is sent as the `If-None-Match` header, so the server does not send the tag if it has not changed.
+	pub async fn try_get_tag_if_not(
+		&self,
+		pattern: &tg::tag::Pattern,
+		item: &Either<tg::build::Id, tg::object::Id>,
+	) -> tg::Result<Option<tg::tag::get::Conditional>> {
+		let method = http::Method::GET;
+		let uri = format!("/tags/{pattern}");
+		let request = http::request::Builder::default()
+			.method(method)
+			.uri(uri)
+			.header(http::header::IF_NONE_MATCH, format!("\"{item}\""))
+			.empty()
+			.unwrap();
+		let response = self.send(request).await?;
+		if response.status() == http::StatusCode::NOT_FOUND {
+			return Ok(None);
+		}
+		if response.status() == http::StatusCode::NOT_MODIFIED {
+			return Ok(Some(tg::tag::get::Conditional::NotModified));
+		}
+		if !response.status().is_success() {
+			let error = response.json().await?;
+			return Err(error);
+		}
+		let output = response.json().await?;
+		Ok(Some(tg::tag::get::Conditional::Modified(output)))
+	}
 }
@@ -14,6 +14,20 @@ pub struct Arg {
 
 	#[serde(default, skip_serializing_if = "is_false")]
 	pub reverse: bool,
+
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub sort: Option<Sort>,
+}
+
+#[derive(
+	Clone, Copy, Debug, Eq, PartialEq, serde_with::DeserializeFromStr, serde_with::SerializeDisplay,
+)]
+pub enum Sort {
+	/// Sort tags by their string.
+	Lexical,
+
+	/// Sort tags by their last component as a version. Versions are compared by precedence, so versions that differ only in their build are ordered by their strings. Tags whose last component is not a version sort after those that are.
+	Semver,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -38,3 +52,101 @@ impl tg::Client {
 		Ok(output)
 	}
 }
+
+impl Sort {
+	#[must_use]
+	pub fn compare(self, a: &tg::Tag, b: &tg::Tag) -> std::cmp::Ordering {
+		match self {
+			Self::Lexical => a.as_str().cmp(b.as_str()),
+			Self::Semver => {
+				let (Some((a_last, a_prefix)), Some((b_last, b_prefix))) =
+					(a.components().split_last(), b.components().split_last())
+				else {
+					return a.as_str().cmp(b.as_str());
+				};
+				let a_prefix = a_prefix.iter().map(ToString::to_string);
+				let b_prefix = b_prefix.iter().map(ToString::to_string);
+				a_prefix
+					.cmp(b_prefix)
+					.then_with(|| match (a_last, b_last) {
+						(tg::tag::Component::Version(a), tg::tag::Component::Version(b)) => {
+							a.cmp_precedence(b)
+						},
+						(tg::tag::Component::Version(_), tg::tag::Component::String(_)) => {
+							std::cmp::Ordering::Less
+						},
+						(tg::tag::Component::String(_), tg::tag::Component::Version(_)) => {
+							std::cmp::Ordering::Greater
+						},
+						(tg::tag::Component::String(a), tg::tag::Component::String(b)) => a.cmp(b),
+					})
+					.then_with(|| a.as_str().cmp(b.as_str()))
+			},
+		}
+	}
+}
+
+impl std::fmt::Display for Sort {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Lexical => write!(f, "lexical"),
+			Self::Semver => write!(f, "semver"),
+		}
+	}
+}
+
+impl std::str::FromStr for Sort {
+	type Err = tg::Error;
+
+	fn from_str(s: &str) -> tg::Result<Self, Self::Err> {
+		match s {
+			"lexical" => Ok(Self::Lexical),
+			"semver" => Ok(Self::Semver),
+			sort => Err(tg::error!(%sort, "invalid value")),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate as tg;
+
+	fn sort(tags: &[&str], sort: tg::tag::list::Sort) -> Vec<String> {
+		let mut tags = tags
+			.iter()
+			.map(|tag| tag.parse::<tg::Tag>().unwrap())
+			.collect::<Vec<_>>();
+		tags.sort_by(|a, b| sort.compare(a, b));
+		tags.iter().map(ToString::to_string).collect()
+	}
+
+	#[test]
+	fn lexical() {
+		let tags = ["std/1.9.0", "std/1.10.0", "std/1.2.0"];
+		let left = sort(&tags, tg::tag::list::Sort::Lexical);
+		let right = ["std/1.10.0", "std/1.2.0", "std/1.9.0"];
+		assert_eq!(left, right);
+	}
+
+	#[test]
+	fn semver() {
+		let tags = [
+			"std/1.9.0",
+			"std/latest",
+			"std/1.10.0",
+			"foo/2.0.0",
+			"std/1.2.3",
+			"std/nightly",
+		];
+		let left = sort(&tags, tg::tag::list::Sort::Semver);
+		let right = [
+			"foo/2.0.0",
+			"std/1.2.3",
+			"std/1.9.0",
+			"std/1.10.0",
+			"std/latest",
+			"std/nightly",
+		];
+		assert_eq!(left, right);
+	}
+}
@@ -134,6 +134,9 @@ impl std::str::FromStr for Component {
 		if s == "*" {
 			return Ok(Self::Wildcard);
 		}
+		if s.is_empty() {
+			return Err(tg::error!("the component must not be empty"));
+		}
 		if let Ok(component) = s.parse() {
 			return Ok(Self::Normal(component));
 		}
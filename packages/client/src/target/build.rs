@@ -0,0 +1,136 @@
+use crate::{
+	self as tg,
+	handle::Ext as _,
+	util::serde::{is_true, return_true},
+};
+use std::collections::BTreeMap;
+use tangram_http::{incoming::response::Ext as _, outgoing::request::Ext as _};
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Arg {
+	#[serde(default = "return_true", skip_serializing_if = "is_true")]
+	pub create: bool,
+
+	#[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+	pub labels: BTreeMap<String, String>,
+
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub parent: Option<tg::build::Id>,
+
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub remote: Option<String>,
+
+	#[serde(default, skip_serializing_if = "retry_is_canceled")]
+	pub retry: tg::build::Retry,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct Output {
+	pub build: tg::build::Id,
+
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cache: Option<Cache>,
+}
+
+/// The result of looking up an existing build for a target. A target's ID is a hash of its host, executable, args, env, and checksum, so a build is only reused if all of them are the same.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum Cache {
+	/// An existing local build was found.
+	Local,
+
+	/// An existing build was found on a remote.
+	Remote { remote: String },
+
+	/// An existing build was found, but its outcome did not satisfy the retry constraint, so a new build was created. A build is reused only if its outcome's retry value is greater than the constraint, so `outcome` is less than or equal to `retry`.
+	Retry {
+		/// The existing build.
+		build: tg::build::Id,
+
+		/// The retry value of the existing build's outcome.
+		outcome: tg::build::Retry,
+
+		/// The retry constraint of the request.
+		retry: tg::build::Retry,
+	},
+
+	/// No existing build was found, so a new build was created.
+	Miss,
+}
+
+impl tg::Target {
+	pub async fn build<H>(&self, handle: &H, arg: tg::target::build::Arg) -> tg::Result<tg::Build>
+	where
+		H: tg::Handle,
+	{
+		let id = self.id(handle).await?;
+		let output = handle.build_target(&id, arg).await?;
+		let build = tg::Build::with_id(output.build);
+		Ok(build)
+	}
+
+	pub async fn outcome<H>(
+		&self,
+		handle: &H,
+		arg: tg::target::build::Arg,
+	) -> tg::Result<tg::build::Outcome>
+	where
+		H: tg::Handle,
+	{
+		let build = self.build(handle, arg).await?;
+		let outcome = build.outcome(handle).await?;
+		Ok(outcome)
+	}
+
+	pub async fn output<H>(&self, handle: &H, arg: tg::target::build::Arg) -> tg::Result<tg::Value>
+	where
+		H: tg::Handle,
+	{
+		let build = self.build(handle, arg).await?;
+		let output = build.output(handle).await?;
+		Ok(output)
+	}
+}
+
+impl tg::Client {
+	pub async fn try_build_target(
+		&self,
+		id: &tg::target::Id,
+		arg: tg::target::build::Arg,
+	) -> tg::Result<Option<tg::target::build::Output>> {
+		let method = http::Method::POST;
+		let uri = format!("/targets/{id}/build");
+		let request = http::request::Builder::default()
+			.method(method)
+			.uri(uri)
+			.json(arg)
+			.unwrap();
+		let response = self.send(request).await?;
+		if response.status() == http::StatusCode::NOT_FOUND {
+			return Ok(None);
+		}
+		if !response.status().is_success() {
+			let error = response.json().await?;
+			return Err(error);
+		}
+		let output = response.json().await?;
+		Ok(output)
+	}
+}
+
+impl Default for Arg {
+	fn default() -> Self {
+		Self {
+			create: true,
+			labels: BTreeMap::new(),
+			parent: None,
+			remote: None,
+			retry: tg::build::Retry::default(),
+		}
+	}
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn retry_is_canceled(retry: &tg::build::Retry) -> bool {
+	matches!(retry, tg::build::Retry::Canceled)
+}
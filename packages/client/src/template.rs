@@ -27,6 +27,31 @@ impl Template {
 		&self.components
 	}
 
+	/// Concatenate templates. Adjacent string components are merged and empty string components are removed.
+	#[must_use]
+	pub fn concat<I, T>(templates: I) -> Self
+	where
+		I: IntoIterator<Item = T>,
+		T: Into<Self>,
+	{
+		let mut components = Vec::new();
+		for template in templates {
+			for component in template.into().components {
+				if let Component::String(string) = &component {
+					if string.is_empty() {
+						continue;
+					}
+					if let Some(Component::String(last)) = components.last_mut() {
+						last.push_str(string);
+						continue;
+					}
+				}
+				components.push(component);
+			}
+		}
+		Self { components }
+	}
+
 	pub fn artifacts(&self) -> impl Iterator<Item = &tg::Artifact> {
 		self.components
 			.iter()
@@ -189,6 +214,37 @@ impl From<&str> for Template {
 	}
 }
 
+impl From<tg::Artifact> for Template {
+	fn from(value: tg::Artifact) -> Self {
+		vec![Component::Artifact(value)].into()
+	}
+}
+
+impl From<tg::Directory> for Template {
+	fn from(value: tg::Directory) -> Self {
+		tg::Artifact::from(value).into()
+	}
+}
+
+impl From<tg::File> for Template {
+	fn from(value: tg::File) -> Self {
+		tg::Artifact::from(value).into()
+	}
+}
+
+impl From<tg::Symlink> for Template {
+	fn from(value: tg::Symlink) -> Self {
+		tg::Artifact::from(value).into()
+	}
+}
+
+#[macro_export]
+macro_rules! template {
+	($($component:expr),* $(,)?) => {
+		$crate::Template::concat([$($crate::Template::from($component)),*])
+	};
+}
+
 pub mod component {
 	use crate as tg;
 
@@ -262,6 +318,73 @@ impl std::fmt::Display for Template {
 mod tests {
 	use super::*;
 
+	fn render(template: &tg::Template) -> String {
+		template
+			.try_render_sync(|component| match component {
+				Component::String(string) => Ok(string.into()),
+				Component::Artifact(artifact) => {
+					let id = match artifact {
+						tg::Artifact::Directory(directory) => directory
+							.state()
+							.read()
+							.unwrap()
+							.id()
+							.cloned()
+							.unwrap()
+							.to_string(),
+						tg::Artifact::File(file) => file
+							.state()
+							.read()
+							.unwrap()
+							.id()
+							.cloned()
+							.unwrap()
+							.to_string(),
+						tg::Artifact::Symlink(symlink) => symlink
+							.state()
+							.read()
+							.unwrap()
+							.id()
+							.cloned()
+							.unwrap()
+							.to_string(),
+					};
+					Ok(format!("/artifacts/{id}").into())
+				},
+			})
+			.unwrap()
+	}
+
+	#[test]
+	fn concat() {
+		let template = tg::Template::concat(["foo", "", "bar"]);
+		assert_eq!(template.components().len(), 1);
+		assert_eq!(render(&template), "foobar");
+
+		let template =
+			tg::Template::concat([tg::Template::from("foo "), tg::template!("bar", " baz")]);
+		assert_eq!(template.components().len(), 1);
+		assert_eq!(render(&template), "foo bar baz");
+	}
+
+	#[test]
+	fn macro_() {
+		let id: tg::directory::Id = "dir_010000000000000000000000000000000000000000000000000000"
+			.parse()
+			.unwrap();
+		let directory = tg::Directory::with_id(id.clone());
+		let template = tg::template!("--prefix=", directory.clone(), "/bin", ":", directory);
+		assert_eq!(template.components().len(), 4);
+		assert!(template.components()[0].is_string());
+		assert!(template.components()[1].is_artifact());
+		assert!(template.components()[2].is_string());
+		assert!(template.components()[3].is_artifact());
+		assert_eq!(
+			render(&template),
+			format!("--prefix=/artifacts/{id}/bin:/artifacts/{id}")
+		);
+	}
+
 	#[test]
 	fn unrender() {
 		let id = "dir_010000000000000000000000000000000000000000000000000000"
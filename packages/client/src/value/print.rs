@@ -13,6 +13,8 @@ pub struct Printer<W> {
 
 #[derive(Clone, Debug, Default)]
 pub struct Options {
+	/// If set, bytes values longer than this many bytes are printed as a preview of their first bytes in hex with the total length, instead of in full.
+	pub preview_bytes: Option<usize>,
 	pub recursive: bool,
 	pub style: Style,
 }
@@ -576,7 +578,17 @@ where
 
 	pub fn bytes(&mut self, value: &Bytes) -> Result {
 		write!(self.writer, "tg.bytes(")?;
-		write!(self.writer, "\"{}\"", data_encoding::BASE64.encode(value))?;
+		match self.options.preview_bytes {
+			Some(length) if value.len() > length => {
+				let size = byte_unit::Byte::from_u64(value.len().to_u64().unwrap())
+					.get_appropriate_unit(byte_unit::UnitType::Binary);
+				let preview = data_encoding::HEXLOWER.encode(&value[..length]);
+				write!(self.writer, "{size:.1}: {preview}...")?;
+			},
+			_ => {
+				write!(self.writer, "\"{}\"", data_encoding::BASE64.encode(value))?;
+			},
+		}
 		write!(self.writer, ")")?;
 		Ok(())
 	}
@@ -779,4 +791,39 @@ mod tests {
 		.trim();
 		assert_eq!(left, right);
 	}
+
+	#[test]
+	fn short_bytes() {
+		let mut left = String::new();
+		let options = Options {
+			preview_bytes: Some(4),
+			..Default::default()
+		};
+		let mut printer = Printer::new(&mut left, options);
+		printer.bytes(&Bytes::from_static(b"\x89PNG")).unwrap();
+		let right = r#"tg.bytes("iVBORw==")"#;
+		assert_eq!(left, right);
+	}
+
+	#[test]
+	fn long_bytes() {
+		let mut left = String::new();
+		let options = Options {
+			preview_bytes: Some(4),
+			..Default::default()
+		};
+		let mut printer = Printer::new(&mut left, options);
+		let mut bytes = b"\x89PNG".to_vec();
+		bytes.resize(1229, 0);
+		printer.bytes(&bytes.into()).unwrap();
+		let right = "tg.bytes(1.2 KiB: 89504e47...)";
+		assert_eq!(left, right);
+
+		let mut left = String::new();
+		let options = Options::default();
+		let mut printer = Printer::new(&mut left, options);
+		let bytes = vec![0; 1229];
+		printer.bytes(&bytes.into()).unwrap();
+		assert!(left.starts_with("tg.bytes(\"AAAA"));
+	}
 }
@@ -0,0 +1,120 @@
+import ts from "typescript";
+import type { Module } from "./module.ts";
+import type { Range } from "./range.ts";
+import * as typescript from "./typescript.ts";
+
+export type Request = {
+	module: Module;
+	range: Range;
+};
+
+export type Response = {
+	actions: Array<Action>;
+};
+
+export type Action = {
+	title: string;
+	changes: Array<Change>;
+};
+
+export type Change = {
+	module: Module;
+	edits: Array<Edit>;
+};
+
+export type Edit = {
+	range: Range;
+	text: string;
+};
+
+// The fixes to offer. These remove unused imports and declarations, and add imports for names that are not found.
+let fixNames = new Set(["unusedIdentifier", "import"]);
+
+export let handle = (request: Request): Response => {
+	// Get the source file and the range.
+	let fileName = typescript.fileNameFromModule(request.module);
+	let sourceFile = typescript.host.getSourceFile(
+		fileName,
+		ts.ScriptTarget.ESNext,
+	);
+	if (sourceFile === undefined) {
+		throw new Error();
+	}
+	let start = ts.getPositionOfLineAndCharacter(
+		sourceFile,
+		request.range.start.line,
+		request.range.start.character,
+	);
+	let end = ts.getPositionOfLineAndCharacter(
+		sourceFile,
+		request.range.end.line,
+		request.range.end.character,
+	);
+
+	// Get the diagnostics that overlap the range. Unused imports are reported as suggestions.
+	let diagnostics = [
+		...typescript.languageService.getSyntacticDiagnostics(fileName),
+		...typescript.languageService.getSemanticDiagnostics(fileName),
+		...typescript.languageService.getSuggestionDiagnostics(fileName),
+	].filter(
+		(diagnostic) =>
+			diagnostic.start !== undefined &&
+			diagnostic.length !== undefined &&
+			diagnostic.start <= end &&
+			diagnostic.start + diagnostic.length >= start,
+	);
+
+	// Get the fixes for each diagnostic. The fix providers look for the node at the diagnostic's span, so each diagnostic is requested with its own span rather than the range.
+	let formatOptions: ts.FormatCodeSettings = {
+		...ts.getDefaultFormatCodeSettings("\n"),
+		convertTabsToSpaces: false,
+	};
+	let descriptions = new Set<string>();
+	let fixes = diagnostics.flatMap((diagnostic) =>
+		typescript.languageService
+			.getCodeFixesAtPosition(
+				fileName,
+				diagnostic.start!,
+				diagnostic.start! + diagnostic.length!,
+				[diagnostic.code],
+				formatOptions,
+				{},
+			)
+			.filter((fix) => {
+				if (!fixNames.has(fix.fixName) || descriptions.has(fix.description)) {
+					return false;
+				}
+				descriptions.add(fix.description);
+				return true;
+			}),
+	);
+
+	// Convert the fixes.
+	let actions = fixes.map((fix) => {
+		let changes = fix.changes.map((change) => {
+			let destFile = typescript.host.getSourceFile(
+				change.fileName,
+				ts.ScriptTarget.ESNext,
+			);
+			if (destFile === undefined) {
+				throw new Error();
+			}
+			let edits = change.textChanges.map((textChange) => {
+				let start = ts.getLineAndCharacterOfPosition(
+					destFile,
+					textChange.span.start,
+				);
+				let end = ts.getLineAndCharacterOfPosition(
+					destFile,
+					textChange.span.start + textChange.span.length,
+				);
+				return { range: { start, end }, text: textChange.newText };
+			});
+			let module = typescript.moduleFromFileName(change.fileName);
+			return { module, edits };
+		});
+		return { title: fix.description, changes };
+	});
+
+	return { actions };
+};
@@ -0,0 +1,76 @@
+import ts from "typescript";
+import type { Module } from "./module.ts";
+import * as typescript from "./typescript.ts";
+
+export type Request = {
+	module: Module;
+};
+
+export type Response = {
+	foldingRanges: Array<FoldingRange>;
+};
+
+export type FoldingRange = {
+	startLine: number;
+	endLine: number;
+	kind: Kind | undefined;
+};
+
+export type Kind = "comment" | "imports" | "region";
+
+export let handle = (request: Request): Response => {
+	// Get the source file.
+	let fileName = typescript.fileNameFromModule(request.module);
+	let sourceFile = typescript.host.getSourceFile(
+		fileName,
+		ts.ScriptTarget.ESNext,
+	);
+	if (sourceFile === undefined) {
+		throw new Error();
+	}
+
+	// Get the outlining spans.
+	let spans = typescript.languageService.getOutliningSpans(fileName);
+
+	// Convert the outlining spans to folding ranges.
+	let foldingRanges = [];
+	for (let span of spans) {
+		let start = span.textSpan.start;
+		let end = span.textSpan.start + span.textSpan.length;
+		let startLine = ts.getLineAndCharacterOfPosition(sourceFile, start).line;
+		let endLine = ts.getLineAndCharacterOfPosition(sourceFile, end).line;
+
+		// Keep the closing delimiter of a block or a template literal visible when it is on its own line.
+		let lineStart = ts.getPositionOfLineAndCharacter(sourceFile, endLine, 0);
+		let lastLine = sourceFile.text.slice(lineStart, end);
+		if (endLine > startLine && /^\s*[}\])`]$/.test(lastLine)) {
+			endLine -= 1;
+		}
+
+		// Skip the spans that do not span multiple lines.
+		if (endLine <= startLine) {
+			continue;
+		}
+
+		foldingRanges.push({ startLine, endLine, kind: convertKind(span.kind) });
+	}
+
+	return { foldingRanges };
+};
+
+let convertKind = (kind: ts.OutliningSpanKind): Kind | undefined => {
+	switch (kind) {
+		case ts.OutliningSpanKind.Comment: {
+			return "comment";
+		}
+		case ts.OutliningSpanKind.Imports: {
+			return "imports";
+		}
+		case ts.OutliningSpanKind.Region: {
+			return "region";
+		}
+		case ts.OutliningSpanKind.Code: {
+			return undefined;
+		}
+	}
+};
@@ -1,36 +1,48 @@
 import * as check from "./check.ts";
+import * as codeAction from "./code_action.ts";
 import * as completion from "./completion.ts";
 import * as definition from "./definition.ts";
 import * as diagnostics from "./diagnostics.ts";
 import * as document from "./document.ts";
 import { Error_ } from "./error.ts";
+import * as foldingRanges from "./folding_ranges.ts";
 import * as hover from "./hover.ts";
 import * as references from "./references.ts";
 import * as rename from "./rename.ts";
+import * as semanticTokens from "./semantic_tokens.ts";
+import * as signatureHelp from "./signature_help.ts";
 import * as symbols from "./symbols.ts";
 
 type Request =
 	| { kind: "check"; request: check.Request }
+	| { kind: "code_action"; request: codeAction.Request }
 	| { kind: "completion"; request: completion.Request }
 	| { kind: "definition"; request: definition.Request }
 	| { kind: "type_definition"; request: definition.Request }
 	| { kind: "diagnostics"; request: diagnostics.Request }
 	| { kind: "document"; request: document.Request }
+	| { kind: "folding_ranges"; request: foldingRanges.Request }
 	| { kind: "hover"; request: hover.Request }
 	| { kind: "references"; request: references.Request }
 	| { kind: "rename"; request: rename.Request }
+	| { kind: "semantic_tokens"; request: semanticTokens.Request }
+	| { kind: "signature_help"; request: signatureHelp.Request }
 	| { kind: "symbols"; request: symbols.Request };
 
 type Response =
 	| { kind: "check"; response: check.Response }
+	| { kind: "code_action"; response: codeAction.Response }
 	| { kind: "completion"; response: completion.Response }
 	| { kind: "definition"; response: definition.Response }
 	| { kind: "type_definition"; response: definition.Response }
 	| { kind: "diagnostics"; response: diagnostics.Response }
 	| { kind: "document"; response: document.Response }
+	| { kind: "folding_ranges"; response: foldingRanges.Response }
 	| { kind: "hover"; response: hover.Response }
 	| { kind: "references"; response: references.Response }
 	| { kind: "rename"; response: rename.Response }
+	| { kind: "semantic_tokens"; response: semanticTokens.Response }
+	| { kind: "signature_help"; response: signatureHelp.Response }
 	| { kind: "symbols"; response: symbols.Response };
 
 let handle = ({ kind, request }: Request): Response => {
@@ -39,6 +51,10 @@ let handle = ({ kind, request }: Request): Response => {
 			let response = check.handle(request);
 			return { kind: "check", response };
 		}
+		case "code_action": {
+			let response = codeAction.handle(request);
+			return { kind: "code_action", response };
+		}
 		case "completion": {
 			let response = completion.handle(request);
 			return { kind: "completion", response };
@@ -59,6 +75,10 @@ let handle = ({ kind, request }: Request): Response => {
 			let response = document.handle(request);
 			return { kind: "document", response };
 		}
+		case "folding_ranges": {
+			let response = foldingRanges.handle(request);
+			return { kind: "folding_ranges", response };
+		}
 		case "hover": {
 			let response = hover.handle(request);
 			return { kind: "hover", response };
@@ -71,6 +91,14 @@ let handle = ({ kind, request }: Request): Response => {
 			let response = rename.handle(request);
 			return { kind: "rename", response };
 		}
+		case "semantic_tokens": {
+			let response = semanticTokens.handle(request);
+			return { kind: "semantic_tokens", response };
+		}
+		case "signature_help": {
+			let response = signatureHelp.handle(request);
+			return { kind: "signature_help", response };
+		}
 		case "symbols": {
 			let response = symbols.handle(request);
 			return { kind: "symbols", response };
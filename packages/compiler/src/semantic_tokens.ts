@@ -0,0 +1,174 @@
+import ts from "typescript";
+import type { Module } from "./module.ts";
+import type { Range } from "./range.ts";
+import * as typescript from "./typescript.ts";
+
+export type Request = {
+	module: Module;
+};
+
+export type Response = {
+	tokens: Array<Token>;
+};
+
+export type Token = {
+	range: Range;
+	type: Type;
+	modifiers: Array<Modifier>;
+};
+
+export type Type =
+	| "class"
+	| "enum"
+	| "interface"
+	| "namespace"
+	| "typeParameter"
+	| "type"
+	| "parameter"
+	| "variable"
+	| "enumMember"
+	| "property"
+	| "function"
+	| "method"
+	| "module"
+	| "template";
+
+export type Modifier =
+	| "declaration"
+	| "static"
+	| "async"
+	| "readonly"
+	| "defaultLibrary"
+	| "local";
+
+// The token types and modifiers of TypeScript's semantic classifications, in the order of their encoding.
+let classificationTypes: Array<Type> = [
+	"class",
+	"enum",
+	"interface",
+	"namespace",
+	"typeParameter",
+	"type",
+	"parameter",
+	"variable",
+	"enumMember",
+	"property",
+	"function",
+	"method",
+];
+let classificationModifiers: Array<Modifier> = [
+	"declaration",
+	"static",
+	"async",
+	"readonly",
+	"defaultLibrary",
+	"local",
+];
+
+export let handle = (request: Request): Response => {
+	// Get the source file.
+	let fileName = typescript.fileNameFromModule(request.module);
+	let sourceFile = typescript.host.getSourceFile(
+		fileName,
+		ts.ScriptTarget.ESNext,
+	);
+	if (sourceFile === undefined) {
+		throw new Error();
+	}
+
+	// Get the spans of the tokens.
+	let spans: Array<{
+		start: number;
+		end: number;
+		type: Type;
+		modifiers: Array<Modifier>;
+	}> = [];
+
+	// Add the semantic classifications of identifiers.
+	let classifications =
+		typescript.languageService.getEncodedSemanticClassifications(
+			fileName,
+			{ start: 0, length: sourceFile.end },
+			ts.SemanticClassificationFormat.TwentyTwenty,
+		);
+	for (let i = 0; i < classifications.spans.length; i += 3) {
+		let start = classifications.spans[i]!;
+		let length = classifications.spans[i + 1]!;
+		let classification = classifications.spans[i + 2]!;
+		let type = classificationTypes[(classification >> 8) - 1];
+		if (type === undefined) {
+			continue;
+		}
+		let modifiers = classificationModifiers.filter(
+			(_, index) => (classification & (1 << index)) !== 0,
+		);
+		spans.push({ start, end: start + length, type, modifiers });
+	}
+
+	// Add the module specifiers of imports and the literal parts of template literals, which are not classified.
+	let visit = (node: ts.Node) => {
+		if (
+			(ts.isImportDeclaration(node) || ts.isExportDeclaration(node)) &&
+			node.moduleSpecifier !== undefined &&
+			ts.isStringLiteral(node.moduleSpecifier)
+		) {
+			let specifier = node.moduleSpecifier;
+			spans.push({
+				start: specifier.getStart(sourceFile),
+				end: specifier.end,
+				type: "module",
+				modifiers: [],
+			});
+		} else if (
+			ts.isCallExpression(node) &&
+			node.expression.kind === ts.SyntaxKind.ImportKeyword &&
+			node.arguments[0] !== undefined &&
+			ts.isStringLiteral(node.arguments[0])
+		) {
+			let specifier = node.arguments[0];
+			spans.push({
+				start: specifier.getStart(sourceFile),
+				end: specifier.end,
+				type: "module",
+				modifiers: [],
+			});
+		} else if (
+			ts.isNoSubstitutionTemplateLiteral(node) ||
+			ts.isTemplateHead(node) ||
+			ts.isTemplateMiddle(node) ||
+			ts.isTemplateTail(node)
+		) {
+			spans.push({
+				start: node.getStart(sourceFile),
+				end: node.end,
+				type: "template",
+				modifiers: [],
+			});
+		}
+		ts.forEachChild(node, visit);
+	};
+	visit(sourceFile);
+
+	// Sort the spans.
+	spans.sort((a, b) => a.start - b.start);
+
+	// Create the tokens. A token may not span multiple lines, so split spans at line breaks.
+	let tokens: Array<Token> = [];
+	let text = sourceFile.text;
+	for (let { start, end, type, modifiers } of spans) {
+		while (start < end) {
+			let newline = text.indexOf("\n", start);
+			let lineEnd = newline === -1 || newline >= end ? end : newline;
+			if (lineEnd > start) {
+				let range = {
+					start: ts.getLineAndCharacterOfPosition(sourceFile, start),
+					end: ts.getLineAndCharacterOfPosition(sourceFile, lineEnd),
+				};
+				tokens.push({ range, type, modifiers });
+			}
+			start = lineEnd + 1;
+		}
+	}
+
+	return { tokens };
+};
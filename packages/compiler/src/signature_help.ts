@@ -0,0 +1,92 @@
+import ts from "typescript";
+import type { Module } from "./module.ts";
+import type { Position } from "./position.ts";
+import * as typescript from "./typescript.ts";
+
+export type Request = {
+	module: Module;
+	position: Position;
+};
+
+export type Response = {
+	signatureHelp: SignatureHelp | undefined;
+};
+
+export type SignatureHelp = {
+	signatures: Array<Signature>;
+	activeSignature: number;
+	activeParameter: number;
+};
+
+export type Signature = {
+	label: string;
+	documentation: string | undefined;
+	parameters: Array<Parameter>;
+};
+
+export type Parameter = {
+	label: string;
+	documentation: string | undefined;
+};
+
+export let handle = (request: Request): Response => {
+	// Get the source file.
+	let sourceFile = typescript.host.getSourceFile(
+		typescript.fileNameFromModule(request.module),
+		ts.ScriptTarget.ESNext,
+	);
+	if (sourceFile === undefined) {
+		throw new Error();
+	}
+
+	// Get the position of the signature help.
+	let position = ts.getPositionOfLineAndCharacter(
+		sourceFile,
+		request.position.line,
+		request.position.character,
+	);
+
+	// Get the signature help items at the position.
+	let items = typescript.languageService.getSignatureHelpItems(
+		typescript.fileNameFromModule(request.module),
+		position,
+		undefined,
+	);
+	if (items === undefined) {
+		return { signatureHelp: undefined };
+	}
+
+	// Convert the items.
+	let signatures = items.items.map((item) => {
+		let prefix = displayPartsToString(item.prefixDisplayParts);
+		let separator = displayPartsToString(item.separatorDisplayParts);
+		let suffix = displayPartsToString(item.suffixDisplayParts);
+		let parameters = item.parameters.map((parameter) => ({
+			label: displayPartsToString(parameter.displayParts),
+			documentation: documentationToString(parameter.documentation),
+		}));
+		let label =
+			prefix +
+			parameters.map((parameter) => parameter.label).join(separator) +
+			suffix;
+		let documentation = documentationToString(item.documentation);
+		return { label, documentation, parameters };
+	});
+	let signatureHelp = {
+		signatures,
+		activeSignature: items.selectedItemIndex,
+		activeParameter: items.argumentIndex,
+	};
+
+	return { signatureHelp };
+};
+
+let displayPartsToString = (parts: Array<ts.SymbolDisplayPart>): string =>
+	parts.map(({ text }) => text).join("");
+
+let documentationToString = (
+	parts: Array<ts.SymbolDisplayPart>,
+): string | undefined => {
+	let text = displayPartsToString(parts);
+	return text.length > 0 ? text : undefined;
+};
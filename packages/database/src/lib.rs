@@ -13,6 +13,9 @@ pub mod row;
 pub mod sqlite;
 pub mod value;
 
+/// The default maximum number of prepared statements to cache per connection.
+pub const DEFAULT_STATEMENT_CACHE_SIZE: usize = 64;
+
 pub mod prelude {
 	pub use super::{Connection as _, Database as _, Error as _, Query as _, Transaction as _};
 }
@@ -49,6 +52,9 @@ pub trait Database {
 pub struct ConnectionOptions {
 	pub kind: ConnectionKind,
 	pub priority: Priority,
+
+	/// The maximum duration of each statement executed on the connection. A statement that exceeds it is interrupted and returns an error that is not retryable.
+	pub timeout: Option<std::time::Duration>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -145,6 +151,18 @@ pub trait Query {
 		})
 	}
 
+	/// Execute a statement and stream its rows deserialized as `T`. Unlike `query_all_into`, the rows are not collected. Each row is fetched from the database when the stream is polled, so a caller that processes the rows one at a time uses the same amount of memory regardless of how many rows there are. The connection cannot be used for other statements until the stream is dropped.
+	fn query_stream_into<T>(
+		&self,
+		statement: String,
+		params: Vec<Value>,
+	) -> impl Stream<Item = Result<T, Self::Error>> + Send
+	where
+		T: serde::de::DeserializeOwned,
+	{
+		self.query_into(statement, params).try_flatten_stream()
+	}
+
 	fn query_optional(
 		&self,
 		statement: String,
@@ -1,22 +1,38 @@
 use std::{
-	collections::BinaryHeap,
+	collections::VecDeque,
 	sync::{Arc, Mutex},
+	time::{Duration, Instant},
 };
 
+/// The default duration a low priority request waits before it is served as if it were high priority.
+pub const DEFAULT_AGING: Duration = Duration::from_secs(1);
+
+/// The upper bounds of the wait time histogram buckets. The last bucket counts the waits longer than the last bound.
+pub const HISTOGRAM_BOUNDS: [Duration; 5] = [
+	Duration::from_millis(1),
+	Duration::from_millis(10),
+	Duration::from_millis(100),
+	Duration::from_secs(1),
+	Duration::from_secs(10),
+];
+
 pub struct Pool<T> {
 	state: Arc<Mutex<State<T>>>,
 }
 
 struct State<T> {
-	requests: BinaryHeap<Request<T>>,
+	aging: Duration,
+	high: VecDeque<Request<T>>,
+	low: VecDeque<Request<T>>,
+	metrics: Metrics,
 	order: usize,
 	values: Vec<T>,
 }
 
 struct Request<T> {
-	priority: Priority,
 	order: usize,
 	sender: tokio::sync::oneshot::Sender<T>,
+	time: Instant,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
@@ -26,6 +42,20 @@ pub enum Priority {
 	High,
 }
 
+/// Wait time histograms for the requests of each priority.
+#[derive(Clone, Debug, Default)]
+pub struct Metrics {
+	pub high: Histogram,
+	pub low: Histogram,
+}
+
+/// A histogram of wait times. `counts[i]` is the number of waits no longer than `HISTOGRAM_BOUNDS[i]`, and the last count is the number of waits longer than all bounds.
+#[derive(Clone, Debug, Default)]
+pub struct Histogram {
+	pub counts: [u64; HISTOGRAM_BOUNDS.len() + 1],
+	pub sum: Duration,
+}
+
 pub struct Guard<T> {
 	value: Option<T>,
 	pool: Pool<T>,
@@ -34,8 +64,17 @@ pub struct Guard<T> {
 impl<T> Pool<T> {
 	#[must_use]
 	pub fn new() -> Self {
+		Self::with_aging(DEFAULT_AGING)
+	}
+
+	/// Create a pool in which a low priority request that has waited longer than `aging` is served as if it were high priority, so that it is not starved by a steady stream of high priority requests.
+	#[must_use]
+	pub fn with_aging(aging: Duration) -> Self {
 		let state = State {
-			requests: BinaryHeap::new(),
+			aging,
+			high: VecDeque::new(),
+			low: VecDeque::new(),
+			metrics: Metrics::default(),
 			order: 0,
 			values: Vec::new(),
 		};
@@ -44,21 +83,15 @@ impl<T> Pool<T> {
 	}
 
 	pub fn add(&self, value: T) {
-		let mut state = self.state.lock().unwrap();
-		if let Some(request) = state.requests.pop() {
-			let result = request.sender.send(value);
-			if let Err(value) = result {
-				state.values.push(value);
-			}
-		} else {
-			state.values.push(value);
-		}
+		self.state.lock().unwrap().release(value);
 	}
 
 	pub async fn get(&self, priority: Priority) -> Guard<T> {
+		let time = Instant::now();
 		let receiver = {
 			let mut state = self.state.lock().unwrap();
 			if let Some(value) = state.values.pop() {
+				state.metrics.record(priority, Duration::ZERO);
 				return Guard {
 					value: Some(value),
 					pool: self.clone(),
@@ -67,15 +100,23 @@ impl<T> Pool<T> {
 			let (sender, receiver) = tokio::sync::oneshot::channel();
 			let order = state.order;
 			let request = Request {
-				priority,
 				order,
 				sender,
+				time,
 			};
 			state.order += 1;
-			state.requests.push(request);
+			match priority {
+				Priority::High => state.high.push_back(request),
+				Priority::Low => state.low.push_back(request),
+			}
 			receiver
 		};
 		let value = receiver.await.unwrap();
+		self.state
+			.lock()
+			.unwrap()
+			.metrics
+			.record(priority, time.elapsed());
 		Guard {
 			value: Some(value),
 			pool: self.clone(),
@@ -86,6 +127,70 @@ impl<T> Pool<T> {
 	pub fn available(&self) -> usize {
 		self.state.lock().unwrap().values.len()
 	}
+
+	/// Get the wait time histograms.
+	#[must_use]
+	pub fn metrics(&self) -> Metrics {
+		self.state.lock().unwrap().metrics.clone()
+	}
+}
+
+impl<T> State<T> {
+	/// Give the value to the next request, or return it to the pool if there are no requests.
+	fn release(&mut self, mut value: T) {
+		while let Some(request) = self.pop() {
+			match request.sender.send(value) {
+				Ok(()) => return,
+				// If the request was dropped, then try the next one.
+				Err(value_) => value = value_,
+			}
+		}
+		self.values.push(value);
+	}
+
+	/// Pop the next request. High priority requests are served first, unless the oldest low priority request has waited longer than the aging duration, in which case it competes with the high priority requests in the order the requests were made.
+	fn pop(&mut self) -> Option<Request<T>> {
+		let aged = self
+			.low
+			.front()
+			.is_some_and(|request| request.time.elapsed() >= self.aging);
+		let low = match (self.high.front(), self.low.front()) {
+			(Some(high), Some(low)) => aged && low.order < high.order,
+			(Some(_), None) => false,
+			(None, _) => true,
+		};
+		if low {
+			self.low.pop_front()
+		} else {
+			self.high.pop_front()
+		}
+	}
+}
+
+impl Metrics {
+	fn record(&mut self, priority: Priority, wait: Duration) {
+		match priority {
+			Priority::High => self.high.record(wait),
+			Priority::Low => self.low.record(wait),
+		}
+	}
+}
+
+impl Histogram {
+	fn record(&mut self, wait: Duration) {
+		let index = HISTOGRAM_BOUNDS
+			.iter()
+			.position(|bound| wait <= *bound)
+			.unwrap_or(HISTOGRAM_BOUNDS.len());
+		self.counts[index] += 1;
+		self.sum += wait;
+	}
+
+	/// Get the number of recorded waits.
+	#[must_use]
+	pub fn count(&self) -> u64 {
+		self.counts.iter().sum()
+	}
 }
 
 impl<T> Default for Pool<T> {
@@ -131,39 +236,57 @@ impl<T> AsMut<T> for Guard<T> {
 impl<T> Drop for Guard<T> {
 	fn drop(&mut self) {
 		let value = self.value.take().unwrap();
-		let mut state = self.pool.state.lock().unwrap();
-		if let Some(request) = state.requests.pop() {
-			let result = request.sender.send(value);
-			if let Err(value) = result {
-				state.values.push(value);
-			}
-		} else {
-			state.values.push(value);
-		}
+		self.pool.state.lock().unwrap().release(value);
 	}
 }
 
-impl<T> PartialEq for Request<T> {
-	fn eq(&self, other: &Self) -> bool {
-		self.priority == other.priority && self.order == other.order
-	}
-}
+#[cfg(test)]
+mod tests {
+	use super::*;
 
-impl<T> Eq for Request<T> {}
+	#[tokio::test]
+	async fn low_priority_is_not_starved() {
+		let pool = Pool::with_aging(Duration::from_millis(50));
+		pool.add(());
 
-#[allow(clippy::non_canonical_partial_ord_impl)]
-impl<T> PartialOrd for Request<T> {
-	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-		Some(
-			self.priority
-				.cmp(&other.priority)
-				.then_with(|| self.order.cmp(&other.order).reverse()),
-		)
-	}
-}
+		// Hold the value so that the low priority request must wait.
+		let guard = pool.get(Priority::High).await;
+		let low = tokio::spawn({
+			let pool = pool.clone();
+			async move {
+				pool.get(Priority::Low).await;
+			}
+		});
+		tokio::task::yield_now().await;
+
+		// Flood the pool with high priority requests.
+		let flood = (0..8)
+			.map(|_| {
+				let pool = pool.clone();
+				tokio::spawn(async move {
+					loop {
+						let guard = pool.get(Priority::High).await;
+						tokio::time::sleep(Duration::from_millis(1)).await;
+						drop(guard);
+					}
+				})
+			})
+			.collect::<Vec<_>>();
+		tokio::task::yield_now().await;
+		drop(guard);
+
+		// The low priority request must be served within a bound.
+		tokio::time::timeout(Duration::from_secs(5), low)
+			.await
+			.expect("the low priority request was starved")
+			.unwrap();
+		for task in flood {
+			task.abort();
+		}
 
-impl<T> Ord for Request<T> {
-	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-		self.partial_cmp(other).unwrap()
+		let metrics = pool.metrics();
+		assert_eq!(metrics.low.count(), 1);
+		assert!(metrics.low.sum >= Duration::from_millis(50));
+		assert!(metrics.high.count() > 1);
 	}
 }
@@ -5,7 +5,7 @@ use crate::{
 use futures::{future, Future, Stream, TryStreamExt as _};
 use indexmap::IndexMap;
 use itertools::Itertools as _;
-use std::collections::HashMap;
+use std::time::Duration;
 use tokio_postgres as postgres;
 use url::Url;
 
@@ -21,26 +21,40 @@ pub enum Error {
 pub struct DatabaseOptions {
 	pub url: Url,
 	pub connections: usize,
+
+	/// The URL of a read replica. If this is set, then read connections are made to the replica instead of the primary.
+	pub read_url: Option<Url>,
+
+	/// The maximum number of prepared statements to cache per connection.
+	pub statement_cache_size: usize,
+
+	/// The default maximum duration of each statement, for connections that do not set a timeout. If this is not set, then statements do not time out.
+	pub timeout: Option<Duration>,
 }
 
 #[derive(Clone, Debug)]
 pub struct ConnectionOptions {
+	pub statement_cache_size: usize,
 	pub url: Url,
 }
 
-#[derive(Default)]
+/// A cache of prepared statements that evicts the least recently used statement when it is full.
 pub struct Cache {
-	statements: tokio::sync::Mutex<HashMap<String, postgres::Statement, fnv::FnvBuildHasher>>,
+	capacity: usize,
+	statements: tokio::sync::Mutex<IndexMap<String, postgres::Statement, fnv::FnvBuildHasher>>,
 }
 
 pub struct Database {
 	pool: Pool<Connection>,
+	read_pool: Option<Pool<Connection>>,
+	timeout: Option<Duration>,
 }
 
 pub struct Connection {
 	options: ConnectionOptions,
 	client: postgres::Client,
 	cache: Cache,
+	timeout: Option<Duration>,
 }
 
 pub struct Transaction<'a> {
@@ -49,41 +63,83 @@ pub struct Transaction<'a> {
 }
 
 impl Cache {
+	#[must_use]
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			capacity,
+			statements: tokio::sync::Mutex::new(IndexMap::default()),
+		}
+	}
+
 	pub async fn get(
 		&self,
 		client: &impl postgres::GenericClient,
 		query: impl AsRef<str>,
 	) -> Result<postgres::Statement, Error> {
-		if let Some(statement) = self.statements.lock().await.get(query.as_ref()) {
-			return Ok(statement.clone());
+		// Move the statement to the end if it is cached.
+		{
+			let mut statements = self.statements.lock().await;
+			if let Some(index) = statements.get_index_of(query.as_ref()) {
+				let last = statements.len() - 1;
+				statements.move_index(index, last);
+				return Ok(statements[last].clone());
+			}
 		}
+
+		// Prepare the statement.
 		let statement = client.prepare(query.as_ref()).await?;
-		self.statements
-			.lock()
-			.await
-			.insert(query.as_ref().to_owned(), statement.clone());
+
+		// Cache the statement and evict the least recently used statements.
+		if self.capacity > 0 {
+			let mut statements = self.statements.lock().await;
+			statements.insert(query.as_ref().to_owned(), statement.clone());
+			while statements.len() > self.capacity {
+				statements.shift_remove_index(0);
+			}
+		}
+
 		Ok(statement)
 	}
 }
 
 impl Database {
 	pub async fn new(options: DatabaseOptions) -> Result<Self, Error> {
+		let pool = Self::create_pool(&options, &options.url).await?;
+		let read_pool = if let Some(url) = &options.read_url {
+			Some(Self::create_pool(&options, url).await?)
+		} else {
+			None
+		};
+		let database = Self {
+			pool,
+			read_pool,
+			timeout: options.timeout,
+		};
+		Ok(database)
+	}
+
+	async fn create_pool(options: &DatabaseOptions, url: &Url) -> Result<Pool<Connection>, Error> {
 		let pool = Pool::new();
 		for _ in 0..options.connections {
 			let options = ConnectionOptions {
-				url: options.url.clone(),
+				statement_cache_size: options.statement_cache_size,
+				url: url.clone(),
 			};
 			let connection = Connection::connect(options).await?;
 			pool.add(connection);
 		}
-		let database = Self { pool };
-		Ok(database)
+		Ok(pool)
 	}
 
 	#[must_use]
 	pub fn pool(&self) -> &Pool<Connection> {
 		&self.pool
 	}
+
+	#[must_use]
+	pub fn read_pool(&self) -> &Pool<Connection> {
+		self.read_pool.as_ref().unwrap_or(&self.pool)
+	}
 }
 
 impl Connection {
@@ -95,11 +151,12 @@ impl Connection {
 				.inspect_err(|error| tracing::error!(?error, "postgres connection failed"))
 				.ok();
 		});
-		let cache = Cache::default();
+		let cache = Cache::new(options.statement_cache_size);
 		let connection = Self {
 			options,
 			client,
 			cache,
+			timeout: None,
 		};
 		Ok(connection)
 	}
@@ -114,7 +171,20 @@ impl Connection {
 				.ok();
 		});
 		self.client = client;
-		self.cache = Cache::default();
+		self.cache = Cache::new(self.options.statement_cache_size);
+		self.timeout = None;
+		Ok(())
+	}
+
+	/// Set the connection's statement timeout if it differs from the current one.
+	async fn set_timeout(&mut self, timeout: Option<Duration>) -> Result<(), Error> {
+		if self.timeout == timeout {
+			return Ok(());
+		}
+		let milliseconds = timeout.map_or(0, |timeout| timeout.as_millis().max(1));
+		let statement = format!("set statement_timeout = {milliseconds};");
+		self.client.batch_execute(&statement).await?;
+		self.timeout = timeout;
 		Ok(())
 	}
 }
@@ -128,10 +198,17 @@ impl super::Database for Database {
 		&self,
 		options: super::ConnectionOptions,
 	) -> Result<Self::T, Self::Error> {
-		let mut connection = self.pool.get(options.priority).await;
+		let pool = match options.kind {
+			crate::ConnectionKind::Read => self.read_pool(),
+			crate::ConnectionKind::Write => self.pool(),
+		};
+		let mut connection = pool.get(options.priority).await;
 		if connection.client.is_closed() {
 			connection.reconnect().await?;
 		}
+		connection
+			.set_timeout(options.timeout.or(self.timeout))
+			.await?;
 		Ok(connection)
 	}
 }
@@ -309,10 +386,11 @@ impl postgres::types::ToSql for Value {
 			Value::Real(value) => value.to_sql(ty, out),
 			Value::Text(value) => value.to_sql(ty, out),
 			Value::Blob(value) => value.to_sql(ty, out),
+			Value::Json(value) => Json(value).to_sql(ty, out),
 		}
 	}
 
-	postgres::types::accepts!(BOOL, INT8, FLOAT8, TEXT, BYTEA);
+	postgres::types::accepts!(BOOL, INT8, FLOAT8, TEXT, BYTEA, JSON, JSONB);
 
 	postgres::types::to_sql_checked!();
 }
@@ -330,6 +408,9 @@ impl<'a> postgres::types::FromSql<'a> for Value {
 			postgres::types::Type::FLOAT8 => Ok(Self::Real(f64::from_sql(ty, raw)?)),
 			postgres::types::Type::TEXT => Ok(Self::Text(String::from_sql(ty, raw)?)),
 			postgres::types::Type::BYTEA => Ok(Self::Blob(<Vec<u8>>::from_sql(ty, raw)?)),
+			postgres::types::Type::JSON | postgres::types::Type::JSONB => {
+				Ok(Self::Json(Json::<serde_json::Value>::from_sql(ty, raw)?.0))
+			},
 			_ => Err("invalid type".into()),
 		}
 	}
@@ -340,5 +421,131 @@ impl<'a> postgres::types::FromSql<'a> for Value {
 		Ok(Self::Null)
 	}
 
-	postgres::types::accepts!(BOOL, INT8, NUMERIC, FLOAT8, TEXT, BYTEA);
+	postgres::types::accepts!(BOOL, INT8, NUMERIC, FLOAT8, TEXT, BYTEA, JSON, JSONB);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{value::Json, Query as _};
+
+	/// Connect to the postgres server at `TANGRAM_TEST_POSTGRES_URL`. These tests are ignored by default because they require a postgres server. Run them with `cargo test -- --ignored`.
+	async fn connect(statement_cache_size: usize) -> Connection {
+		let url = std::env::var("TANGRAM_TEST_POSTGRES_URL")
+			.unwrap_or_else(|_| "postgres://postgres@localhost:5432/postgres".to_owned())
+			.parse()
+			.unwrap();
+		let options = ConnectionOptions {
+			statement_cache_size,
+			url,
+		};
+		Connection::connect(options).await.unwrap()
+	}
+
+	#[tokio::test]
+	#[ignore = "requires a postgres server"]
+	async fn json_round_trip() {
+		let connection = connect(crate::DEFAULT_STATEMENT_CACHE_SIZE).await;
+		connection
+			.execute(
+				"create temporary table test (text text, json jsonb);".to_owned(),
+				Vec::new(),
+			)
+			.await
+			.unwrap();
+
+		// Insert a string as serialized JSON in the text column and as a JSON value in the json column.
+		let text = serde_json::to_string("abc").unwrap();
+		let json = serde_json::json!("abc");
+		connection
+			.execute(
+				"insert into test (text, json) values ($1, $2);".to_owned(),
+				vec![Value::Text(text), Value::Json(json)],
+			)
+			.await
+			.unwrap();
+
+		// Verify that both deserialize to the same string.
+		let Json(text) = connection
+			.query_one_value_into::<Json<String>>("select text from test;".to_owned(), Vec::new())
+			.await
+			.unwrap();
+		assert_eq!(text, "abc");
+		let Json(json) = connection
+			.query_one_value_into::<Json<String>>("select json from test;".to_owned(), Vec::new())
+			.await
+			.unwrap();
+		assert_eq!(json, "abc");
+	}
+
+	#[tokio::test]
+	#[ignore = "requires a postgres server"]
+	async fn statement_timeout() {
+		let mut connection = connect(crate::DEFAULT_STATEMENT_CACHE_SIZE).await;
+
+		// Verify that the timeout is applied to the connection.
+		connection
+			.set_timeout(Some(Duration::from_millis(10)))
+			.await
+			.unwrap();
+		let value = connection
+			.query_one_value_into::<String>("show statement_timeout;".to_owned(), Vec::new())
+			.await
+			.unwrap();
+		assert_eq!(value, "10ms");
+
+		// Verify that a statement that runs past the timeout is canceled.
+		let error = connection
+			.query_all("select pg_sleep(1);".to_owned(), Vec::new())
+			.await
+			.unwrap_err();
+		let Error::Postgres(error) = &error else {
+			panic!("{error:?}");
+		};
+		assert_eq!(
+			error.code(),
+			Some(&postgres::error::SqlState::QUERY_CANCELED)
+		);
+
+		// Verify that the timeout is cleared.
+		connection.set_timeout(None).await.unwrap();
+		let value = connection
+			.query_one_value_into::<String>("show statement_timeout;".to_owned(), Vec::new())
+			.await
+			.unwrap();
+		assert_eq!(value, "0");
+	}
+
+	#[tokio::test]
+	#[ignore = "requires a postgres server"]
+	async fn statement_cache_evicts_least_recently_used() {
+		let connection = connect(2).await;
+		let cache = &connection.cache;
+		let client = &connection.client;
+
+		// Prepare two statements, then use the first again so that the second is the least recently used.
+		cache.get(client, "select 1;").await.unwrap();
+		cache.get(client, "select 2;").await.unwrap();
+		cache.get(client, "select 1;").await.unwrap();
+
+		// Prepare a third statement, which must evict the second.
+		cache.get(client, "select 3;").await.unwrap();
+		let statements = cache
+			.statements
+			.lock()
+			.await
+			.keys()
+			.cloned()
+			.collect::<Vec<_>>();
+		assert_eq!(statements, vec!["select 1;", "select 3;"]);
+
+		// Verify that a cache with no capacity does not cache statements.
+		let connection = connect(0).await;
+		connection
+			.cache
+			.get(&connection.client, "select 1;")
+			.await
+			.unwrap();
+		assert!(connection.cache.statements.lock().await.is_empty());
+	}
 }
@@ -7,7 +7,7 @@ use indexmap::IndexMap;
 use itertools::Itertools as _;
 use num::ToPrimitive as _;
 use rusqlite as sqlite;
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
 #[derive(Debug, derive_more::Display, derive_more::Error, derive_more::From)]
 pub enum Error {
@@ -19,32 +19,42 @@ pub struct DatabaseOptions {
 	pub connections: usize,
 	pub initialize: Initialize,
 	pub path: PathBuf,
+
+	/// The maximum number of prepared statements to cache per connection.
+	pub statement_cache_size: usize,
+
+	/// The default maximum duration of each statement, for connections that do not set a timeout. If this is not set, then statements do not time out.
+	pub timeout: Option<Duration>,
 }
 
 type Initialize = Arc<dyn Fn(&sqlite::Connection) -> sqlite::Result<()> + Send + Sync + 'static>;
 
 pub struct Database {
-	#[allow(dead_code)]
 	options: DatabaseOptions,
 	read_pool: Pool<Connection>,
 	write_pool: Pool<Connection>,
 }
 
 pub struct Connection {
+	interrupt: Arc<sqlite::InterruptHandle>,
 	#[allow(dead_code)]
 	options: ConnectionOptions,
 	sender: tokio::sync::mpsc::UnboundedSender<ConnectionMessage>,
+	timeout: Option<Duration>,
 }
 
 pub struct ConnectionOptions {
 	pub flags: rusqlite::OpenFlags,
 	pub initialize: Initialize,
 	pub path: PathBuf,
+	pub statement_cache_size: usize,
 }
 
 pub struct Transaction<'a> {
+	interrupt: Arc<sqlite::InterruptHandle>,
 	marker: std::marker::PhantomData<&'a ()>,
 	sender: tokio::sync::mpsc::UnboundedSender<TransactionMessage>,
+	timeout: Option<Duration>,
 }
 
 enum ConnectionMessage {
@@ -114,6 +124,7 @@ impl Database {
 			flags: rusqlite::OpenFlags::default(),
 			initialize: options.initialize.clone(),
 			path: options.path.clone(),
+			statement_cache_size: options.statement_cache_size,
 		};
 		let connection = Connection::connect(options_).await?;
 		write_pool.add(connection);
@@ -127,6 +138,7 @@ impl Database {
 				flags,
 				initialize: options.initialize.clone(),
 				path: options.path.clone(),
+				statement_cache_size: options.statement_cache_size,
 			};
 			let connection = Connection::connect(options).await?;
 			read_pool.add(connection);
@@ -154,9 +166,16 @@ impl Connection {
 	pub async fn connect(options: ConnectionOptions) -> Result<Self, Error> {
 		let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
 		let connection = sqlite::Connection::open_with_flags(&options.path, options.flags)?;
+		connection.set_prepared_statement_cache_capacity(options.statement_cache_size);
 		(options.initialize)(&connection)?;
+		let interrupt = Arc::new(connection.get_interrupt_handle());
 		tokio::task::spawn_blocking(|| Self::run(connection, receiver));
-		let connection = Self { options, sender };
+		let connection = Self {
+			interrupt,
+			options,
+			sender,
+			timeout: None,
+		};
 		Ok(connection)
 	}
 
@@ -279,10 +298,11 @@ impl super::Database for Database {
 		&self,
 		options: super::ConnectionOptions,
 	) -> Result<Self::T, Self::Error> {
-		let connection = match options.kind {
+		let mut connection = match options.kind {
 			crate::ConnectionKind::Read => self.read_pool.get(options.priority).await,
 			crate::ConnectionKind::Write => self.write_pool.get(options.priority).await,
 		};
+		connection.timeout = options.timeout.or(self.options.timeout);
 		Ok(connection)
 	}
 }
@@ -301,7 +321,12 @@ impl super::Connection for Connection {
 		let message = ConnectionMessage::Transaction(ConnectionTransactionMessage { sender });
 		self.sender.send(message).unwrap();
 		let sender = receiver.await.unwrap()?;
-		Ok(Transaction { marker, sender })
+		Ok(Transaction {
+			interrupt: self.interrupt.clone(),
+			marker,
+			sender,
+			timeout: self.timeout,
+		})
 	}
 }
 
@@ -346,6 +371,7 @@ impl super::Query for Connection {
 	}
 
 	async fn execute(&self, statement: String, params: Vec<Value>) -> Result<u64, Self::Error> {
+		let deadline = deadline(self.timeout);
 		let (sender, receiver) = tokio::sync::oneshot::channel();
 		let message = ConnectionMessage::Execute(ExecuteMessage {
 			statement,
@@ -353,7 +379,7 @@ impl super::Query for Connection {
 			sender,
 		});
 		self.sender.send(message).unwrap();
-		let n = receiver.await.unwrap()?;
+		let n = wait(receiver, &self.interrupt, deadline).await.unwrap()?;
 		Ok(n)
 	}
 
@@ -362,6 +388,7 @@ impl super::Query for Connection {
 		statement: String,
 		params: Vec<Value>,
 	) -> Result<impl Stream<Item = Result<Row, Self::Error>> + Send, Self::Error> {
+		let deadline = deadline(self.timeout);
 		let (sender, receiver) = tokio::sync::oneshot::channel();
 		let message = ConnectionMessage::Query(QueryMessage {
 			statement,
@@ -369,19 +396,23 @@ impl super::Query for Connection {
 			sender,
 		});
 		self.sender.send(message).unwrap();
-		let sender = receiver.await.unwrap()?;
-		let rows = stream::try_unfold(sender, |sender| async move {
-			let (sender_, receiver) = tokio::sync::oneshot::channel();
-			sender
-				.send(sender_)
-				.map_err(|_| Error::other("failed to get the next row"))?;
-			let result = receiver
-				.await
-				.map_err(|_| Error::other("failed to get the next row"))?;
-			match result {
-				Ok(Some(row)) => Ok(Some((row, sender))),
-				Ok(None) => Ok(None),
-				Err(error) => Err(error),
+		let sender = wait(receiver, &self.interrupt, deadline).await.unwrap()?;
+		let interrupt = self.interrupt.clone();
+		let rows = stream::try_unfold(sender, move |sender| {
+			let interrupt = interrupt.clone();
+			async move {
+				let (sender_, receiver) = tokio::sync::oneshot::channel();
+				sender
+					.send(sender_)
+					.map_err(|_| Error::other("failed to get the next row"))?;
+				let result = wait(receiver, &interrupt, deadline)
+					.await
+					.map_err(|_| Error::other("failed to get the next row"))?;
+				match result {
+					Ok(Some(row)) => Ok(Some((row, sender))),
+					Ok(None) => Ok(None),
+					Err(error) => Err(error),
+				}
 			}
 		});
 		Ok(rows)
@@ -406,6 +437,7 @@ impl super::Query for Connection {
 		statement: String,
 		params: Vec<Value>,
 	) -> Result<Vec<Row>, Self::Error> {
+		let deadline = deadline(self.timeout);
 		let (sender, receiver) = tokio::sync::oneshot::channel();
 		let message = ConnectionMessage::QueryAll(QueryAllMessage {
 			statement,
@@ -413,7 +445,7 @@ impl super::Query for Connection {
 			sender,
 		});
 		self.sender.send(message).unwrap();
-		let rows = receiver.await.unwrap()?;
+		let rows = wait(receiver, &self.interrupt, deadline).await.unwrap()?;
 		Ok(rows)
 	}
 }
@@ -475,6 +507,7 @@ impl super::Query for Transaction<'_> {
 	}
 
 	async fn execute(&self, statement: String, params: Vec<Value>) -> Result<u64, Self::Error> {
+		let deadline = deadline(self.timeout);
 		let (sender, receiver) = tokio::sync::oneshot::channel();
 		let message = TransactionMessage::Execute(ExecuteMessage {
 			statement,
@@ -482,7 +515,7 @@ impl super::Query for Transaction<'_> {
 			sender,
 		});
 		self.sender.send(message).unwrap();
-		let n = receiver.await.unwrap()?;
+		let n = wait(receiver, &self.interrupt, deadline).await.unwrap()?;
 		Ok(n)
 	}
 
@@ -491,6 +524,7 @@ impl super::Query for Transaction<'_> {
 		statement: String,
 		params: Vec<Value>,
 	) -> Result<impl Stream<Item = Result<Row, Self::Error>> + Send, Self::Error> {
+		let deadline = deadline(self.timeout);
 		let (sender, receiver) = tokio::sync::oneshot::channel();
 		let message = TransactionMessage::Query(QueryMessage {
 			statement,
@@ -498,19 +532,23 @@ impl super::Query for Transaction<'_> {
 			sender,
 		});
 		self.sender.send(message).unwrap();
-		let sender = receiver.await.unwrap()?;
-		let rows = stream::try_unfold(sender, |sender| async move {
-			let (sender_, receiver) = tokio::sync::oneshot::channel();
-			sender
-				.send(sender_)
-				.map_err(|_| Error::other("failed to get the next row"))?;
-			let result = receiver
-				.await
-				.map_err(|_| Error::other("failed to get the next row"))?;
-			match result {
-				Ok(Some(row)) => Ok(Some((row, sender))),
-				Ok(None) => Ok(None),
-				Err(error) => Err(error),
+		let sender = wait(receiver, &self.interrupt, deadline).await.unwrap()?;
+		let interrupt = self.interrupt.clone();
+		let rows = stream::try_unfold(sender, move |sender| {
+			let interrupt = interrupt.clone();
+			async move {
+				let (sender_, receiver) = tokio::sync::oneshot::channel();
+				sender
+					.send(sender_)
+					.map_err(|_| Error::other("failed to get the next row"))?;
+				let result = wait(receiver, &interrupt, deadline)
+					.await
+					.map_err(|_| Error::other("failed to get the next row"))?;
+				match result {
+					Ok(Some(row)) => Ok(Some((row, sender))),
+					Ok(None) => Ok(None),
+					Err(error) => Err(error),
+				}
 			}
 		});
 		Ok(rows)
@@ -535,6 +573,7 @@ impl super::Query for Transaction<'_> {
 		statement: String,
 		params: Vec<Value>,
 	) -> Result<Vec<Row>, Self::Error> {
+		let deadline = deadline(self.timeout);
 		let (sender, receiver) = tokio::sync::oneshot::channel();
 		let message = TransactionMessage::QueryAll(QueryAllMessage {
 			statement,
@@ -542,11 +581,32 @@ impl super::Query for Transaction<'_> {
 			sender,
 		});
 		self.sender.send(message).unwrap();
-		let rows = receiver.await.unwrap()?;
+		let rows = wait(receiver, &self.interrupt, deadline).await.unwrap()?;
 		Ok(rows)
 	}
 }
 
+/// Get the deadline for a statement that starts now.
+fn deadline(timeout: Option<Duration>) -> Option<tokio::time::Instant> {
+	timeout.map(|timeout| tokio::time::Instant::now() + timeout)
+}
+
+/// Wait for a response from a connection's thread. If the deadline passes first, then interrupt the statement that is executing, which causes it to return an error.
+async fn wait<T>(
+	mut receiver: tokio::sync::oneshot::Receiver<T>,
+	interrupt: &sqlite::InterruptHandle,
+	deadline: Option<tokio::time::Instant>,
+) -> Result<T, tokio::sync::oneshot::error::RecvError> {
+	let Some(deadline) = deadline else {
+		return receiver.await;
+	};
+	if let Ok(result) = tokio::time::timeout_at(deadline, &mut receiver).await {
+		return result;
+	}
+	interrupt.interrupt();
+	receiver.await
+}
+
 fn handle_execute_message(connection: &sqlite::Connection, message: ExecuteMessage) {
 	let ExecuteMessage {
 		statement,
@@ -713,6 +773,9 @@ impl sqlite::types::ToSql for Value {
 			Value::Blob(value) => Ok(sqlite::types::ToSqlOutput::Borrowed(
 				sqlite::types::ValueRef::Blob(value.as_ref()),
 			)),
+			Value::Json(value) => Ok(sqlite::types::ToSqlOutput::Owned(
+				sqlite::types::Value::Text(value.to_string()),
+			)),
 		}
 	}
 }
@@ -766,6 +829,7 @@ impl From<Value> for sqlite::types::Value {
 			Value::Real(value) => Self::Real(value),
 			Value::Text(value) => Self::Text(value),
 			Value::Blob(value) => Self::Blob(value),
+			Value::Json(value) => Self::Text(value.to_string()),
 		}
 	}
 }
@@ -794,3 +858,116 @@ where
 		Ok(Self(value))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Database as _, Error as _, Query as _};
+	use serde::Deserialize as _;
+
+	#[test]
+	fn json_round_trip() {
+		let connection = sqlite::Connection::open_in_memory().unwrap();
+		connection
+			.execute("create table test (value text)", [])
+			.unwrap();
+		let value = Value::Json(serde_json::json!(["a", "b"]));
+		connection
+			.execute("insert into test (value) values (?1)", [&value])
+			.unwrap();
+		let value = connection
+			.query_row("select value from test", [], |row| row.get::<_, Value>(0))
+			.unwrap();
+		assert_eq!(value.try_unwrap_text_ref().unwrap(), r#"["a","b"]"#);
+		let crate::value::Json(left) =
+			crate::value::Json::<Vec<String>>::deserialize(value).unwrap();
+		assert_eq!(left, vec!["a".to_owned(), "b".to_owned()]);
+	}
+
+	#[test]
+	fn json_value_deserialize() {
+		let value = Value::Json(serde_json::json!({ "a": 1 }));
+		let crate::value::Json(left) =
+			crate::value::Json::<std::collections::BTreeMap<String, u64>>::deserialize(value)
+				.unwrap();
+		assert_eq!(left.get("a"), Some(&1));
+	}
+
+	#[test]
+	fn json_string_deserialize() {
+		// A JSON value that is a string must not be parsed again.
+		let value = Value::Json(serde_json::json!("abc"));
+		let crate::value::Json(left) = crate::value::Json::<String>::deserialize(value).unwrap();
+		assert_eq!(left, "abc");
+
+		// Serialized JSON in a text column must be parsed.
+		let value = Value::Text(r#""abc""#.to_owned());
+		let crate::value::Json(left) = crate::value::Json::<String>::deserialize(value).unwrap();
+		assert_eq!(left, "abc");
+	}
+
+	#[tokio::test]
+	async fn statement_timeout() {
+		let options = ConnectionOptions {
+			flags: rusqlite::OpenFlags::default(),
+			initialize: Arc::new(|_| Ok(())),
+			path: ":memory:".into(),
+			statement_cache_size: crate::DEFAULT_STATEMENT_CACHE_SIZE,
+		};
+		let mut connection = Connection::connect(options).await.unwrap();
+		connection.timeout = Some(Duration::from_millis(10));
+		let statement = "
+			with recursive c (x) as (select 1 union all select x + 1 from c limit 1000000000)
+			select count(*) from c;
+		"
+		.to_owned();
+		let error = connection
+			.query_all(statement, Vec::new())
+			.await
+			.unwrap_err();
+		assert!(
+			matches!(
+				&error,
+				Error::Sqlite(sqlite::Error::SqliteFailure(error, _))
+					if error.code == sqlite::ErrorCode::OperationInterrupted
+			),
+			"{error:?}"
+		);
+		assert!(!error.is_retry());
+		let rows = connection
+			.query_all("select 1;".to_owned(), Vec::new())
+			.await
+			.unwrap();
+		assert_eq!(rows.len(), 1);
+	}
+
+	#[tokio::test]
+	async fn default_timeout() {
+		let options = DatabaseOptions {
+			connections: 0,
+			initialize: Arc::new(|_| Ok(())),
+			path: ":memory:".into(),
+			statement_cache_size: crate::DEFAULT_STATEMENT_CACHE_SIZE,
+			timeout: Some(Duration::from_millis(10)),
+		};
+		let database = Database::new(options).await.unwrap();
+		let connection = database.write_connection().await.unwrap();
+		let statement = "
+			with recursive c (x) as (select 1 union all select x + 1 from c limit 1000000000)
+			select count(*) from c;
+		"
+		.to_owned();
+		let error = connection
+			.query_all(statement, Vec::new())
+			.await
+			.unwrap_err();
+		assert!(
+			matches!(
+				&error,
+				Error::Sqlite(sqlite::Error::SqliteFailure(error, _))
+					if error.code == sqlite::ErrorCode::OperationInterrupted
+			),
+			"{error:?}"
+		);
+	}
+}
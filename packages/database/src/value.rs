@@ -22,4 +22,5 @@ pub enum Value {
 	Real(f64),
 	Text(String),
 	Blob(Vec<u8>),
+	Json(serde_json::Value),
 }
@@ -1,5 +1,5 @@
 use super::Value;
-use serde::de::Error as _;
+use serde::{de::Error as _, Deserializer as _};
 
 #[derive(Debug, derive_more::Display, derive_more::Error, derive_more::From)]
 pub enum Error {
@@ -27,6 +27,7 @@ impl<'de> serde::Deserializer<'de> for Value {
 			Self::Real(value) => visitor.visit_f64(value),
 			Self::Text(value) => visitor.visit_string(value),
 			Self::Blob(value) => visitor.visit_byte_buf(value),
+			Self::Json(value) => value.deserialize_any(visitor).map_err(Error::custom),
 		}
 	}
 
@@ -34,6 +35,9 @@ impl<'de> serde::Deserializer<'de> for Value {
 	where
 		V: serde::de::Visitor<'de>,
 	{
+		if let Self::Json(value) = self {
+			return value.deserialize_bool(visitor).map_err(Error::custom);
+		}
 		let value = self
 			.try_unwrap_integer()
 			.map_err(|_| Error::custom("expected an integer value"))?;
@@ -41,6 +45,22 @@ impl<'de> serde::Deserializer<'de> for Value {
 		visitor.visit_bool(value)
 	}
 
+	fn deserialize_newtype_struct<V>(
+		self,
+		name: &'static str,
+		visitor: V,
+	) -> Result<V::Value, Self::Error>
+	where
+		V: serde::de::Visitor<'de>,
+	{
+		match self {
+			Self::Json(value) if name == super::json::NAME => {
+				visitor.visit_newtype_struct(value).map_err(Error::custom)
+			},
+			_ => self.deserialize_any(visitor),
+		}
+	}
+
 	fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
 	where
 		V: serde::de::Visitor<'de>,
@@ -51,7 +71,7 @@ impl<'de> serde::Deserializer<'de> for Value {
 		}
 	}
 
-	serde::forward_to_deserialize_any!(i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any);
+	serde::forward_to_deserialize_any!(i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct enum identifier ignored_any);
 }
 
 impl serde::de::Error for Error {
@@ -1,3 +1,8 @@
+use std::marker::PhantomData;
+
+/// The name Json passes to `deserialize_newtype_struct`, so that a `Value` can tell it apart from other newtypes.
+pub(crate) const NAME: &str = "$tangram_database::Json";
+
 pub struct Json<T>(pub T);
 
 impl<T> serde::Serialize for Json<T>
@@ -21,8 +26,37 @@ where
 	where
 		D: serde::Deserializer<'de>,
 	{
-		let json = String::deserialize(deserializer)?;
-		let value = serde_json::from_str(&json).map_err(serde::de::Error::custom)?;
+		deserializer.deserialize_newtype_struct(NAME, Visitor(PhantomData))
+	}
+}
+
+struct Visitor<T>(PhantomData<T>);
+
+impl<'de, T> serde::de::Visitor<'de> for Visitor<T>
+where
+	T: serde::de::DeserializeOwned,
+{
+	type Value = Json<T>;
+
+	fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+		formatter.write_str("serialized JSON or a JSON value")
+	}
+
+	// Text columns contain the serialized JSON.
+	fn visit_str<E>(self, json: &str) -> Result<Self::Value, E>
+	where
+		E: serde::de::Error,
+	{
+		let value = serde_json::from_str(json).map_err(E::custom)?;
+		Ok(Json(value))
+	}
+
+	// JSON columns contain the value itself, which must not be parsed again, even if it is a string.
+	fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let value = T::deserialize(deserializer)?;
 		Ok(Json(value))
 	}
 }
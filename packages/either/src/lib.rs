@@ -1,3 +1,10 @@
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncWrite, ReadBuf};
+
 #[derive(
 	Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, serde::Deserialize, serde::Serialize,
 )]
@@ -64,6 +71,118 @@ impl<L, R> Either<L, R> {
 			Self::Right(right) => Either::Right(f(right)),
 		}
 	}
+
+	/// Apply `f` to the left value or `g` to the right value.
+	///
+	/// ```
+	/// # use tangram_either::Either;
+	/// let value = Either::<u8, &str>::Left(1);
+	/// let value = value.map_either(|n| n + 1, str::len);
+	/// assert_eq!(value, Either::Left(2));
+	/// ```
+	pub fn map_either<F, G, L2, R2>(self, f: F, g: G) -> Either<L2, R2>
+	where
+		F: FnOnce(L) -> L2,
+		G: FnOnce(R) -> R2,
+	{
+		match self {
+			Self::Left(left) => Either::Left(f(left)),
+			Self::Right(right) => Either::Right(g(right)),
+		}
+	}
+
+	/// Apply `f` to the left value or `g` to the right value, and return the result.
+	///
+	/// ```
+	/// # use tangram_either::Either;
+	/// let value = Either::<u8, &str>::Right("hello");
+	/// assert_eq!(value.either(usize::from, str::len), 5);
+	/// ```
+	pub fn either<F, G, T>(self, f: F, g: G) -> T
+	where
+		F: FnOnce(L) -> T,
+		G: FnOnce(R) -> T,
+	{
+		match self {
+			Self::Left(left) => f(left),
+			Self::Right(right) => g(right),
+		}
+	}
+
+	/// Replace the left value with the result of `f`, which may be either value.
+	///
+	/// ```
+	/// # use tangram_either::Either;
+	/// let value = Either::<u8, &str>::Left(0);
+	/// let value = value.left_and_then(|n| if n == 0 { Either::Right("zero") } else { Either::Left(n) });
+	/// assert_eq!(value, Either::Right("zero"));
+	/// ```
+	pub fn left_and_then<F, L2>(self, f: F) -> Either<L2, R>
+	where
+		F: FnOnce(L) -> Either<L2, R>,
+	{
+		match self {
+			Self::Left(left) => f(left),
+			Self::Right(right) => Either::Right(right),
+		}
+	}
+
+	/// Replace the right value with the result of `f`, which may be either value.
+	///
+	/// ```
+	/// # use tangram_either::Either;
+	/// let value = Either::<u8, &str>::Right("1");
+	/// let value = value.right_and_then(|s| s.parse().map_or(Either::Right(s), Either::Left));
+	/// assert_eq!(value, Either::Left(1));
+	/// ```
+	pub fn right_and_then<F, R2>(self, f: F) -> Either<L, R2>
+	where
+		F: FnOnce(R) -> Either<L, R2>,
+	{
+		match self {
+			Self::Left(left) => Either::Left(left),
+			Self::Right(right) => f(right),
+		}
+	}
+
+	pub fn as_pin_mut(self: Pin<&mut Self>) -> Either<Pin<&mut L>, Pin<&mut R>> {
+		unsafe {
+			match self.get_unchecked_mut() {
+				Self::Left(left) => Either::Left(Pin::new_unchecked(left)),
+				Self::Right(right) => Either::Right(Pin::new_unchecked(right)),
+			}
+		}
+	}
+}
+
+impl<T> Either<T, T> {
+	/// Apply `f` to whichever value is present.
+	///
+	/// ```
+	/// # use tangram_either::Either;
+	/// let value = Either::<u8, u8>::Right(1);
+	/// assert_eq!(value.map_inner(|n| n * 2), Either::Right(2));
+	/// ```
+	pub fn map_inner<F, U>(self, f: F) -> Either<U, U>
+	where
+		F: FnOnce(T) -> U,
+	{
+		match self {
+			Self::Left(left) => Either::Left(f(left)),
+			Self::Right(right) => Either::Right(f(right)),
+		}
+	}
+
+	/// Return whichever value is present.
+	///
+	/// ```
+	/// # use tangram_either::Either;
+	/// let value = Either::<u8, u8>::Left(1);
+	/// assert_eq!(value.into_inner(), 1);
+	/// ```
+	pub fn into_inner(self) -> T {
+		for_both!(self, value => value)
+	}
 }
 
 impl<L, R> std::fmt::Display for Either<L, R>
@@ -178,6 +297,93 @@ where
 	}
 }
 
+/// Poll whichever future is present.
+///
+/// ```
+/// # use tangram_either::Either;
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let future = if true {
+/// 	Either::Left(async { 1 })
+/// } else {
+/// 	Either::Right(std::future::ready(2))
+/// };
+/// assert_eq!(future.await, 1);
+/// # });
+/// ```
+impl<L, R> Future for Either<L, R>
+where
+	L: Future,
+	R: Future<Output = L::Output>,
+{
+	type Output = L::Output;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		for_both!(self.as_pin_mut(), inner => inner.poll(cx))
+	}
+}
+
+impl<L, R> AsyncRead for Either<L, R>
+where
+	L: AsyncRead,
+	R: AsyncRead,
+{
+	fn poll_read(
+		self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut ReadBuf<'_>,
+	) -> Poll<std::io::Result<()>> {
+		for_both!(self.as_pin_mut(), inner => inner.poll_read(cx, buf))
+	}
+}
+
+impl<L, R> AsyncBufRead for Either<L, R>
+where
+	L: AsyncBufRead,
+	R: AsyncBufRead,
+{
+	fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<&[u8]>> {
+		for_both!(self.as_pin_mut(), inner => inner.poll_fill_buf(cx))
+	}
+
+	fn consume(self: Pin<&mut Self>, amt: usize) {
+		for_both!(self.as_pin_mut(), inner => inner.consume(amt));
+	}
+}
+
+impl<L, R> AsyncWrite for Either<L, R>
+where
+	L: AsyncWrite,
+	R: AsyncWrite,
+{
+	fn poll_write(
+		self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+	) -> Poll<std::io::Result<usize>> {
+		for_both!(self.as_pin_mut(), inner => inner.poll_write(cx, buf))
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+		for_both!(self.as_pin_mut(), inner => inner.poll_flush(cx))
+	}
+
+	fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+		for_both!(self.as_pin_mut(), inner => inner.poll_shutdown(cx))
+	}
+
+	fn poll_write_vectored(
+		self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		bufs: &[std::io::IoSlice<'_>],
+	) -> Poll<std::io::Result<usize>> {
+		for_both!(self.as_pin_mut(), inner => inner.poll_write_vectored(cx, bufs))
+	}
+
+	fn is_write_vectored(&self) -> bool {
+		for_both!(self, inner => inner.is_write_vectored())
+	}
+}
+
 #[macro_export]
 macro_rules! for_both {
 	($value:expr, $pattern:pat => $result:expr) => {
@@ -18,6 +18,7 @@ pub enum Error {
 
 #[derive(Debug)]
 pub struct Matcher {
+	case_insensitive: bool,
 	file_names: Vec<OsString>,
 	global: Option<File>,
 	root: Arc<RwLock<Node>>,
@@ -32,26 +33,51 @@ struct Node {
 #[derive(Debug)]
 struct File {
 	glob_set: GlobSet,
+	path: Option<PathBuf>,
 	patterns: Vec<Pattern>,
 }
 
 #[derive(Debug)]
 struct Pattern {
+	line: usize,
 	negated: bool,
-	#[allow(dead_code)]
 	string: String,
 	trailing_slash: bool,
 }
 
+/// The pattern that determines whether a path is ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+	/// The path of the ignore file that contains the pattern, or `None` if it is a global pattern.
+	pub path: Option<PathBuf>,
+
+	/// The line number of the pattern, starting from one.
+	pub line: usize,
+
+	/// The pattern as it is written in the ignore file.
+	pub pattern: String,
+
+	/// Whether the pattern is negated. If it is, then the path is not ignored.
+	pub negated: bool,
+}
+
 impl Matcher {
-	pub async fn new(file_names: Vec<OsString>, global: Option<&str>) -> Result<Self, Error> {
-		let root = Self::node_with_path_and_file_names(Path::new("/"), &file_names).await?;
+	/// Create a matcher. If `case_insensitive` is set, then patterns match paths regardless of case, which mirrors how paths resolve on case-insensitive file systems.
+	pub async fn new(
+		file_names: Vec<OsString>,
+		global: Option<&str>,
+		case_insensitive: bool,
+	) -> Result<Self, Error> {
+		let root =
+			Self::node_with_path_and_file_names(Path::new("/"), &file_names, case_insensitive)
+				.await?;
 		let global = if let Some(global) = global {
-			Some(Self::file_with_contents(global)?)
+			Some(Self::file_with_contents(global, None, case_insensitive)?)
 		} else {
 			None
 		};
 		Ok(Self {
+			case_insensitive,
 			file_names,
 			global,
 			root,
@@ -59,6 +85,16 @@ impl Matcher {
 	}
 
 	pub async fn matches(&self, path: &Path, is_directory: Option<bool>) -> Result<bool, Error> {
+		let output = self.explain(path, is_directory).await?;
+		Ok(output.is_some_and(|output| !output.negated))
+	}
+
+	/// Get the pattern that determines whether a path is ignored, or `None` if no pattern matches the path.
+	pub async fn explain(
+		&self,
+		path: &Path,
+		is_directory: Option<bool>,
+	) -> Result<Option<Match>, Error> {
 		// Check if the path is a directory if necessary.
 		let is_directory = if let Some(is_directory) = is_directory {
 			is_directory
@@ -73,7 +109,7 @@ impl Matcher {
 			.components()
 			.peekable();
 
-		// Get or create the nodes.
+		// Get or create the nodes of the path's ancestors. The path's own node is not included, because the ignore files in a directory do not apply to the directory itself.
 		let mut path = PathBuf::from("/");
 		let mut nodes = vec![self.root.clone()];
 		while let Some(component) = components.next() {
@@ -81,12 +117,20 @@ impl Matcher {
 			let std::path::Component::Normal(name) = component else {
 				return Err(Error::Path);
 			};
+			if components.peek().is_none() {
+				break;
+			}
 			let node = nodes.last().unwrap();
 			let option = node.read().unwrap().children.get(name).cloned();
 			let child = if let Some(child) = option {
 				child
-			} else if components.peek().is_some() {
-				let child = Self::node_with_path_and_file_names(&path, &self.file_names).await?;
+			} else {
+				let child = Self::node_with_path_and_file_names(
+					&path,
+					&self.file_names,
+					self.case_insensitive,
+				)
+				.await?;
 				let child = node
 					.write()
 					.unwrap()
@@ -95,74 +139,105 @@ impl Matcher {
 					.or_insert(child)
 					.clone();
 				child
-			} else {
-				break;
 			};
 			nodes.push(child);
 		}
 
-		// Match.
+		// Match. The nodes are checked from the deepest to the root, so a pattern in a descendant's ignore file takes precedence over one in an ancestor's.
 		let mut matches = Vec::new();
 		for (node, node_path) in std::iter::zip(nodes.iter().rev(), path.ancestors().skip(1)) {
 			let candidate = Candidate::new(path.strip_prefix(node_path).unwrap());
 			let files = &node.read().unwrap().files;
 			for file in files {
-				file.glob_set
-					.matches_candidate_into(&candidate, &mut matches);
-				if let Some(index) = matches.last() {
-					let pattern = file.patterns.get(*index).unwrap();
-					if !pattern.trailing_slash || is_directory {
-						return Ok(!pattern.negated);
-					}
+				if let Some(output) = file.matches(&candidate, is_directory, &mut matches) {
+					return Ok(Some(output));
 				}
 			}
 		}
 		if let Some(global) = &self.global {
 			let candidate = Candidate::new(path.strip_prefix("/").unwrap());
-			global
-				.glob_set
-				.matches_candidate_into(&candidate, &mut matches);
-			if let Some(index) = matches.last() {
-				let pattern = global.patterns.get(*index).unwrap();
-				if !pattern.trailing_slash || is_directory {
-					return Ok(!pattern.negated);
-				}
+			if let Some(output) = global.matches(&candidate, is_directory, &mut matches) {
+				return Ok(Some(output));
 			}
 		}
 
-		Ok(false)
+		Ok(None)
+	}
+
+	/// Reload the ignore files in a directory. The matcher reads the ignore files in each directory once, so call this after an ignore file in the directory is created, modified, or removed. Ignore files in other directories, including the directory's descendants, are not reloaded.
+	pub async fn invalidate(&self, path: &Path) -> Result<(), Error> {
+		// Get the directory's node. If it has not been created, then there is nothing to reload.
+		let components = path
+			.strip_prefix("/")
+			.map_err(|_| Error::Path)?
+			.components();
+		let mut node = self.root.clone();
+		for component in components {
+			let std::path::Component::Normal(name) = component else {
+				return Err(Error::Path);
+			};
+			let child = node.read().unwrap().children.get(name).cloned();
+			let Some(child) = child else {
+				return Ok(());
+			};
+			node = child;
+		}
+
+		// Reload the files.
+		let files =
+			Self::files_with_path_and_file_names(path, &self.file_names, self.case_insensitive)
+				.await?;
+		node.write().unwrap().files = files;
+
+		Ok(())
 	}
 
 	async fn node_with_path_and_file_names(
 		path: &Path,
 		file_names: &[OsString],
+		case_insensitive: bool,
 	) -> Result<Arc<RwLock<Node>>, Error> {
+		let files =
+			Self::files_with_path_and_file_names(path, file_names, case_insensitive).await?;
+		let node = Arc::new(RwLock::new(Node {
+			children: BTreeMap::new(),
+			files,
+		}));
+		Ok(node)
+	}
+
+	async fn files_with_path_and_file_names(
+		path: &Path,
+		file_names: &[OsString],
+		case_insensitive: bool,
+	) -> Result<Vec<File>, Error> {
 		let mut files = Vec::new();
 		for name in file_names {
-			let contents = match tokio::fs::read_to_string(path.join(name)).await {
+			let path = path.join(name);
+			let contents = match tokio::fs::read_to_string(&path).await {
 				Ok(contents) => contents,
 				Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
 					continue;
 				},
 				Err(error) => return Err(error.into()),
 			};
-			let file = Self::file_with_contents(&contents)?;
+			let file = Self::file_with_contents(&contents, Some(path), case_insensitive)?;
 			files.push(file);
 		}
-		let node = Arc::new(RwLock::new(Node {
-			children: BTreeMap::new(),
-			files,
-		}));
-		Ok(node)
+		Ok(files)
 	}
 
-	fn file_with_contents(contents: &str) -> Result<File, Error> {
+	fn file_with_contents(
+		contents: &str,
+		path: Option<PathBuf>,
+		case_insensitive: bool,
+	) -> Result<File, Error> {
 		// Create the patterns and glob set builder.
 		let mut patterns = Vec::new();
 		let mut glob_set = GlobSetBuilder::new();
 
 		// Handle each line.
-		for mut line in contents.lines() {
+		for (index, mut line) in contents.lines().enumerate() {
 			// Skip commented lines.
 			if line.starts_with('#') {
 				continue;
@@ -177,6 +252,7 @@ impl Matcher {
 			if line.is_empty() {
 				continue;
 			}
+			let string = line.to_owned();
 
 			// Create the glob. This code is derived from here: <https://github.com/BurntSushi/ripgrep/blob/79cbe89deb1151e703f4d91b19af9cdcc128b765/crates/ignore/src/gitignore.rs#L436>.
 			let mut absolute = false;
@@ -202,16 +278,16 @@ impl Matcher {
 					line = &line[..line.len() - 1];
 				}
 			}
-			let mut string = line.to_owned();
-			if !absolute && !line.chars().any(|c| c == '/') && !string.starts_with("**/") {
-				string = format!("**/{string}");
+			let mut glob = line.to_owned();
+			if !absolute && !line.chars().any(|c| c == '/') && !glob.starts_with("**/") {
+				glob = format!("**/{glob}");
 			}
-			if string.ends_with("/**") {
-				string = format!("{string}/*");
+			if glob.ends_with("/**") {
+				glob = format!("{glob}/*");
 			}
-			let glob = GlobBuilder::new(&string)
+			let glob = GlobBuilder::new(&glob)
 				.literal_separator(true)
-				.case_insensitive(false)
+				.case_insensitive(case_insensitive)
 				.backslash_escape(true)
 				.build()?;
 
@@ -220,6 +296,7 @@ impl Matcher {
 
 			// Add the pattern.
 			let pattern = Pattern {
+				line: index + 1,
 				negated,
 				string,
 				trailing_slash,
@@ -230,6 +307,34 @@ impl Matcher {
 		// Build the glob set.
 		let glob_set = glob_set.build()?;
 
-		Ok(File { glob_set, patterns })
+		Ok(File {
+			glob_set,
+			path,
+			patterns,
+		})
+	}
+}
+
+impl File {
+	/// Get the pattern that determines whether the candidate is ignored. As in git, this is the last pattern in the file that matches the candidate, skipping patterns with a trailing slash if the candidate is not a directory.
+	fn matches(
+		&self,
+		candidate: &Candidate,
+		is_directory: bool,
+		matches: &mut Vec<usize>,
+	) -> Option<Match> {
+		self.glob_set.matches_candidate_into(candidate, matches);
+		matches.sort_unstable();
+		let pattern = matches
+			.iter()
+			.rev()
+			.map(|index| &self.patterns[*index])
+			.find(|pattern| !pattern.trailing_slash || is_directory)?;
+		Some(Match {
+			path: self.path.clone(),
+			line: pattern.line,
+			pattern: pattern.string.clone(),
+			negated: pattern.negated,
+		})
 	}
 }
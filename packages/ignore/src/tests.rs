@@ -1,4 +1,4 @@
-use super::Matcher;
+use super::{Match, Matcher};
 use indoc::indoc;
 use pretty_assertions::assert_eq;
 use tangram_temp::{self as temp, Temp};
@@ -29,7 +29,7 @@ async fn test() {
 			.DS_Store
 		"
 	);
-	let matcher = Matcher::new(file_names, Some(global)).await.unwrap();
+	let matcher = Matcher::new(file_names, Some(global), false).await.unwrap();
 	let right = vec![
 		(".DS_Store", true),
 		(".gitignore", false),
@@ -50,3 +50,193 @@ async fn test() {
 	}
 	assert_eq!(left, right);
 }
+
+#[tokio::test]
+async fn case_insensitive() {
+	let temp = Temp::new();
+	let artifact = temp::Artifact::from(temp::directory! {
+		".gitignore" => temp::file!(indoc!("
+			Cargo.toml
+		")),
+		"Cargo.toml" => temp::file!(""),
+		"cargo.toml" => temp::file!(""),
+	});
+	artifact.to_path(temp.path()).await.unwrap();
+	for (case_insensitive, right) in [
+		(false, vec![("Cargo.toml", true), ("cargo.toml", false)]),
+		(true, vec![("Cargo.toml", true), ("cargo.toml", true)]),
+	] {
+		let file_names = vec![".gitignore".into()];
+		let matcher = Matcher::new(file_names, None, case_insensitive)
+			.await
+			.unwrap();
+		let mut left = Vec::new();
+		for (path, _) in &right {
+			let matches = matcher
+				.matches(&temp.path().join(path), Some(false))
+				.await
+				.unwrap();
+			left.push((*path, matches));
+		}
+		assert_eq!(left, right);
+	}
+}
+
+#[tokio::test]
+async fn negation() {
+	let temp = Temp::new();
+	let artifact = temp::Artifact::from(temp::directory! {
+		".gitignore" => temp::file!(indoc!("
+			*.log
+			!keep.log
+			build
+			!build/
+		")),
+		"a.log" => temp::file!(""),
+		"keep.log" => temp::file!(""),
+		"build" => temp::file!(""),
+		"directory" => temp::directory! {
+			".gitignore" => temp::file!(indoc!("
+				!b.log
+				keep.log
+			")),
+			"a.log" => temp::file!(""),
+			"b.log" => temp::file!(""),
+			"keep.log" => temp::file!(""),
+			"build" => temp::directory! {},
+		},
+	});
+	artifact.to_path(temp.path()).await.unwrap();
+	let file_names = vec![".gitignore".into()];
+	let matcher = Matcher::new(file_names, None, false).await.unwrap();
+	let right = vec![
+		("a.log", true),
+		("keep.log", false),
+		("build", true),
+		("directory/a.log", true),
+		("directory/b.log", false),
+		("directory/keep.log", true),
+		("directory/build", false),
+	];
+	let mut left = Vec::new();
+	for (path, _) in &right {
+		let matches = matcher
+			.matches(&temp.path().join(path), None)
+			.await
+			.unwrap();
+		left.push((*path, matches));
+	}
+	assert_eq!(left, right);
+}
+
+#[tokio::test]
+async fn directory_ignore_file_does_not_apply_to_itself() {
+	let temp = Temp::new();
+	let artifact = temp::Artifact::from(temp::directory! {
+		"directory" => temp::directory! {
+			".gitignore" => temp::file!(indoc!("
+				directory
+			")),
+			"file" => temp::file!(""),
+		},
+	});
+	artifact.to_path(temp.path()).await.unwrap();
+	let file_names = vec![".gitignore".into()];
+	let matcher = Matcher::new(file_names, None, false).await.unwrap();
+	let path = temp.path().join("directory/file");
+	assert!(!matcher.matches(&path, None).await.unwrap());
+	let path = temp.path().join("directory");
+	assert!(!matcher.matches(&path, None).await.unwrap());
+}
+
+#[tokio::test]
+async fn explain() {
+	let temp = Temp::new();
+	let artifact = temp::Artifact::from(temp::directory! {
+		".gitignore" => temp::file!(indoc!("
+			# Logs.
+			*.log
+
+			!keep.log
+		")),
+		"a.log" => temp::file!(""),
+		"keep.log" => temp::file!(""),
+		"file" => temp::file!(""),
+		".DS_Store" => temp::file!(""),
+	});
+	artifact.to_path(temp.path()).await.unwrap();
+	let file_names = vec![".gitignore".into()];
+	let global = indoc!(
+		"
+			.DS_Store
+		"
+	);
+	let matcher = Matcher::new(file_names, Some(global), false).await.unwrap();
+	let path = temp.path().join(".gitignore");
+	let left = matcher
+		.explain(&temp.path().join("a.log"), None)
+		.await
+		.unwrap();
+	let right = Some(Match {
+		path: Some(path.clone()),
+		line: 2,
+		pattern: "*.log".to_owned(),
+		negated: false,
+	});
+	assert_eq!(left, right);
+	let left = matcher
+		.explain(&temp.path().join("keep.log"), None)
+		.await
+		.unwrap();
+	let right = Some(Match {
+		path: Some(path.clone()),
+		line: 4,
+		pattern: "!keep.log".to_owned(),
+		negated: true,
+	});
+	assert_eq!(left, right);
+	let left = matcher
+		.explain(&temp.path().join(".DS_Store"), None)
+		.await
+		.unwrap();
+	let right = Some(Match {
+		path: None,
+		line: 1,
+		pattern: ".DS_Store".to_owned(),
+		negated: false,
+	});
+	assert_eq!(left, right);
+	let left = matcher
+		.explain(&temp.path().join("file"), None)
+		.await
+		.unwrap();
+	assert_eq!(left, None);
+}
+
+#[tokio::test]
+async fn invalidate() {
+	let temp = Temp::new();
+	let artifact = temp::Artifact::from(temp::directory! {
+		".gitignore" => temp::file!(indoc!("
+			a
+		")),
+		"a" => temp::file!(""),
+		"b" => temp::file!(""),
+	});
+	artifact.to_path(temp.path()).await.unwrap();
+	let file_names = vec![".gitignore".into()];
+	let matcher = Matcher::new(file_names, None, false).await.unwrap();
+	let a = temp.path().join("a");
+	let b = temp.path().join("b");
+	assert!(matcher.matches(&a, None).await.unwrap());
+	assert!(!matcher.matches(&b, None).await.unwrap());
+
+	// Edit the ignore file. The matcher uses the cached file until it is invalidated.
+	tokio::fs::write(temp.path().join(".gitignore"), "b\n")
+		.await
+		.unwrap();
+	assert!(matcher.matches(&a, None).await.unwrap());
+	matcher.invalidate(temp.path()).await.unwrap();
+	assert!(!matcher.matches(&a, None).await.unwrap());
+	assert!(matcher.matches(&b, None).await.unwrap());
+}
@@ -1,5 +1,6 @@
 use crate::Message;
 use bytes::Bytes;
+use dashmap::DashMap;
 use futures::{future, Future, FutureExt as _, Stream, StreamExt as _};
 use std::{convert::Infallible, sync::Arc};
 
@@ -8,6 +9,13 @@ pub struct Messenger(Arc<Inner>);
 pub struct Inner {
 	sender: async_broadcast::Sender<Message>,
 	receiver: async_broadcast::InactiveReceiver<Message>,
+	groups: DashMap<(String, String), Group, fnv::FnvBuildHasher>,
+}
+
+/// A queue group. Each message published to the group's subject is delivered to exactly one of its members.
+struct Group {
+	next: usize,
+	senders: Vec<async_channel::Sender<Message>>,
 }
 
 impl Messenger {
@@ -16,22 +24,62 @@ impl Messenger {
 		let (mut sender, receiver) = async_broadcast::broadcast(1_000_000);
 		let receiver = receiver.deactivate();
 		sender.set_overflow(true);
-		Self(Arc::new(Inner { sender, receiver }))
+		let groups = DashMap::default();
+		Self(Arc::new(Inner {
+			sender,
+			receiver,
+			groups,
+		}))
 	}
 
 	async fn publish(&self, subject: String, payload: Bytes) {
 		let message = Message { subject, payload };
-		self.sender.try_broadcast(message.clone()).ok();
+
+		// Send the message to one member of each group subscribed to the subject.
+		for mut entry in self.groups.iter_mut() {
+			if entry.key().0 != message.subject {
+				continue;
+			}
+			let group = entry.value_mut();
+			group.senders.retain(|sender| !sender.is_closed());
+			if group.senders.is_empty() {
+				continue;
+			}
+			let index = group.next % group.senders.len();
+			group.next = group.next.wrapping_add(1);
+			group.senders[index].try_send(message.clone()).ok();
+		}
+
+		// Send the message to every subscriber without a group.
+		self.sender.try_broadcast(message).ok();
 	}
 
 	async fn subscribe(
 		&self,
 		subject: String,
-		_group: Option<String>,
+		group: Option<String>,
 	) -> impl Stream<Item = Message> + Send + 'static {
-		self.receiver
-			.activate_cloned()
-			.filter(move |message| future::ready(message.subject == subject))
+		// If there is no group, then receive every message published to the subject.
+		let Some(group) = group else {
+			return self
+				.receiver
+				.activate_cloned()
+				.filter(move |message| future::ready(message.subject == subject))
+				.left_stream();
+		};
+
+		// Otherwise, join the group.
+		let (sender, receiver) = async_channel::unbounded();
+		self.groups
+			.entry((subject, group))
+			.or_insert_with(|| Group {
+				next: 0,
+				senders: Vec::new(),
+			})
+			.senders
+			.push(sender);
+
+		receiver.right_stream()
 	}
 }
 
@@ -68,3 +116,58 @@ impl std::ops::Deref for Messenger {
 		&self.0
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::Messenger;
+	use futures::{FutureExt as _, Stream, StreamExt as _};
+
+	fn drain(stream: &mut (impl Stream<Item = crate::Message> + Unpin)) -> Vec<String> {
+		let mut payloads = Vec::new();
+		while let Some(Some(message)) = stream.next().now_or_never() {
+			payloads.push(String::from_utf8(message.payload.to_vec()).unwrap());
+		}
+		payloads
+	}
+
+	#[test]
+	fn queue_groups() {
+		futures::executor::block_on(async {
+			let messenger = Messenger::new();
+
+			// Subscribe two members of a group and one subscriber without a group.
+			let mut a = Box::pin(
+				messenger
+					.subscribe("subject".to_owned(), Some("group".to_owned()))
+					.await,
+			);
+			let mut b = Box::pin(
+				messenger
+					.subscribe("subject".to_owned(), Some("group".to_owned()))
+					.await,
+			);
+			let mut c = Box::pin(messenger.subscribe("subject".to_owned(), None).await);
+
+			// Publish the messages.
+			let expected = (0..10).map(|i| i.to_string()).collect::<Vec<_>>();
+			for payload in &expected {
+				messenger
+					.publish("subject".to_owned(), payload.clone().into())
+					.await;
+			}
+
+			// Verify that each message was delivered to exactly one member of the group.
+			let a = drain(&mut a);
+			let b = drain(&mut b);
+			assert!(!a.is_empty());
+			assert!(!b.is_empty());
+			assert!(a.iter().all(|payload| !b.contains(payload)));
+			let mut actual = a.into_iter().chain(b).collect::<Vec<_>>();
+			actual.sort_by_key(|payload| payload.parse::<u32>().unwrap());
+			assert_eq!(actual, expected);
+
+			// Verify that the subscriber without a group received every message.
+			assert_eq!(drain(&mut c), expected);
+		});
+	}
+}
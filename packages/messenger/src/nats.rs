@@ -12,6 +12,14 @@ impl Messenger {
 	pub fn new(client: nats::Client) -> Self {
 		Self { client }
 	}
+
+	#[must_use]
+	pub fn is_connected(&self) -> bool {
+		matches!(
+			self.client.connection_state(),
+			nats::connection::State::Connected
+		)
+	}
 }
 
 impl crate::Messenger for Messenger {
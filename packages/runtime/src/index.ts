@@ -15,6 +15,7 @@ import type { Module } from "./module.ts";
 import { Mutation, mutation } from "./mutation.ts";
 import { Object as Object_ } from "./object.ts";
 import { path } from "./path.ts";
+import { progress } from "./progress.ts";
 import type { Reference } from "./reference.ts";
 import type { Referent } from "./referent.ts";
 import type { Resolved, Unresolved } from "./resolve.ts";
@@ -81,6 +82,7 @@ export {
 	log,
 	mutation,
 	path,
+	progress,
 	resolve,
 	sleep,
 	start,
@@ -0,0 +1,3 @@
+export let progress = async (value: unknown) => {
+	return await syscall("build_progress", value);
+};
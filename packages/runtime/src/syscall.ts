@@ -8,6 +8,8 @@ declare global {
 		target: tg.Target,
 	): Promise<tg.Value>;
 
+	function syscall(syscall: "build_progress", value: unknown): Promise<void>;
+
 	function syscall(
 		syscall: "checksum",
 		input: string | Uint8Array,
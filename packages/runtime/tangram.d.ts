@@ -926,6 +926,9 @@ declare namespace tg {
 			| "target";
 	}
 
+	/** Report a progress value for the current build. The value must be serializable to JSON. Progress values are not stored. They are only delivered to clients that are following the build's progress when the value is reported. */
+	export let progress: (value: unknown) => Promise<void>;
+
 	export type Reference = string;
 
 	export type Referent<T> = {
@@ -1,3 +1,4 @@
+mod archive;
 pub(crate) mod cache;
 mod checkin;
 mod checkout;
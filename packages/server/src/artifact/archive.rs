@@ -0,0 +1,525 @@
+use crate::Server;
+use futures::{StreamExt as _, TryStreamExt as _};
+use num::ToPrimitive as _;
+use std::{
+	collections::{BTreeMap, HashMap},
+	path::PathBuf,
+	pin::pin,
+};
+use tangram_client as tg;
+use tangram_either::Either;
+use tangram_http::{incoming::request::Ext as _, outgoing::response::Ext as _, Incoming, Outgoing};
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek};
+use tokio_util::compat::TokioAsyncReadCompatExt as _;
+
+impl Server {
+	pub async fn list_archive_entries(
+		&self,
+		blob: &tg::blob::Id,
+		arg: tg::artifact::archive::list::Arg,
+	) -> tg::Result<tg::artifact::archive::list::Output> {
+		// Create the reader.
+		let blob = tg::Blob::with_id(blob.clone());
+		let reader = crate::blob::Reader::new(self, blob).await?;
+
+		// List the entries.
+		let entries = match arg.format {
+			tg::artifact::archive::Format::Tar => tar(reader).await?,
+			tg::artifact::archive::Format::Tgar => tgar(self, reader).await?,
+			tg::artifact::archive::Format::Zip => zip(reader).await?,
+		};
+
+		Ok(tg::artifact::archive::list::Output { entries })
+	}
+}
+
+impl Server {
+	pub(crate) async fn handle_list_archive_entries_request<H>(
+		handle: &H,
+		request: http::Request<Incoming>,
+		blob: &str,
+	) -> tg::Result<http::Response<Outgoing>>
+	where
+		H: tg::Handle,
+	{
+		let blob = blob.parse()?;
+		let arg = request
+			.query_params()
+			.transpose()?
+			.ok_or_else(|| tg::error!("query parameters required"))?;
+		let output = handle.list_archive_entries(&blob, arg).await?;
+		let response = http::Response::builder().json(output).unwrap();
+		Ok(response)
+	}
+}
+
+/// List the entries of a tar archive by reading each header and skipping the entry's content.
+async fn tar<R>(reader: R) -> tg::Result<Vec<tg::artifact::archive::list::Entry>>
+where
+	R: AsyncRead + Unpin + Send + 'static,
+{
+	let reader = async_tar::Archive::new(reader.compat());
+	let mut entries = Vec::new();
+	let mut iter = reader
+		.entries()
+		.map_err(|source| tg::error!(!source, "failed to get the entries from the archive"))?;
+	while let Some(entry) = iter.next().await {
+		let entry = entry
+			.map_err(|source| tg::error!(!source, "failed to get the entry from the archive"))?;
+		let header = entry.header();
+		let kind = match header.entry_type() {
+			async_tar::EntryType::Directory => tg::artifact::Kind::Directory,
+			async_tar::EntryType::Symlink => tg::artifact::Kind::Symlink,
+			async_tar::EntryType::XGlobalHeader
+			| async_tar::EntryType::XHeader
+			| async_tar::EntryType::GNULongName
+			| async_tar::EntryType::GNULongLink => {
+				continue;
+			},
+			_ => tg::artifact::Kind::File,
+		};
+		let path = PathBuf::from(
+			entry
+				.path()
+				.map_err(|source| tg::error!(!source, "failed to get the entry path"))?
+				.as_ref(),
+		);
+		let size = header
+			.size()
+			.map_err(|source| tg::error!(!source, "failed to get the entry size"))?;
+		entries.push(tg::artifact::archive::list::Entry { kind, path, size });
+	}
+	Ok(entries)
+}
+
+/// List the entries of a tgar archive by reading its objects as a stream and walking the directory at its root. A leaf's bytes are dropped as soon as they are read, and only its size is kept. A file's size is computed from its blob's leaves and branches.
+async fn tgar<R>(server: &Server, reader: R) -> tg::Result<Vec<tg::artifact::archive::list::Entry>>
+where
+	R: AsyncRead + Unpin + Send + 'static,
+{
+	// Read the objects.
+	let stream = server.extract_object(reader).await?;
+	let mut stream = pin!(stream);
+	let mut root = None;
+	let mut objects = HashMap::new();
+	while let Some((id, bytes)) = stream.try_next().await? {
+		root.get_or_insert_with(|| id.clone());
+		let object = if id.kind() == tg::object::Kind::Leaf {
+			TgarObject::Leaf(bytes.len().to_u64().unwrap())
+		} else {
+			TgarObject::Data(tg::object::Data::deserialize(id.kind(), &bytes)?)
+		};
+		objects.insert(id, object);
+	}
+	let root = root.ok_or_else(|| tg::error!("the archive is empty"))?;
+	let root = tg::artifact::Id::try_from(root)
+		.map_err(|source| tg::error!(!source, "the archive's root is not an artifact"))?;
+	let archive = Tgar { objects };
+
+	// Walk the directory.
+	let TgarNode::Directory(entries) = archive.node(&TgarArtifact::Id(root))? else {
+		return Err(tg::error!("the archive's root is not a directory"));
+	};
+	let mut stack = entries
+		.into_iter()
+		.rev()
+		.map(|(name, artifact)| (PathBuf::from(name), artifact))
+		.collect::<Vec<_>>();
+	let mut entries = Vec::new();
+	while let Some((path, artifact)) = stack.pop() {
+		match archive.node(&artifact)? {
+			TgarNode::Directory(children) => {
+				stack.extend(
+					children
+						.into_iter()
+						.rev()
+						.map(|(name, artifact)| (path.join(name), artifact)),
+				);
+				let kind = tg::artifact::Kind::Directory;
+				entries.push(tg::artifact::archive::list::Entry {
+					kind,
+					path,
+					size: 0,
+				});
+			},
+			TgarNode::File(contents) => {
+				let kind = tg::artifact::Kind::File;
+				let size = archive.blob_size(&contents)?;
+				entries.push(tg::artifact::archive::list::Entry { kind, path, size });
+			},
+			TgarNode::Symlink => {
+				let kind = tg::artifact::Kind::Symlink;
+				entries.push(tg::artifact::archive::list::Entry {
+					kind,
+					path,
+					size: 0,
+				});
+			},
+		}
+	}
+
+	Ok(entries)
+}
+
+/// The objects of a tgar archive.
+struct Tgar {
+	objects: HashMap<tg::object::Id, TgarObject>,
+}
+
+/// An object in a tgar archive. Leaves are stored by their size.
+enum TgarObject {
+	Leaf(u64),
+	Data(tg::object::Data),
+}
+
+/// An artifact in a tgar archive, either by its ID or as a node of a graph.
+enum TgarArtifact {
+	Id(tg::artifact::Id),
+	Node(tg::graph::Id, usize),
+}
+
+enum TgarNode {
+	Directory(BTreeMap<String, TgarArtifact>),
+	File(tg::blob::Id),
+	Symlink,
+}
+
+impl Tgar {
+	fn get(&self, id: &tg::object::Id) -> tg::Result<&TgarObject> {
+		self.objects
+			.get(id)
+			.ok_or_else(|| tg::error!(%id, "the archive does not contain the object"))
+	}
+
+	fn get_data(&self, id: &tg::object::Id) -> tg::Result<&tg::object::Data> {
+		match self.get(id)? {
+			TgarObject::Data(data) => Ok(data),
+			TgarObject::Leaf(_) => Err(tg::error!(%id, "expected an artifact or a graph")),
+		}
+	}
+
+	fn node(&self, artifact: &TgarArtifact) -> tg::Result<TgarNode> {
+		let (graph, node) = match artifact {
+			TgarArtifact::Id(id) => match self.get_data(&id.clone().into())? {
+				tg::object::Data::Directory(tg::directory::Data::Graph { graph, node })
+				| tg::object::Data::File(tg::file::Data::Graph { graph, node })
+				| tg::object::Data::Symlink(tg::symlink::Data::Graph { graph, node }) => (graph.clone(), *node),
+				tg::object::Data::Directory(tg::directory::Data::Normal { entries }) => {
+					let entries = entries
+						.iter()
+						.map(|(name, id)| (name.clone(), TgarArtifact::Id(id.clone())))
+						.collect();
+					return Ok(TgarNode::Directory(entries));
+				},
+				tg::object::Data::File(tg::file::Data::Normal { contents, .. }) => {
+					return Ok(TgarNode::File(contents.clone()));
+				},
+				tg::object::Data::Symlink(_) => {
+					return Ok(TgarNode::Symlink);
+				},
+				_ => {
+					return Err(tg::error!(%id, "expected an artifact"));
+				},
+			},
+			TgarArtifact::Node(graph, node) => (graph.clone(), *node),
+		};
+		let tg::object::Data::Graph(data) = self.get_data(&tg::object::Id::Graph(graph.clone()))?
+		else {
+			return Err(tg::error!(%graph, "expected a graph"));
+		};
+		let data = data
+			.nodes
+			.get(node)
+			.ok_or_else(|| tg::error!(%graph, %node, "invalid graph node"))?;
+		match data {
+			tg::graph::data::Node::Directory(directory) => {
+				let entries = directory
+					.entries
+					.iter()
+					.map(|(name, either)| {
+						let artifact = match either {
+							Either::Left(node) => TgarArtifact::Node(graph.clone(), *node),
+							Either::Right(id) => TgarArtifact::Id(id.clone()),
+						};
+						(name.clone(), artifact)
+					})
+					.collect();
+				Ok(TgarNode::Directory(entries))
+			},
+			tg::graph::data::Node::File(file) => Ok(TgarNode::File(file.contents.clone())),
+			tg::graph::data::Node::Symlink(_) => Ok(TgarNode::Symlink),
+		}
+	}
+
+	fn blob_size(&self, id: &tg::blob::Id) -> tg::Result<u64> {
+		match self.get(&id.clone().into())? {
+			TgarObject::Leaf(size) => Ok(*size),
+			TgarObject::Data(tg::object::Data::Branch(branch)) => {
+				Ok(branch.children.iter().map(|child| child.size).sum())
+			},
+			_ => Err(tg::error!(%id, "expected a blob")),
+		}
+	}
+}
+
+/// List the entries of a zip archive from its central directory.
+async fn zip<R>(reader: R) -> tg::Result<Vec<tg::artifact::archive::list::Entry>>
+where
+	R: AsyncBufRead + AsyncSeek + Unpin + Send + 'static,
+{
+	let reader = async_zip::base::read::seek::ZipFileReader::new(reader.compat())
+		.await
+		.map_err(|source| tg::error!(!source, "failed to create the zip reader"))?;
+	let mut entries = Vec::new();
+	for entry in reader.file().entries() {
+		// Directory entries' filenames end with a slash, which is removed so that the paths match the other formats.
+		let path = PathBuf::from(
+			entry
+				.filename()
+				.as_str()
+				.map_err(|source| tg::error!(!source, "failed to get the entry filename"))?
+				.trim_end_matches('/'),
+		);
+		let is_dir = entry
+			.dir()
+			.map_err(|source| tg::error!(!source, "failed to get type of entry"))?;
+		let is_symlink = entry
+			.unix_permissions()
+			.is_some_and(|permissions| permissions & 0o120_000 == 0o120_000);
+		let kind = if is_dir {
+			tg::artifact::Kind::Directory
+		} else if is_symlink {
+			tg::artifact::Kind::Symlink
+		} else {
+			tg::artifact::Kind::File
+		};
+		let size = entry.uncompressed_size();
+		entries.push(tg::artifact::archive::list::Entry { kind, path, size });
+	}
+	Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{util::fs::cleanup, Config, Server};
+	use futures::FutureExt as _;
+	use insta::assert_json_snapshot;
+	use std::{collections::BTreeMap, panic::AssertUnwindSafe};
+	use tangram_client as tg;
+	use tangram_temp::Temp;
+
+	#[tokio::test]
+	async fn list_tar_entries() -> tg::Result<()> {
+		let temp = Temp::new();
+		let config = Config::with_path(temp.path().to_owned());
+		let server = Server::start(config).await?;
+		let result = AssertUnwindSafe(async {
+			// Create the archive.
+			let mut builder = async_tar::Builder::new(Vec::new());
+			let mut header = async_tar::Header::new_gnu();
+			header.set_size(0);
+			header.set_entry_type(async_tar::EntryType::Directory);
+			header.set_mode(0o755);
+			builder
+				.append_data(&mut header, "directory", &[][..])
+				.await
+				.unwrap();
+			let mut header = async_tar::Header::new_gnu();
+			header.set_size(0);
+			header.set_entry_type(async_tar::EntryType::Directory);
+			header.set_mode(0o755);
+			builder
+				.append_data(&mut header, "directory/nested", &[][..])
+				.await
+				.unwrap();
+			let contents = b"Hello, World!";
+			let mut header = async_tar::Header::new_gnu();
+			header.set_size(contents.len().try_into().unwrap());
+			header.set_entry_type(async_tar::EntryType::Regular);
+			header.set_mode(0o644);
+			builder
+				.append_data(&mut header, "directory/nested/hello.txt", &contents[..])
+				.await
+				.unwrap();
+			let mut header = async_tar::Header::new_gnu();
+			header.set_size(0);
+			header.set_entry_type(async_tar::EntryType::Symlink);
+			header.set_mode(0o777);
+			header.set_link_name("nested/hello.txt").unwrap();
+			builder
+				.append_data(&mut header, "directory/link", &[][..])
+				.await
+				.unwrap();
+			let bytes = builder.into_inner().await.unwrap();
+
+			// Create the blob.
+			let reader = std::io::Cursor::new(bytes);
+			let blob = server.create_blob_with_reader(reader).await?.blob;
+
+			// List the entries.
+			let arg = tg::artifact::archive::list::Arg {
+				format: tg::artifact::archive::Format::Tar,
+			};
+			let output = server.list_archive_entries(&blob, arg).await?;
+			assert_json_snapshot!(output, @r#"
+   {
+     "entries": [
+       {
+         "kind": "directory",
+         "path": "directory",
+         "size": 0
+       },
+       {
+         "kind": "directory",
+         "path": "directory/nested",
+         "size": 0
+       },
+       {
+         "kind": "file",
+         "path": "directory/nested/hello.txt",
+         "size": 13
+       },
+       {
+         "kind": "symlink",
+         "path": "directory/link",
+         "size": 0
+       }
+     ]
+   }
+   "#);
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+
+	#[tokio::test]
+	async fn list_zip_entries_round_trip() -> tg::Result<()> {
+		let temp = Temp::new();
+		let config = Config::with_path(temp.path().to_owned());
+		let server = Server::start(config).await?;
+		let result = AssertUnwindSafe(async {
+			// Archive the artifact.
+			let artifact = artifact();
+			let format = tg::artifact::archive::Format::Zip;
+			let blob = artifact.archive(&server, format).await?;
+
+			// List the entries.
+			let arg = tg::artifact::archive::list::Arg { format };
+			let output = server
+				.list_archive_entries(&blob.id(&server).await?, arg)
+				.await?;
+			assert_json_snapshot!(output, @r#"
+   {
+     "entries": [
+       {
+         "kind": "directory",
+         "path": "directory",
+         "size": 0
+       },
+       {
+         "kind": "symlink",
+         "path": "directory/link",
+         "size": 16
+       },
+       {
+         "kind": "directory",
+         "path": "directory/nested",
+         "size": 0
+       },
+       {
+         "kind": "file",
+         "path": "directory/nested/hello.txt",
+         "size": 13
+       }
+     ]
+   }
+   "#);
+
+			// Extract the archive and verify that it is the same artifact.
+			let extracted = tg::Artifact::extract(&server, &blob, None).await?;
+			assert_eq!(extracted.id(&server).await?, artifact.id(&server).await?);
+
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+
+	#[tokio::test]
+	async fn list_tgar_entries_round_trip() -> tg::Result<()> {
+		let temp = Temp::new();
+		let config = Config::with_path(temp.path().to_owned());
+		let server = Server::start(config).await?;
+		let result = AssertUnwindSafe(async {
+			// Archive the artifact.
+			let artifact = artifact();
+			let format = tg::artifact::archive::Format::Tgar;
+			let blob = artifact.archive(&server, format).await?;
+
+			// List the entries.
+			let arg = tg::artifact::archive::list::Arg { format };
+			let output = server
+				.list_archive_entries(&blob.id(&server).await?, arg)
+				.await?;
+			assert_json_snapshot!(output, @r#"
+   {
+     "entries": [
+       {
+         "kind": "directory",
+         "path": "directory",
+         "size": 0
+       },
+       {
+         "kind": "symlink",
+         "path": "directory/link",
+         "size": 0
+       },
+       {
+         "kind": "directory",
+         "path": "directory/nested",
+         "size": 0
+       },
+       {
+         "kind": "file",
+         "path": "directory/nested/hello.txt",
+         "size": 13
+       }
+     ]
+   }
+   "#);
+
+			// Extract the archive and verify that it is the same artifact.
+			let extracted = tg::Artifact::extract(&server, &blob, None).await?;
+			assert_eq!(extracted.id(&server).await?, artifact.id(&server).await?);
+
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+
+	fn artifact() -> tg::Artifact {
+		let file = tg::File::with_contents(tg::Blob::from("Hello, World!"));
+		let nested = tg::Directory::with_entries(BTreeMap::from([(
+			"hello.txt".to_owned(),
+			tg::Artifact::from(file),
+		)]));
+		let symlink = tg::Symlink::with_target("nested/hello.txt".into());
+		let directory = tg::Directory::with_entries(BTreeMap::from([
+			("link".to_owned(), tg::Artifact::from(symlink)),
+			("nested".to_owned(), tg::Artifact::from(nested)),
+		]));
+		let root = tg::Directory::with_entries(BTreeMap::from([(
+			"directory".to_owned(),
+			tg::Artifact::from(directory),
+		)]));
+		root.into()
+	}
+}
@@ -448,6 +448,55 @@ async fn directory_with_symlink_cycle() -> tg::Result<()> {
 	.await
 }
 
+/// Test that checking out an artifact without caching requires that it is already cached.
+#[tokio::test]
+async fn no_cache() -> tg::Result<()> {
+	let temp = Temp::new();
+	let config = Config::with_path(temp.path().to_owned());
+	let server = Server::start(config).await?;
+	let result = AssertUnwindSafe(async {
+		let artifact = tg::Artifact::from(tg::file!("Hello, World!"));
+		let artifact = artifact.id(&server).await?;
+		let check_out = |cache| {
+			let server = server.clone();
+			let artifact = artifact.clone();
+			async move {
+				let arg = tg::artifact::checkout::Arg {
+					cache,
+					dependencies: true,
+					force: false,
+					lockfile: false,
+					path: None,
+				};
+				let stream = server.check_out_artifact(&artifact, arg).await?;
+				pin!(stream)
+					.try_last()
+					.await?
+					.and_then(|event| event.try_unwrap_output().ok())
+					.ok_or_else(|| tg::error!("stream ended without output"))
+			}
+		};
+
+		// Checking out the artifact without caching fails because it is not cached.
+		assert!(check_out(false).await.is_err());
+
+		// Cache the artifact.
+		let cached = check_out(true).await?;
+
+		// Checking out the artifact without caching now succeeds with the same path.
+		let output = check_out(false).await?;
+		assert_eq!(output.path, cached.path);
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	server.stop();
+	server.wait().await;
+	temp.remove().await.ok();
+	result.unwrap()
+}
+
 async fn test<F, Fut>(artifact: impl Into<tg::Artifact>, assertions: F) -> tg::Result<()>
 where
 	F: FnOnce(Server, temp::Artifact) -> Fut,
@@ -458,6 +507,7 @@ where
 	let server = Server::start(config).await?;
 	let result = AssertUnwindSafe(async {
 		let arg = tg::artifact::checkout::Arg {
+			cache: true,
 			dependencies: true,
 			force: false,
 			lockfile: false,
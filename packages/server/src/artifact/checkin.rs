@@ -105,7 +105,11 @@ impl Server {
 
 		// Create the unification graph and get its root node.
 		let (unification_graph, root) = self
-			.create_unification_graph(&input_graph, arg.deterministic)
+			.create_unification_graph(
+				&input_graph,
+				arg.deterministic,
+				arg.strategy.unwrap_or_default(),
+			)
 			.await
 			.map_err(|source| tg::error!(!source, "failed to construct the object graph"))?;
 
@@ -121,6 +125,13 @@ impl Server {
 			.await
 			.map_err(|source| tg::error!(!source, "failed to create the output graph"))?;
 
+		// If this is a hash only checkin, then return the artifact without storing anything.
+		if arg.hash_only {
+			let artifact = output_graph.nodes[0].id.clone();
+			let output = tg::artifact::checkin::Output { artifact };
+			return Ok(output);
+		}
+
 		// Write the output to the database.
 		self.write_output_to_database(&output_graph)
 			.await
@@ -163,7 +174,8 @@ impl Server {
 				tangram.lock
 			"
 		);
-		Matcher::new(file_names, Some(global))
+		let case_insensitive = cfg!(any(target_os = "macos", target_os = "windows"));
+		Matcher::new(file_names, Some(global), case_insensitive)
 			.await
 			.map_err(|source| tg::error!(!source, "failed to create the ignore"))
 	}
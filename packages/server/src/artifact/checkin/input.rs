@@ -115,6 +115,18 @@ impl Server {
 				return Err(tg::error!(%path = absolute_path.display(), "invalid file type"));
 			}
 
+			// If symlinks are followed, then use the symlink's target in its place.
+			if metadata.is_symlink() {
+				if let Some(follow_symlinks) = arg.follow_symlinks {
+					let root = state.read().await.graph.root.clone();
+					let target = follow_symlink(&root, &absolute_path, follow_symlinks).await?;
+					return Box::pin(
+						self.create_input_graph_inner(None, &target, arg, state, progress),
+					)
+					.await;
+				}
+			}
+
 			// If this is a root module file, ensure the parent is collected.
 			if metadata.is_file() && tg::package::is_root_module_path(&absolute_path) {
 				let parent = absolute_path.parent().unwrap().to_owned();
@@ -894,6 +906,42 @@ impl State {
 	}
 }
 
+async fn follow_symlink(
+	root: &Path,
+	path: &Path,
+	follow_symlinks: tg::artifact::checkin::FollowSymlinks,
+) -> tg::Result<PathBuf> {
+	// Resolve the target, which fails if the symlinks form a cycle.
+	let target = tokio::fs::canonicalize(path).await.map_err(|source| {
+		if source.raw_os_error() == Some(libc::ELOOP) {
+			tg::error!(%path = path.display(), "the symlink is part of a cycle")
+		} else {
+			tg::error!(!source, %path = path.display(), "failed to resolve the symlink")
+		}
+	})?;
+
+	// A symlink to one of its ancestors would create a directory that contains itself.
+	if path.starts_with(&target) {
+		return Err(
+			tg::error!(%path = path.display(), %target = target.display(), "the symlink points to one of its ancestors"),
+		);
+	}
+
+	// Unless all symlinks are followed, the target must be within the root.
+	if follow_symlinks == tg::artifact::checkin::FollowSymlinks::Root {
+		let root = tokio::fs::canonicalize(root).await.map_err(
+			|source| tg::error!(!source, %path = root.display(), "failed to canonicalize the path"),
+		)?;
+		if !target.starts_with(&root) {
+			return Err(
+				tg::error!(%path = path.display(), %target = target.display(), "the symlink points outside of the path being checked in"),
+			);
+		}
+	}
+
+	Ok(target)
+}
+
 fn get_root_node(graph: &Graph, mut node: usize) -> usize {
 	loop {
 		let Some(parent) = graph.nodes[node].parent else {
@@ -102,9 +102,12 @@ where
 			path: temp.path().join(path),
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: true,
 			lockfile: true,
+			strategy: None,
 		};
 		let input = server.create_input_graph(arg, None).await?;
 		assertions(input)
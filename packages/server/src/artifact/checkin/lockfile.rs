@@ -290,6 +290,7 @@ impl Server {
 		}
 	}
 
+	/// Strip the nodes that do not need to be locked. The retained nodes are renumbered in the order of a depth first search from the root that visits directory entries and file dependencies in sorted order, so that identical graphs always produce identical lockfiles, regardless of the order in which their nodes were discovered.
 	#[allow(clippy::unused_self)]
 	pub fn strip_lockfile_nodes(
 		&self,
@@ -212,11 +212,13 @@ impl Server {
 				// Get the graph metadata.
 				let metadata = self.compute_graph_metadata(graph, &object_graph);
 
-				// Store the graph.
+				// Store the graph unless this is a hash only checkin.
 				let bytes = object_graph.serialize()?;
 				let id = tg::graph::Id::new(&bytes);
-				let arg = tg::object::put::Arg { bytes };
-				self.put_object(&id.clone().into(), arg).await?;
+				if !input.nodes[0].arg.hash_only {
+					let arg = tg::object::put::Arg { bytes };
+					self.put_object_trusted(&id.clone().into(), arg).await?;
+				}
 
 				graph_metadata.insert(id.clone(), metadata);
 
@@ -293,9 +295,10 @@ impl Server {
 	) -> tg::Result<tg::graph::data::Node> {
 		// Get the input metadata, or skip if the node is an object.
 		let input_index = graph.nodes[index].unify.object.clone().unwrap_left();
-		let (path, metadata) = (
+		let (path, metadata, hash_only) = (
 			input.nodes[input_index].arg.path.clone(),
 			input.nodes[input_index].metadata.clone(),
+			input.nodes[input_index].arg.hash_only,
 		);
 
 		let edges = graph.nodes[index]
@@ -342,7 +345,14 @@ impl Server {
 			tg::graph::data::Node::Directory(directory)
 		} else if metadata.is_file() {
 			let file = self
-				.create_graph_file_node_data(path.as_ref(), index, metadata, edges, file_metadata)
+				.create_graph_file_node_data(
+					path.as_ref(),
+					index,
+					metadata,
+					edges,
+					file_metadata,
+					hash_only,
+				)
 				.await?;
 			tg::graph::data::Node::File(file)
 		} else if metadata.is_symlink() {
@@ -527,6 +537,7 @@ impl Server {
 		metadata: std::fs::Metadata,
 		edges: Vec<RemappedEdge>,
 		file_metadata: &mut BTreeMap<usize, tg::object::Metadata>,
+		hash_only: bool,
 	) -> tg::Result<tg::graph::data::File> {
 		// Compute the dependencies, which will be shared in all cases.
 		let dependencies = edges
@@ -547,9 +558,12 @@ impl Server {
 		let tg::blob::create::Output {
 			blob,
 			metadata: blob_metadata,
-		} = self.create_blob_with_path(path).await.map_err(
-			|source| tg::error!(!source, %path = path.display(), "failed to create blob"),
-		)?;
+		} = if hash_only {
+			self.compute_blob_with_path(path).await
+		} else {
+			self.create_blob_with_path(path).await
+		}
+		.map_err(|source| tg::error!(!source, %path = path.display(), "failed to create blob"))?;
 		drop(permit);
 
 		// For files only, we need to keep track of the count, depth, and weight when reading the file.
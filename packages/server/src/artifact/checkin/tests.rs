@@ -1,3 +1,4 @@
+use super::unify::{Edge, Graph, Id, Node, Unresolved};
 use crate::{util::fs::cleanup, Config, Server};
 use futures::{Future, FutureExt as _};
 use indoc::indoc;
@@ -1433,9 +1434,12 @@ async fn tagged_package_survives_clean() -> tg::Result<()> {
 			path: temp.path().to_owned(),
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
+			strategy: None,
 		};
 		let artifact1 = tg::Artifact::check_in(&local1, arg).await?;
 		let lockfile1 = tg::Lockfile::try_read(&temp.path().join(tg::package::LOCKFILE_FILE_NAME))
@@ -1445,6 +1449,7 @@ async fn tagged_package_survives_clean() -> tg::Result<()> {
 		object.load_recursive(&local1).await?;
 		let value = tg::Value::from(artifact1.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -1484,9 +1489,12 @@ async fn tagged_package_survives_clean() -> tg::Result<()> {
 			path: temp.path().to_owned(),
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
+			strategy: None,
 		};
 		let artifact2 = tg::Artifact::check_in(&local2, arg).await?;
 		let lockfile2 = tg::Lockfile::try_read(&temp.path().join(tg::package::LOCKFILE_FILE_NAME))
@@ -1497,6 +1505,7 @@ async fn tagged_package_survives_clean() -> tg::Result<()> {
 		object.load_recursive(&local2).await?;
 		let value = tg::Value::from(artifact2.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -2169,6 +2178,341 @@ async fn diamond_dependency() -> tg::Result<()> {
 	result.unwrap()
 }
 
+#[tokio::test]
+async fn minimal_strategy() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		for version in ["1.0.0", "1.1.0", "1.2.0"] {
+			publish(
+				&server,
+				&format!("a/{version}"),
+				temp::directory! {
+					"tangram.ts" => format!("export default tg.target(() => \"a/{version}\");\n"),
+				},
+			)
+			.await?;
+		}
+		publish(
+			&server,
+			"b",
+			temp::directory! {
+				"tangram.ts" => indoc::indoc!(r#"
+					import a from "a/>=1.1";
+					export default tg.target(() => "b");
+				"#),
+			},
+		)
+		.await?;
+		publish(
+			&server,
+			"c",
+			temp::directory! {
+				"tangram.ts" => indoc::indoc!(r#"
+					import a from "a/>=1.1";
+					export default tg.target(() => "c");
+				"#),
+			},
+		)
+		.await?;
+
+		// Both dependents should select the lowest version that satisfies their constraints.
+		let artifact = temp::directory! {
+			"tangram.ts" => indoc::indoc!(r#"
+				import b from "b";
+				import c from "c";
+			"#),
+		};
+		let strategy = Some(tg::artifact::checkin::Strategy::Minimal);
+		let (_artifact, _metadata, _lockfile, output) =
+			checkin_with_strategy(&server, artifact, strategy).await?;
+		assert_eq!(output.matches(r#""tag": "a/1.1.0""#).count(), 2);
+		assert!(!output.contains("a/1.0.0"));
+		assert!(!output.contains("a/1.2.0"));
+
+		// The default strategy should select the newest version.
+		let artifact = temp::directory! {
+			"tangram.ts" => indoc::indoc!(r#"
+				import b from "b";
+				import c from "c";
+			"#),
+		};
+		let (_artifact, _metadata, _lockfile, output) = checkin(&server, artifact).await?;
+		assert_eq!(output.matches(r#""tag": "a/1.2.0""#).count(), 2);
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	cleanup(temp, server).await;
+	result.unwrap()
+}
+
+#[tokio::test]
+async fn lockfile_is_stable() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		publish(
+			&server,
+			"a/1.0.0",
+			temp::directory! {
+				"tangram.ts" => "export default tg.target(() => \"a\");\n",
+			},
+		)
+		.await?;
+		for name in ["b", "c"] {
+			publish(
+				&server,
+				name,
+				temp::directory! {
+					"tangram.ts" => format!("import a from \"a/^1\";\nexport default tg.target(() => \"{name}\");\n"),
+				},
+			)
+			.await?;
+		}
+
+		// Create the package.
+		let package = Temp::new();
+		let artifact: temp::Artifact = temp::directory! {
+			"tangram.ts" => indoc!(r#"
+				import c from "c";
+				import b from "b";
+				import * as d from "./d.tg.ts";
+			"#),
+			"d.tg.ts" => indoc!(r#"
+				import b from "b";
+			"#),
+		}
+		.into();
+		artifact
+			.to_path(package.path())
+			.await
+			.map_err(|source| tg::error!(!source, "failed to create the package"))?;
+		let arg = tg::artifact::checkin::Arg {
+			cache: false,
+			destructive: false,
+			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
+			ignore: true,
+			locked: false,
+			lockfile: true,
+			path: package.path().to_owned(),
+			strategy: None,
+		};
+		let lockfile_path = package.path().join(tg::package::LOCKFILE_FILE_NAME);
+
+		// Check in the package, then remove the lockfile and check it in again.
+		tg::Artifact::check_in(&server, arg.clone()).await?;
+		let lockfile1 = tokio::fs::read(&lockfile_path)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to read the lockfile"))?;
+		tokio::fs::remove_file(&lockfile_path)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to remove the lockfile"))?;
+		tg::Artifact::check_in(&server, arg).await?;
+		let lockfile2 = tokio::fs::read(&lockfile_path)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to read the lockfile"))?;
+
+		// The lockfiles must be identical.
+		assert_eq!(lockfile1, lockfile2);
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	cleanup(temp, server).await;
+	result.unwrap()
+}
+
+#[test]
+fn conflict_path() {
+	let reference = |s: &str| s.parse::<tg::Reference>().unwrap();
+	let edge = |referent: &Id| Edge {
+		kind: None,
+		path: None,
+		referent: referent.clone(),
+		subpath: None,
+	};
+	let root: Id = Either::Right(0);
+	let left: Id = Either::Left(reference("left"));
+	let middle: Id = Either::Left(reference("middle/*"));
+	let shared: Id = Either::Left(reference("shared/*"));
+	let mut graph = Graph::default();
+	graph.nodes.insert(
+		root.clone(),
+		Node {
+			edges: [(reference("left"), edge(&left))].into(),
+			errors: Vec::new(),
+			object: Either::Left(0),
+			tag: None,
+		},
+	);
+	graph.nodes.insert(
+		left.clone(),
+		Node {
+			edges: [(reference("middle/^1"), edge(&middle))].into(),
+			errors: Vec::new(),
+			object: Either::Left(1),
+			tag: Some("left".parse().unwrap()),
+		},
+	);
+	graph.nodes.insert(
+		middle.clone(),
+		Node {
+			edges: [(reference("shared/^1"), edge(&shared))].into(),
+			errors: Vec::new(),
+			object: Either::Left(2),
+			tag: Some("middle/1.2.0".parse().unwrap()),
+		},
+	);
+	let unresolved = Unresolved {
+		src: middle,
+		dst: shared,
+		reference: reference("shared/^1"),
+	};
+	let path = graph.path_to_edge(&root, &unresolved);
+	assert_eq!(path, "left -> middle/1.2.0 -> shared/^1");
+}
+
+#[tokio::test]
+async fn hash_only() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		let directory = Temp::new();
+		let artifact = temp::directory! {
+			"hello.txt" => "Hello, world!",
+			"link" => temp::symlink!("hello.txt"),
+			".tangramignore" => "ignored.txt",
+			"ignored.txt" => "ignored",
+		};
+		artifact.to_path(directory.as_ref()).await.map_err(
+			|source| tg::error!(!source, %path = directory.path().display(), "failed to write the artifact"),
+		)?;
+		let arg = tg::artifact::checkin::Arg {
+			cache: false,
+			destructive: false,
+			deterministic: true,
+			follow_symlinks: None,
+			hash_only: true,
+			ignore: true,
+			locked: false,
+			lockfile: false,
+			path: directory.path().to_owned(),
+			strategy: None,
+		};
+
+		// Compute the artifact's ID and verify that it was not stored.
+		let expected = tg::Artifact::check_in(&server, arg.clone())
+			.await?
+			.id(&server)
+			.await?;
+		let object = server.try_get_object(&expected.clone().into()).await?;
+		assert!(object.is_none());
+
+		// Check in the artifact and verify that the IDs match.
+		let arg = tg::artifact::checkin::Arg {
+			hash_only: false,
+			..arg
+		};
+		let actual = tg::Artifact::check_in(&server, arg)
+			.await?
+			.id(&server)
+			.await?;
+		assert_eq!(actual, expected);
+		let object = server.try_get_object(&actual.into()).await?;
+		assert!(object.is_some());
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	cleanup(temp, server).await;
+	result.unwrap()
+}
+
+#[tokio::test]
+async fn follow_symlinks() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		let directory = Temp::new();
+		let artifact = temp::directory! {
+			"inside" => temp::directory! {
+				"hello.txt" => "Hello, world!",
+				"link" => temp::symlink!("hello.txt"),
+				"subdirectory" => temp::directory! {
+					"sublink" => temp::symlink!("../link"),
+				},
+			},
+			"outside" => temp::directory! {
+				"link" => temp::symlink!("../hello.txt"),
+			},
+			"cycle" => temp::directory! {
+				"a" => temp::symlink!("b"),
+				"b" => temp::symlink!("a"),
+			},
+			"hello.txt" => "Hello, world!",
+		};
+		artifact.to_path(directory.as_ref()).await.map_err(
+			|source| tg::error!(!source, %path = directory.path().display(), "failed to write the artifact"),
+		)?;
+		let arg = |path: &str, follow_symlinks| tg::artifact::checkin::Arg {
+			cache: false,
+			destructive: false,
+			deterministic: false,
+			follow_symlinks: Some(follow_symlinks),
+			hash_only: false,
+			ignore: true,
+			locked: false,
+			lockfile: false,
+			path: directory.path().join(path),
+			strategy: None,
+		};
+
+		// Symlinks within the path are replaced by their targets.
+		let root = tg::artifact::checkin::FollowSymlinks::Root;
+		let inside = tg::Artifact::check_in(&server, arg("inside", root))
+			.await?
+			.try_unwrap_directory()
+			.unwrap();
+		let file = inside.get(&server, "hello.txt").await?.id(&server).await?;
+		let link = inside.get(&server, "link").await?;
+		assert!(link.is_file());
+		assert_eq!(link.id(&server).await?, file);
+		let sublink = inside.get(&server, "subdirectory/sublink").await?;
+		assert_eq!(sublink.id(&server).await?, file);
+
+		// Symlinks that point outside the path are only followed if all symlinks are followed.
+		let error = tg::Artifact::check_in(&server, arg("outside", root)).await;
+		assert!(error.is_err());
+		let all = tg::artifact::checkin::FollowSymlinks::All;
+		let outside = tg::Artifact::check_in(&server, arg("outside", all))
+			.await?
+			.try_unwrap_directory()
+			.unwrap();
+		let link = outside.get(&server, "link").await?;
+		assert_eq!(link.id(&server).await?, file);
+
+		// Symlink cycles are an error.
+		let error = tg::Artifact::check_in(&server, arg("cycle", all)).await;
+		assert!(error.is_err());
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	cleanup(temp, server).await;
+	result.unwrap()
+}
+
 async fn test<F, Fut>(
 	artifact: impl Into<temp::Artifact>,
 	path: &str,
@@ -2195,10 +2539,13 @@ where
 			cache: false,
 			destructive,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
 			path: path.clone(),
+			strategy: None,
 		};
 		let stream = server.check_in_artifact(arg).await?;
 		let output = pin!(stream)
@@ -2230,6 +2577,7 @@ where
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(artifact.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -2281,9 +2629,12 @@ where
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
+			strategy: None,
 		};
 		let artifact = tg::Artifact::check_in(&server, arg).await?;
 		let lockfile = tg::Lockfile::try_read(&temp.path().join(tg::package::LOCKFILE_FILE_NAME))
@@ -2296,6 +2647,7 @@ where
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(artifact.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -2335,6 +2687,19 @@ async fn checkin(
 	tg::object::Metadata,
 	Option<tg::Lockfile>,
 	String,
+)> {
+	checkin_with_strategy(server, artifact, None).await
+}
+
+async fn checkin_with_strategy(
+	server: &Server,
+	artifact: impl Into<temp::Artifact>,
+	strategy: Option<tg::artifact::checkin::Strategy>,
+) -> tg::Result<(
+	tg::Artifact,
+	tg::object::Metadata,
+	Option<tg::Lockfile>,
+	String,
 )> {
 	let temp = Temp::new();
 	let artifact: temp::Artifact = artifact.into();
@@ -2346,10 +2711,13 @@ async fn checkin(
 		cache: false,
 		destructive: false,
 		deterministic: false,
+		follow_symlinks: None,
+		hash_only: false,
 		ignore: true,
 		locked: false,
 		lockfile: true,
 		path: temp.path().to_owned(),
+		strategy,
 	};
 	let artifact = tg::Artifact::check_in(server, arg).await?;
 	let lockfile = tg::Lockfile::try_read(&temp.path().join(tg::package::LOCKFILE_FILE_NAME))
@@ -2363,6 +2731,7 @@ async fn checkin(
 	object.load_recursive(server).await?;
 	let value = tg::Value::from(artifact.clone());
 	let options = tg::value::print::Options {
+		preview_bytes: None,
 		recursive: true,
 		style: tg::value::print::Style::Pretty { indentation: "\t" },
 	};
@@ -1,7 +1,12 @@
 use super::input;
 use crate::Server;
+use futures::{future, stream::FuturesUnordered, StreamExt as _};
 use itertools::Itertools as _;
-use std::{collections::BTreeMap, path::PathBuf, sync::Arc};
+use std::{
+	collections::{BTreeMap, BTreeSet, VecDeque},
+	path::PathBuf,
+	sync::Arc,
+};
 use tangram_client as tg;
 use tangram_either::Either;
 
@@ -58,6 +63,9 @@ pub struct Edge {
 	pub subpath: Option<PathBuf>,
 }
 
+// The tagged objects that match each tag dependency's reference, from oldest to newest.
+type TaggedObjects = BTreeMap<tg::Reference, im::Vector<(tg::Tag, tg::Object)>>;
+
 #[derive(Clone, Debug)]
 struct State {
 	// The current graph.
@@ -81,6 +89,7 @@ impl Server {
 		&self,
 		input: &input::Graph,
 		deterministic: bool,
+		strategy: tg::artifact::checkin::Strategy,
 	) -> tg::Result<(Graph, Id)> {
 		let mut graph: Graph = Graph::default();
 		let mut visited_graph_nodes = BTreeMap::new();
@@ -92,7 +101,7 @@ impl Server {
 		// Unify.
 		if !deterministic {
 			graph = self
-				.unify_dependencies(graph.clone(), &root)
+				.unify_dependencies(graph.clone(), &root, strategy)
 				.await
 				.map_err(|source| tg::error!(!source, "failed to unify the object graph"))?;
 
@@ -336,7 +345,12 @@ fn get_reference_from_pattern(pattern: &tg::tag::Pattern) -> tg::Reference {
 }
 
 impl Server {
-	pub async fn unify_dependencies(&self, mut graph: Graph, root: &Id) -> tg::Result<Graph> {
+	pub async fn unify_dependencies(
+		&self,
+		mut graph: Graph,
+		root: &Id,
+		strategy: tg::artifact::checkin::Strategy,
+	) -> tg::Result<Graph> {
 		// Get the overrides.
 		let mut overrides: BTreeMap<Id, BTreeMap<String, tg::Reference>> = BTreeMap::new();
 		let root_node = graph.nodes.get_mut(root).unwrap();
@@ -376,6 +390,11 @@ impl Server {
 			return Ok(graph);
 		};
 
+		// Prefetch the tagged objects for the tag dependencies that are already known.
+		let mut tagged_objects = TaggedObjects::new();
+		self.prefetch_tagged_objects(&graph, graph.nodes.keys(), &mut tagged_objects)
+			.await;
+
 		// Construct the initial state.
 		let objects = None;
 		let visited = im::HashSet::new();
@@ -392,8 +411,15 @@ impl Server {
 
 		// Walk the graph until we have no more edges to solve.
 		loop {
-			self.walk_edge(&mut checkpoints, &mut current, &overrides)
-				.await;
+			self.walk_edge(
+				&mut checkpoints,
+				&mut current,
+				root,
+				&overrides,
+				strategy,
+				&mut tagged_objects,
+			)
+			.await;
 
 			let Some(next) = current.queue.pop_front() else {
 				break;
@@ -410,7 +436,10 @@ impl Server {
 		&self,
 		state: &mut Vec<State>,
 		current: &mut State,
+		root: &Id,
 		overrides: &BTreeMap<Id, BTreeMap<String, tg::Reference>>,
+		strategy: tg::artifact::checkin::Strategy,
+		tagged_objects: &mut TaggedObjects,
 	) {
 		// Check if this edge has already been visited.
 		if current.visited.contains(&current.edge) {
@@ -435,10 +464,20 @@ impl Server {
 
 			// Attempt to resolve a dependency.
 			match self
-				.resolve_dependency(&mut current.graph, &reference, &mut current.objects)
+				.resolve_dependency(
+					&mut current.graph,
+					&reference,
+					&mut current.objects,
+					strategy,
+					tagged_objects,
+				)
 				.await
 			{
 				Ok(dst) => {
+					// Prefetch the tagged objects for the package's tag dependencies.
+					self.prefetch_tagged_objects(&current.graph, [&dst], tagged_objects)
+						.await;
+
 					// Add the direct dependencies to the queue.
 					let edges = current.graph.edges(dst.clone());
 					current.queue.extend(edges);
@@ -502,7 +541,13 @@ impl Server {
 					*current = old;
 				},
 				_ => {
-					let error = tg::error!(%reference, "package version conflict");
+					let path = current.graph.path_to_edge(root, &current.edge);
+					let error = tg::error!(
+						%reference,
+						%selected = tag,
+						%path,
+						"package version conflict"
+					);
 					current.graph.add_error(&current.edge.src, error);
 				},
 			},
@@ -514,37 +559,19 @@ impl Server {
 		graph: &mut Graph,
 		reference: &tg::Reference,
 		objects: &mut Option<im::Vector<(tg::Tag, tg::Object)>>,
+		strategy: tg::artifact::checkin::Strategy,
+		tagged_objects: &mut TaggedObjects,
 	) -> tg::Result<Id> {
 		// Seed the remaining packages if necessary.
 		if objects.is_none() {
-			// Get the tag pattern and remote if necessary.
-			let pattern = reference
-				.item()
-				.try_unwrap_tag_ref()
-				.map_err(|_| tg::error!(%reference, "expected a tag pattern"))?
-				.clone();
-			let remote = reference
-				.options()
-				.as_ref()
-				.and_then(|query| query.remote.clone());
-
-			// List tags that match the pattern.
-			let objects_: im::Vector<_> = self
-				.list_tags(tg::tag::list::Arg {
-					length: None,
-					pattern: pattern.clone(),
-					remote,
-					reverse: false,
-				})
-				.await
-				.map_err(|source| tg::error!(!source, %pattern, "failed to get tags"))?
-				.data
-				.into_iter()
-				.filter_map(|output| {
-					let object = output.item.right()?;
-					Some((output.tag, tg::Object::with_id(object)))
-				})
-				.collect();
+			// Get the tagged objects from the prefetched ones, or list them.
+			let objects_ = if let Some(objects_) = tagged_objects.get(reference) {
+				objects_.clone()
+			} else {
+				let objects_ = self.list_tagged_objects(reference).await?;
+				tagged_objects.insert(reference.clone(), objects_.clone());
+				objects_
+			};
 
 			if objects_.is_empty() {
 				return Err(tg::error!(%reference, "no tagged items match the reference"));
@@ -554,18 +581,87 @@ impl Server {
 			objects.replace(objects_);
 		}
 
-		// Pop the next version off the list.
-		let (tag, object) = objects
-			.as_mut()
-			.unwrap()
-			.pop_back()
+		// Take the next version to try. Prefer the newest remaining version, or the oldest if the strategy is minimal, and prefer releases over prereleases.
+		let objects = objects.as_mut().unwrap();
+		let tags = objects.iter().map(|(tag, _)| tag).collect::<Vec<_>>();
+		let index = strategy
+			.select_candidate(&tags)
 			.ok_or_else(|| tg::error!(%reference, "no solution exists"))?;
+		let (tag, object) = objects.remove(index);
 
 		let unify = true;
 		self.create_unification_node_from_tagged_object(graph, &object, Some(tag), unify)
 			.await
 	}
 
+	/// List the tagged objects that match a reference's tag pattern, from oldest to newest.
+	async fn list_tagged_objects(
+		&self,
+		reference: &tg::Reference,
+	) -> tg::Result<im::Vector<(tg::Tag, tg::Object)>> {
+		// Get the tag pattern and remote if necessary.
+		let pattern = reference
+			.item()
+			.try_unwrap_tag_ref()
+			.map_err(|_| tg::error!(%reference, "expected a tag pattern"))?
+			.clone();
+		let remote = reference
+			.options()
+			.as_ref()
+			.and_then(|query| query.remote.clone());
+
+		// List tags that match the pattern.
+		let objects = self
+			.list_tags(tg::tag::list::Arg {
+				length: None,
+				pattern: pattern.clone(),
+				remote,
+				reverse: false,
+				sort: Some(tg::tag::list::Sort::Semver),
+			})
+			.await
+			.map_err(|source| tg::error!(!source, %pattern, "failed to get tags"))?
+			.data
+			.into_iter()
+			.filter_map(|output| {
+				let object = output.item.right()?;
+				Some((output.tag, tg::Object::with_id(object)))
+			})
+			.collect();
+
+		Ok(objects)
+	}
+
+	/// Concurrently list the tagged objects for the unresolved tag dependencies of the nodes, so that solving does not wait on them one at a time. Path dependencies and references that have already been listed are not prefetched. If listing fails, the reference is left out, and the error is reported when the reference is resolved.
+	async fn prefetch_tagged_objects<'a>(
+		&self,
+		graph: &Graph,
+		nodes: impl IntoIterator<Item = &'a Id>,
+		tagged_objects: &mut TaggedObjects,
+	) {
+		let references = nodes
+			.into_iter()
+			.flat_map(|id| graph.nodes.get(id).unwrap().edges.iter())
+			.filter(|(reference, edge)| {
+				reference.item().try_unwrap_tag_ref().is_ok()
+					&& !graph.nodes.contains_key(&edge.referent)
+					&& !tagged_objects.contains_key(*reference)
+			})
+			.map(|(reference, _)| reference.clone())
+			.collect::<BTreeSet<_>>();
+		let objects = references
+			.into_iter()
+			.map(|reference| async move {
+				let objects = self.list_tagged_objects(&reference).await.ok()?;
+				Some((reference, objects))
+			})
+			.collect::<FuturesUnordered<_>>()
+			.filter_map(future::ready)
+			.collect::<Vec<_>>()
+			.await;
+		tagged_objects.extend(objects);
+	}
+
 	async fn create_unification_node_from_tagged_object(
 		&self,
 		graph: &mut Graph,
@@ -877,6 +973,44 @@ impl Graph {
 		Err(last_error.unwrap())
 	}
 
+	/// Render the chain of tagged packages from the root to an edge's source, followed by the edge's reference, such as `b/1.0.0 -> c/1.2.0 -> a/^2`.
+	pub fn path_to_edge(&self, root: &Id, edge: &Unresolved) -> String {
+		// Find the parent of each node with a breadth first search from the root.
+		let mut parents: BTreeMap<Id, Id> = BTreeMap::new();
+		let mut queue = VecDeque::from([root.clone()]);
+		while let Some(id) = queue.pop_front() {
+			if id == edge.src {
+				break;
+			}
+			let Some(node) = self.nodes.get(&id) else {
+				continue;
+			};
+			for child in node.edges.values() {
+				if &child.referent != root && !parents.contains_key(&child.referent) {
+					parents.insert(child.referent.clone(), id.clone());
+					queue.push_back(child.referent.clone());
+				}
+			}
+		}
+
+		// Follow the parents from the edge's source back to the root, keeping the tagged packages.
+		let mut path = Vec::new();
+		let mut id = &edge.src;
+		while id != root {
+			if let Some(tag) = self.nodes.get(id).and_then(|node| node.tag.as_ref()) {
+				path.push(tag.to_string());
+			}
+			let Some(parent) = parents.get(id) else {
+				break;
+			};
+			id = parent;
+		}
+		path.reverse();
+		path.push(edge.reference.to_string());
+
+		path.join(" -> ")
+	}
+
 	pub fn edges(&self, src: Id) -> impl Iterator<Item = Unresolved> + '_ {
 		self.nodes
 			.get(&src)
@@ -115,12 +115,21 @@ impl Server {
 	) -> tg::Result<tg::artifact::checkout::Output> {
 		match arg.path.clone() {
 			None => {
-				if !self.vfs.lock().unwrap().is_some() {
+				// If the VFS is enabled, then the artifact is read on demand. Otherwise, cache the artifact, or if caching is disabled, then require that it is already cached.
+				let path = self.artifacts_path().join(artifact.to_string());
+				let vfs = self.vfs.lock().unwrap().is_some();
+				if !vfs && arg.cache {
 					self.cache_artifact(artifact.clone(), progress)
 						.await
 						.map_err(|source| tg::error!(!source, "failed to cache the artifact"))?;
+				} else if !vfs {
+					let exists = tokio::fs::symlink_metadata(&path).await.is_ok();
+					if !exists {
+						return Err(
+							tg::error!(%artifact, "the artifact is not in the artifacts directory"),
+						);
+					}
 				}
-				let path = self.artifacts_path().join(artifact.to_string());
 				let output = tg::artifact::checkout::Output { path };
 				Ok(output)
 			},
@@ -165,10 +174,13 @@ impl Server {
 				cache: false,
 				destructive: false,
 				deterministic: true,
+				follow_symlinks: None,
+				hash_only: false,
 				ignore: true,
 				locked: true,
 				lockfile: false,
 				path: path.clone(),
+				strategy: None,
 			};
 			let option = tg::Artifact::check_in(self, arg).await.ok();
 
@@ -395,6 +395,54 @@ async fn directory_with_symlink_cycle() -> tg::Result<()> {
 	.await
 }
 
+/// Test that collapsing an artifact into a graph checks out identically.
+#[tokio::test]
+async fn graph_collapse() -> tg::Result<()> {
+	let graph = tg::Graph::with_object(tg::graph::Object {
+		nodes: vec![tg::graph::object::Node::File(tg::graph::object::File {
+			contents: "Hello from a graph!".into(),
+			dependencies: BTreeMap::new(),
+			executable: true,
+		})],
+	});
+	let artifact: tg::Artifact = tg::directory! {
+		"hello.txt" => "Hello, World!",
+		"directory" => tg::directory! {
+			"graph.sh" => tg::File::with_graph_and_node(graph, 0),
+			"link" => tg::symlink!(PathBuf::from("graph.sh")),
+		},
+	}
+	.into();
+	test(
+		artifact.clone(),
+		Options::default(),
+		|server, expected| async move {
+			let (graph, node) = tg::Graph::with_artifact(&server, &artifact).await?;
+			let collapsed = tg::Directory::with_graph_and_node(graph, node);
+			let temp = Temp::new();
+			let arg = tg::artifact::checkout::Arg {
+				cache: true,
+				dependencies: true,
+				force: false,
+				lockfile: true,
+				path: Some(temp.path().to_owned()),
+			};
+			let id = collapsed.id(&server).await?;
+			let stream = server.check_out_artifact(&id.into(), arg).await?;
+			pin!(stream)
+				.try_last()
+				.await?
+				.and_then(|event| event.try_unwrap_output().ok())
+				.ok_or_else(|| tg::error!("stream ended without output"))?;
+			let actual = temp::Artifact::with_path(temp.path()).await?;
+			temp.remove().await.ok();
+			assert_eq!(actual, expected);
+			Ok::<_, tg::Error>(())
+		},
+	)
+	.await
+}
+
 async fn test<F, Fut>(
 	artifact: impl Into<tg::Artifact>,
 	options: Options,
@@ -410,6 +458,7 @@ where
 	let result = AssertUnwindSafe(async {
 		let temp = Temp::new();
 		let arg = tg::artifact::checkout::Arg {
+			cache: true,
 			dependencies: options.dependencies.unwrap_or(true),
 			force: false,
 			lockfile: true,
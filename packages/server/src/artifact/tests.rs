@@ -396,6 +396,7 @@ async fn symlink_roundtrip() -> tg::Result<()> {
 		object.load_recursive(&server).await.unwrap();
 		let value = tg::Value::Object(object);
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -423,6 +424,7 @@ async fn symlink_roundtrip() -> tg::Result<()> {
 			.await
 			.map_err(|source| tg::error!(!source, "failed to create temp path"))?;
 		let arg = tg::artifact::checkout::Arg {
+			cache: true,
 			dependencies: false,
 			force: false,
 			lockfile: true,
@@ -449,11 +451,14 @@ async fn symlink_roundtrip() -> tg::Result<()> {
 		let arg = tg::artifact::checkin::Arg {
 			cache: false,
 			deterministic: true,
+			follow_symlinks: None,
+			hash_only: false,
 			destructive: false,
 			ignore: true,
 			locked: true,
 			lockfile: true,
 			path,
+			strategy: None,
 		};
 		let artifact = tg::Artifact::check_in(&server, arg)
 			.await?
@@ -464,6 +469,7 @@ async fn symlink_roundtrip() -> tg::Result<()> {
 		object.load_recursive(&server).await.unwrap();
 		let value = tg::Value::Object(object);
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -538,6 +544,7 @@ async fn file_with_transitive_dependencies() -> tg::Result<()> {
 		object.load_recursive(&server).await.unwrap();
 		let value = tg::Value::Object(object);
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -567,6 +574,7 @@ async fn file_with_transitive_dependencies() -> tg::Result<()> {
 		// Check out the file.
 		let temp = Temp::new();
 		let arg = tg::artifact::checkout::Arg {
+			cache: true,
 			dependencies: false,
 			force: false,
 			lockfile: true,
@@ -592,16 +600,20 @@ async fn file_with_transitive_dependencies() -> tg::Result<()> {
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: false,
 			locked: true,
 			lockfile: false,
 			path,
+			strategy: None,
 		};
 		let roundtrip = tg::Artifact::check_in(&server, arg).await?;
 		let object: tg::Object = roundtrip.clone().into();
 		object.load_recursive(&server).await.unwrap();
 		let value = tg::Value::Object(object);
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -677,10 +689,13 @@ async fn file_with_object_dependencies() -> tg::Result<()> {
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: false,
 			locked: false,
 			lockfile: true,
 			path: temp.path().to_owned(),
+			strategy: None,
 		};
 		let directory = tg::Artifact::check_in(&server1, arg).await?;
 
@@ -718,6 +733,7 @@ async fn file_with_object_dependencies() -> tg::Result<()> {
 		let temp = Temp::new();
   		let file = directory.unwrap_directory_ref().get(&server1, "tangram.ts").await?;
 		let arg = tg::artifact::checkout::Arg {
+			cache: true,
 			dependencies: false,
 			force: false,
 			lockfile: true,
@@ -744,10 +760,13 @@ async fn file_with_object_dependencies() -> tg::Result<()> {
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: false,
 			locked: false,
 			lockfile: true,
 			path: temp.path().to_owned(),
+			strategy: None,
 		};
 		let file2 = tg::Artifact::check_in(&server1, arg).await?;
 		assert_eq!(file2.id(&server1).await?, file.id(&server1).await?);
@@ -797,9 +816,12 @@ where
 			path: path.clone(),
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
+			strategy: None,
 		};
 		let artifact = tg::Artifact::check_in(&server1, arg).await?;
 		let checkin_lockfile = tg::Lockfile::try_read(&path.join(tg::package::LOCKFILE_FILE_NAME))
@@ -810,6 +832,7 @@ where
 		// Check the artifact out from the first server.
 		let temp = Temp::new();
 		let arg = tg::artifact::checkout::Arg {
+			cache: true,
 			dependencies: true,
 			path: Some(temp.path().to_owned()),
 			force: false,
@@ -839,9 +862,12 @@ where
 			path: path.clone(),
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
+			strategy: None,
 		};
 		let artifact = tg::Artifact::check_in(&server2, arg).await?;
 		let checkin_lockfile = tg::Lockfile::try_read(&path.join(tg::package::LOCKFILE_FILE_NAME))
@@ -852,6 +878,7 @@ where
 		// Check it out.
 		let temp = Temp::new();
 		let arg = tg::artifact::checkout::Arg {
+			cache: true,
 			dependencies: true,
 			path: Some(temp.path().to_owned()),
 			force: false,
@@ -890,6 +917,7 @@ impl TestArtifact {
 		object.load_recursive(&self.server).await.unwrap();
 		let value = tg::Value::Object(object);
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -27,7 +27,7 @@ pub struct InnerOutput {
 
 struct State<'a> {
 	entries: DashMap<tg::blob::Id, (u64, u64), fnv::FnvBuildHasher>,
-	transaction: Transaction<'a>,
+	transaction: Option<Transaction<'a>>,
 	write_to_blobs_table: bool,
 }
 
@@ -65,7 +65,7 @@ impl Server {
 		// Create the state.
 		let state = State {
 			entries: DashMap::default(),
-			transaction,
+			transaction: Some(transaction),
 			write_to_blobs_table: true,
 		};
 
@@ -81,6 +81,7 @@ impl Server {
 		// Commit the transaction.
 		state
 			.transaction
+			.unwrap()
 			.commit()
 			.await
 			.map_err(|source| tg::error!(!source, "failed to commit the transaction"))?;
@@ -133,7 +134,7 @@ impl Server {
 		// Create the state.
 		let state = State {
 			entries: DashMap::default(),
-			transaction,
+			transaction: Some(transaction),
 			write_to_blobs_table: false,
 		};
 
@@ -149,6 +150,7 @@ impl Server {
 		// Commit the transaction.
 		state
 			.transaction
+			.unwrap()
 			.commit()
 			.await
 			.map_err(|source| tg::error!(!source, "failed to commit the transaction"))?;
@@ -165,6 +167,43 @@ impl Server {
 		Ok(tg::blob::create::Output { blob, metadata })
 	}
 
+	pub(crate) async fn compute_blob_with_path(
+		&self,
+		path: &Path,
+	) -> tg::Result<tg::blob::create::Output> {
+		// Open the file.
+		let file = tokio::fs::File::open(path)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to open the file"))?;
+
+		// Create the state without a transaction so that nothing is written.
+		let state = State {
+			entries: DashMap::default(),
+			transaction: None,
+			write_to_blobs_table: false,
+		};
+
+		// Compute the blob.
+		let InnerOutput {
+			blob,
+			count,
+			depth,
+			weight,
+			..
+		} = self.create_blob_inner(&state, file).await?;
+
+		// Create the metadata.
+		let metadata = tg::object::Metadata {
+			complete: true,
+			count: Some(count),
+			weight: Some(weight),
+			depth: Some(depth),
+		};
+
+		// Create the output.
+		Ok(tg::blob::create::Output { blob, metadata })
+	}
+
 	async fn create_blob_inner(
 		&self,
 		state: &State<'_>,
@@ -210,11 +249,15 @@ impl Server {
 		};
 
 		// Write to the blobs table if necessary.
-		if state.write_to_blobs_table {
+		if let Some(transaction) = state
+			.transaction
+			.as_ref()
+			.filter(|_| state.write_to_blobs_table)
+		{
 			for entry in &state.entries {
 				let blob = entry.key();
 				let (position, length) = entry.value();
-				let p = state.transaction.p();
+				let p = transaction.p();
 				let statement = formatdoc!(
 					"
 						insert into blobs (id, entry, position, length)
@@ -223,8 +266,7 @@ impl Server {
 					"
 				);
 				let params = db::params![blob, &output.blob, position, length];
-				state
-					.transaction
+				transaction
 					.execute(statement, params)
 					.await
 					.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
@@ -240,21 +282,22 @@ impl Server {
 		let count = 1;
 		let depth = 1;
 		let weight = 0;
-		let p = state.transaction.p();
-		let statement = formatdoc!(
-			"
-				insert into objects (id, bytes, complete, count, depth, weight, touched_at)
-				values ({p}1, {p}2, {p}3, {p}4, {p}5, {p}6, {p}7)
-				on conflict (id) do update set touched_at = {p}7;
-			"
-		);
-		let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
-		let params = db::params![&blob, &bytes, 1, count, depth, weight, now];
-		state
-			.transaction
-			.execute(statement, params)
-			.await
-			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+		if let Some(transaction) = &state.transaction {
+			let p = transaction.p();
+			let statement = formatdoc!(
+				"
+					insert into objects (id, bytes, complete, count, depth, weight, touched_at)
+					values ({p}1, {p}2, {p}3, {p}4, {p}5, {p}6, {p}7)
+					on conflict (id) do update set touched_at = {p}7;
+				"
+			);
+			let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
+			let params = db::params![&blob, &bytes, 1, count, depth, weight, now];
+			transaction
+				.execute(statement, params)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+		}
 		Ok(InnerOutput {
 			blob,
 			count: 1,
@@ -280,22 +323,23 @@ impl Server {
 
 		let id = tg::leaf::Id::new(&data);
 
-		let p = state.transaction.p();
-		let data = (!state.write_to_blobs_table).then_some(&data);
-		let statement = formatdoc!(
-			"
-				insert into objects (id, bytes, complete, count, depth, weight, touched_at)
-				values ({p}1, {p}2, {p}3, {p}4, {p}5, {p}6, {p}7)
-				on conflict (id) do update set touched_at = {p}7;
-			"
-		);
-		let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
-		let params = db::params![&id, &data, 1, 1, 1, length, now];
-		state
-			.transaction
-			.execute(statement, params)
-			.await
-			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+		if let Some(transaction) = &state.transaction {
+			let p = transaction.p();
+			let data = (!state.write_to_blobs_table).then_some(&data);
+			let statement = formatdoc!(
+				"
+					insert into objects (id, bytes, complete, count, depth, weight, touched_at)
+					values ({p}1, {p}2, {p}3, {p}4, {p}5, {p}6, {p}7)
+					on conflict (id) do update set touched_at = {p}7;
+				"
+			);
+			let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
+			let params = db::params![&id, &data, 1, 1, 1, length, now];
+			transaction
+				.execute(statement, params)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+		}
 
 		state.entries.insert(id.clone().into(), (position, length));
 
@@ -340,21 +384,22 @@ impl Server {
 		let depth = depth + 1;
 		let weight = weight + bytes.len().to_u64().unwrap();
 
-		let p = state.transaction.p();
-		let statement = formatdoc!(
-			"
-				insert into objects (id, bytes, complete, count, depth, weight, touched_at)
-				values ({p}1, {p}2, {p}3, {p}4, {p}5, {p}6, {p}7)
-				on conflict (id) do update set touched_at = {p}7;
-			"
-		);
-		let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
-		let params = db::params![&id, &bytes, 1, count, depth, weight, now];
-		state
-			.transaction
-			.execute(statement, params)
-			.await
-			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+		if let Some(transaction) = &state.transaction {
+			let p = transaction.p();
+			let statement = formatdoc!(
+				"
+					insert into objects (id, bytes, complete, count, depth, weight, touched_at)
+					values ({p}1, {p}2, {p}3, {p}4, {p}5, {p}6, {p}7)
+					on conflict (id) do update set touched_at = {p}7;
+				"
+			);
+			let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
+			let params = db::params![&id, &bytes, 1, count, depth, weight, now];
+			transaction
+				.execute(statement, params)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+		}
 
 		let output = InnerOutput {
 			blob: id.into(),
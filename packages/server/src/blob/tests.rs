@@ -27,6 +27,7 @@ async fn create_from_file() -> tg::Result<()> {
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(object);
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -60,6 +61,7 @@ async fn create_from_file_in_cache() -> tg::Result<()> {
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(object);
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -11,6 +11,7 @@ mod heartbeat;
 mod index;
 mod log;
 mod outcome;
+mod progress;
 mod pull;
 mod push;
 mod put;
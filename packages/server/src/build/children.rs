@@ -4,7 +4,7 @@ use futures::{future, stream, FutureExt as _, Stream, StreamExt as _, TryStreamE
 use indoc::formatdoc;
 use itertools::Itertools as _;
 use num::ToPrimitive as _;
-use std::time::Duration;
+use std::{fmt::Write as _, time::Duration};
 use tangram_client::{self as tg, handle::Ext as _};
 use tangram_database::{self as db, prelude::*};
 use tangram_futures::task::Stop;
@@ -65,11 +65,24 @@ impl Server {
 		arg: tg::build::children::get::Arg,
 		sender: async_channel::Sender<tg::Result<tg::build::children::get::Event>>,
 	) -> tg::Result<()> {
+		// Parse the labels.
+		let labels = arg
+			.labels
+			.iter()
+			.flatten()
+			.map(|label| {
+				let (key, value) = label
+					.split_once('=')
+					.ok_or_else(|| tg::error!(%label, "invalid label"))?;
+				Ok::<_, tg::Error>((key.to_owned(), value.to_owned()))
+			})
+			.try_collect::<_, Vec<_>, _>()?;
+
 		// Get the position.
 		let position = match arg.position {
 			Some(std::io::SeekFrom::Start(seek)) => seek,
 			Some(std::io::SeekFrom::End(seek) | std::io::SeekFrom::Current(seek)) => self
-				.try_get_build_children_local_current_position(id)
+				.try_get_build_children_local_current_position(id, &labels)
 				.await?
 				.to_i64()
 				.unwrap()
@@ -131,7 +144,7 @@ impl Server {
 
 				// Read the chunk.
 				let chunk = self
-					.try_get_build_children_local_inner(id, position, size)
+					.try_get_build_children_local_inner(id, &labels, position, size)
 					.await?;
 
 				// If the chunk is empty, then break.
@@ -170,6 +183,7 @@ impl Server {
 	async fn try_get_build_children_local_current_position(
 		&self,
 		id: &tg::build::Id,
+		labels: &[(String, String)],
 	) -> tg::Result<u64> {
 		// Get a database connection.
 		let connection = self
@@ -180,14 +194,16 @@ impl Server {
 
 		// Get the position.
 		let p = connection.p();
+		let (filter, filter_params) = build_children_labels_filter(p, labels, 2);
 		let statement = formatdoc!(
 			"
 				select count(*)
 				from build_children
-				where build = {p}1;
+				where build = {p}1{filter};
 			"
 		);
-		let params = db::params![id];
+		let mut params = db::params![id];
+		params.extend(filter_params);
 		let position = connection
 			.query_one_value_into(statement, params)
 			.await
@@ -202,6 +218,7 @@ impl Server {
 	async fn try_get_build_children_local_inner(
 		&self,
 		id: &tg::build::Id,
+		labels: &[(String, String)],
 		position: u64,
 		length: u64,
 	) -> tg::Result<tg::build::children::get::Chunk> {
@@ -214,17 +231,19 @@ impl Server {
 
 		// Get the children.
 		let p = connection.p();
+		let (filter, filter_params) = build_children_labels_filter(p, labels, 4);
 		let statement = formatdoc!(
 			"
 				select child
 				from build_children
-				where build = {p}1
+				where build = {p}1{filter}
 				order by position
 				limit {p}2
 				offset {p}3;
 			"
 		);
-		let params = db::params![id, length, position,];
+		let mut params = db::params![id, length, position,];
+		params.extend(filter_params);
 		let children = connection
 			.query_all_value_into(statement, params)
 			.await
@@ -394,3 +413,23 @@ impl Server {
 		Ok(response)
 	}
 }
+
+fn build_children_labels_filter(
+	p: &str,
+	labels: &[(String, String)],
+	start: usize,
+) -> (String, Vec<db::Value>) {
+	let mut filter = String::new();
+	let mut params = Vec::new();
+	for (i, (key, value)) in labels.iter().enumerate() {
+		let key_index = start + 2 * i;
+		let value_index = key_index + 1;
+		write!(
+			filter,
+			" and child in (select build from build_labels where key = {p}{key_index} and value = {p}{value_index})"
+		)
+		.unwrap();
+		params.extend(db::params![key, value]);
+	}
+	(filter, params)
+}
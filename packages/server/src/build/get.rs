@@ -114,11 +114,40 @@ impl Server {
 			.query_optional_into::<Row>(statement, params)
 			.await
 			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
-		let output = row.map(|row| tg::build::get::Output {
+		let Some(row) = row else {
+			return Ok(None);
+		};
+
+		// Get the build's labels.
+		#[derive(serde::Deserialize)]
+		struct LabelRow {
+			key: String,
+			value: String,
+		}
+		let p = connection.p();
+		let statement = formatdoc!(
+			"
+				select key, value
+				from build_labels
+				where build = {p}1;
+			"
+		);
+		let params = db::params![id];
+		let labels = connection
+			.query_all_into::<LabelRow>(statement, params)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?
+			.into_iter()
+			.map(|row| (row.key, row.value))
+			.collect();
+
+		// Create the output.
+		let output = tg::build::get::Output {
 			id: row.id,
 			count: row.count,
 			depth: row.depth,
 			host: row.host,
+			labels,
 			log: row.log,
 			logs_count: row.logs_count,
 			logs_depth: row.logs_depth,
@@ -137,12 +166,12 @@ impl Server {
 			dequeued_at: row.dequeued_at,
 			started_at: row.started_at,
 			finished_at: row.finished_at,
-		});
+		};
 
 		// Drop the database connection.
 		drop(connection);
 
-		Ok(output)
+		Ok(Some(output))
 	}
 
 	async fn try_get_build_remote(
@@ -184,6 +213,7 @@ impl Server {
 						children,
 						depth: output.depth,
 						host: output.host.clone(),
+						labels: output.labels.clone(),
 						log: output.log.clone(),
 						outcome: output.outcome.clone(),
 						retry: output.retry,
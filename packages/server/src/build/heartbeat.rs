@@ -2,7 +2,6 @@ use crate::Server;
 use futures::{stream::FuturesUnordered, StreamExt as _};
 use hyper::body::Incoming;
 use num::ToPrimitive;
-use std::time::Duration;
 use tangram_client as tg;
 use tangram_database::{self as db, prelude::*};
 use tangram_http::{incoming::request::Ext as _, outgoing::response::Ext as _, Outgoing};
@@ -69,7 +68,7 @@ impl Server {
 	) {
 		loop {
 			let result = self
-				.build_monitor_heartbeat_task_inner(options.timeout, options.limit)
+				.build_monitor_heartbeat_task_inner(options)
 				.await
 				.inspect_err(|error| tracing::error!(%error, "failed to cancel builds"));
 			if matches!(result, Err(_) | Ok(0)) {
@@ -80,9 +79,21 @@ impl Server {
 
 	pub(crate) async fn build_monitor_heartbeat_task_inner(
 		&self,
-		timeout: Duration,
-		limit: usize,
+		options: &crate::config::BuildHeartbeatMonitor,
 	) -> tg::Result<u64> {
+		// Determine the timeout and the reason to cancel the builds with.
+		let (timeout, reason) = if let Some(max_missed_heartbeats) = options.max_missed_heartbeats {
+			let heartbeat_interval = self.config.build.as_ref().map_or_else(
+				|| crate::config::Build::default().heartbeat_interval,
+				|build| build.heartbeat_interval,
+			);
+			let timeout = heartbeat_interval * max_missed_heartbeats;
+			(timeout, "runner heartbeat timeout")
+		} else {
+			(options.timeout, "the build's heartbeat expired")
+		};
+		let limit = options.limit;
+
 		// Get a database connection.
 		let connection = self
 			.database
@@ -120,7 +131,7 @@ impl Server {
 				let arg = tg::build::finish::Arg {
 					outcome: tg::build::outcome::Data::Cancelation(
 						tg::build::outcome::data::Cancelation {
-							reason: Some("the build's heartbeat expired".to_owned()),
+							reason: Some(reason.to_owned()),
 						},
 					),
 					remote: None,
@@ -155,3 +166,90 @@ impl Server {
 		Ok(response)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{util::fs::cleanup, Config, Server};
+	use futures::FutureExt as _;
+	use indoc::formatdoc;
+	use std::{panic::AssertUnwindSafe, time::Duration};
+	use tangram_client as tg;
+	use tangram_database::{self as db, prelude::*};
+	use tangram_temp::Temp;
+	use time::format_description::well_known::Rfc3339;
+
+	#[tokio::test]
+	async fn cancels_builds_with_missed_heartbeats() -> tg::Result<()> {
+		let temp = Temp::new();
+		let options = Config::with_path(temp.path().to_owned());
+		let server = Server::start(options).await?;
+		let result = AssertUnwindSafe(async {
+			// Create and start two builds.
+			let mut builds = Vec::new();
+			for n in 0..2 {
+				let target = tg::target::Builder::new("host")
+					.args(vec![n.to_string().into()])
+					.build();
+				let target = target.id(&server).await?;
+				let arg = tg::target::build::Arg::default();
+				let output = server.build_target(&target, arg).await?;
+				let arg = tg::build::start::Arg { remote: None };
+				server.try_start_build(&output.build, arg).await?;
+				builds.push(output.build);
+			}
+
+			// Simulate a runner that died by moving the first build's heartbeat into the past.
+			let connection = server
+				.database
+				.write_connection()
+				.await
+				.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
+			let p = connection.p();
+			let statement = formatdoc!(
+				"
+					update builds
+					set heartbeat_at = {p}1
+					where id = {p}2;
+				"
+			);
+			let heartbeat_at = (time::OffsetDateTime::now_utc() - Duration::from_secs(60))
+				.format(&Rfc3339)
+				.unwrap();
+			let params = db::params![heartbeat_at, builds[0]];
+			connection
+				.execute(statement, params)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+			drop(connection);
+
+			// Run the monitor.
+			let options = crate::config::BuildHeartbeatMonitor {
+				max_missed_heartbeats: Some(3),
+				..Default::default()
+			};
+			let canceled = server.build_monitor_heartbeat_task_inner(&options).await?;
+			assert_eq!(canceled, 1);
+
+			// The first build was canceled.
+			let output = server.try_get_build_local(&builds[0]).await?.unwrap();
+			assert_eq!(output.status, tg::build::Status::Finished);
+			let Some(tg::build::outcome::Data::Cancelation(cancelation)) = output.outcome else {
+				panic!("expected the build to be canceled");
+			};
+			assert_eq!(
+				cancelation.reason.as_deref(),
+				Some("runner heartbeat timeout")
+			);
+
+			// The second build is still running.
+			let output = server.try_get_build_local(&builds[1]).await?.unwrap();
+			assert_eq!(output.status, tg::build::Status::Started);
+
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+}
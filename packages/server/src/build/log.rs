@@ -7,7 +7,7 @@ use futures::{
 use indoc::formatdoc;
 use itertools::Itertools as _;
 use num::ToPrimitive;
-use std::{io::Cursor, time::Duration};
+use std::{io::Cursor, pin::pin, time::Duration};
 use sync_wrapper::SyncWrapper;
 use tangram_client::{self as tg, handle::Ext as _};
 use tangram_database::{self as db, prelude::*};
@@ -559,16 +559,15 @@ async fn poll_read_inner(
 		"
 	);
 	let params = db::params![id, position, length];
-	let rows = connection
-		.query_all_into::<Row>(statement, params)
-		.await
-		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
-
-	// Drop the database connection.
-	drop(connection);
+	let mut rows = pin!(connection.query_stream_into::<Row>(statement, params));
 
+	// Copy the rows' bytes as they are read.
 	let mut bytes = BytesMut::with_capacity(length.to_usize().unwrap());
-	for row in rows {
+	while let Some(row) = rows
+		.try_next()
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?
+	{
 		if row.position < position {
 			let start = (position - row.position).to_usize().unwrap();
 			bytes.extend_from_slice(&row.bytes[start..]);
@@ -576,6 +575,10 @@ async fn poll_read_inner(
 			bytes.extend_from_slice(&row.bytes);
 		}
 	}
+
+	// Drop the database connection.
+	drop(rows);
+	drop(connection);
 	let cursor = Cursor::new(bytes.into());
 	Ok(Some(cursor))
 }
@@ -0,0 +1,165 @@
+use crate::Server;
+use bytes::Bytes;
+use futures::{stream, Stream, StreamExt as _};
+use tangram_client as tg;
+use tangram_futures::task::Stop;
+use tangram_http::{incoming::request::Ext as _, outgoing::response::Ext as _, Incoming, Outgoing};
+use tangram_messenger::Messenger as _;
+
+impl Server {
+	pub async fn try_get_build_progress_stream(
+		&self,
+		id: &tg::build::Id,
+	) -> tg::Result<
+		Option<impl Stream<Item = tg::Result<tg::build::progress::get::Event>> + Send + 'static>,
+	> {
+		// Verify the build is local.
+		if !self.get_build_exists_local(id).await? {
+			return Ok(None);
+		}
+
+		// Subscribe to progress events.
+		let subject = format!("builds.{id}.progress");
+		let progress = self
+			.messenger
+			.subscribe(subject, None)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to subscribe"))?
+			.map(|message| Some(message.payload))
+			.boxed();
+
+		// Subscribe to status events.
+		let subject = format!("builds.{id}.status");
+		let status = self
+			.messenger
+			.subscribe(subject, None)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to subscribe"))?
+			.map(|_| None)
+			.boxed();
+
+		// Create the stream. Check the status once on creation, and again on every status event.
+		let events = stream::once(async { None })
+			.chain(stream::select(progress, status))
+			.boxed();
+		let server = self.clone();
+		let id = id.clone();
+		let stream = stream::unfold(Some(events), move |events| {
+			let server = server.clone();
+			let id = id.clone();
+			async move {
+				let mut events = events?;
+				loop {
+					let Some(payload) = events.next().await? else {
+						// End the stream if the build is finished.
+						let status = server.try_get_current_build_status_local(&id).await;
+						match status {
+							Ok(Some(tg::build::Status::Finished) | None) => {
+								return Some((Ok(tg::build::progress::get::Event::End), None));
+							},
+							Ok(Some(_)) => continue,
+							Err(error) => return Some((Err(error), None)),
+						}
+					};
+					let event = serde_json::from_slice(&payload)
+						.map(tg::build::progress::get::Event::Value)
+						.map_err(|source| tg::error!(!source, "failed to deserialize the value"));
+					return Some((event, Some(events)));
+				}
+			}
+		});
+
+		Ok(Some(stream))
+	}
+
+	pub async fn add_build_progress(
+		&self,
+		id: &tg::build::Id,
+		arg: tg::build::progress::post::Arg,
+	) -> tg::Result<()> {
+		// Verify the build is local.
+		if !self.get_build_exists_local(id).await? {
+			return Err(tg::error!("failed to find the build"));
+		}
+
+		// Publish the value.
+		let payload = serde_json::to_vec(&arg.value)
+			.map_err(|source| tg::error!(!source, "failed to serialize the value"))?;
+		self.messenger
+			.publish(format!("builds.{id}.progress"), Bytes::from(payload))
+			.await
+			.map_err(|source| tg::error!(!source, "failed to publish the value"))?;
+
+		Ok(())
+	}
+}
+
+impl Server {
+	pub(crate) async fn handle_get_build_progress_request<H>(
+		handle: &H,
+		request: http::Request<Incoming>,
+		id: &str,
+	) -> tg::Result<http::Response<Outgoing>>
+	where
+		H: tg::Handle,
+	{
+		// Parse the ID.
+		let id = id.parse()?;
+
+		// Get the accept header.
+		let accept: Option<mime::Mime> = request.parse_header(http::header::ACCEPT).transpose()?;
+
+		// Get the stream.
+		let Some(stream) = handle.try_get_build_progress_stream(&id).await? else {
+			return Ok(http::Response::builder().not_found().empty().unwrap());
+		};
+
+		// Stop the stream when the server stops.
+		let stop = request.extensions().get::<Stop>().cloned().unwrap();
+		let stop = async move { stop.wait().await };
+		let stream = stream.take_until(stop);
+
+		// Create the body.
+		let (content_type, body) = match accept
+			.as_ref()
+			.map(|accept| (accept.type_(), accept.subtype()))
+		{
+			Some((mime::TEXT, mime::EVENT_STREAM)) => {
+				let content_type = mime::TEXT_EVENT_STREAM;
+				let stream = stream.map(|result| match result {
+					Ok(event) => event.try_into(),
+					Err(error) => error.try_into(),
+				});
+				(Some(content_type), Outgoing::sse(stream))
+			},
+
+			_ => {
+				return Err(tg::error!(?accept, "invalid accept header"));
+			},
+		};
+
+		// Create the response.
+		let mut response = http::Response::builder();
+		if let Some(content_type) = content_type {
+			response = response.header(http::header::CONTENT_TYPE, content_type.to_string());
+		}
+		let response = response.body(body).unwrap();
+
+		Ok(response)
+	}
+
+	pub(crate) async fn handle_add_build_progress_request<H>(
+		handle: &H,
+		request: http::Request<Incoming>,
+		id: &str,
+	) -> tg::Result<http::Response<Outgoing>>
+	where
+		H: tg::Handle,
+	{
+		let id = id.parse()?;
+		let arg = request.json().await?;
+		handle.add_build_progress(&id, arg).await?;
+		let response = http::Response::builder().empty().unwrap();
+		Ok(response)
+	}
+}
@@ -144,6 +144,7 @@ impl Server {
 			children,
 			depth: output.depth,
 			host: output.host,
+			labels: output.labels,
 			log: output.log.clone(),
 			outcome: output.outcome,
 			retry: output.retry,
@@ -178,7 +179,9 @@ impl Server {
 		let incomplete_object_count_and_weight = incomplete_objects
 			.iter()
 			.map(|object| async {
-				let stream = Self::push_or_pull_object(src, dst, object).await?.boxed();
+				let stream = Self::push_or_pull_object(src, dst, object, false)
+					.await?
+					.boxed();
 				stream.last().await;
 				Ok::<_, tg::Error>((0, 0))
 			})
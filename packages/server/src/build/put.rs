@@ -142,6 +142,48 @@ impl Server {
 				.try_collect::<()>()
 				.await?;
 
+			// Delete any existing labels.
+			let p = transaction.p();
+			let statement = formatdoc!(
+				"
+					delete from build_labels
+					where build = {p}1;
+				"
+			);
+			let params = db::params![id];
+			transaction
+				.execute(statement, params)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+			// Insert the labels.
+			let p = transaction.p();
+			let statement = formatdoc!(
+				"
+					insert into build_labels (build, key, value)
+					values ({p}1, {p}2, {p}3);
+				"
+			);
+			arg.labels
+				.iter()
+				.map(|(key, value)| {
+					let transaction = transaction.clone();
+					let statement = statement.clone();
+					async move {
+						let params = db::params![id, key, value];
+						transaction
+							.execute(statement, params)
+							.await
+							.map_err(|source| {
+								tg::error!(!source, "failed to execute the statement")
+							})?;
+						Ok::<_, tg::Error>(())
+					}
+				})
+				.collect::<FuturesUnordered<_>>()
+				.try_collect::<()>()
+				.await?;
+
 			// Delete any existing objects.
 			let p = transaction.p();
 			let statement = formatdoc!(
@@ -1,4 +1,4 @@
-use crate::{BuildPermit, Server};
+use crate::{BuildPermit, RuntimeSemaphores, Server};
 use futures::{
 	future, stream::FuturesUnordered, Future, FutureExt as _, TryFutureExt as _, TryStreamExt as _,
 };
@@ -110,6 +110,29 @@ impl Server {
 		permit: BuildPermit,
 		remote: Option<String>,
 	) -> tg::Result<()> {
+		// Acquire a permit for the runtime if it has a concurrency limit. Builds that run on their parent's permit do not acquire a runtime permit, to match the global limit and to avoid deadlocks between parents and children. If the host cannot be determined, then the build will fail when it looks up its runtime.
+		let (permit, runtime_permit) = match permit {
+			BuildPermit(Either::Left(permit)) => {
+				let host = match build.target(self).await {
+					Ok(target) => target.host(self).await.ok(),
+					Err(_) => None,
+				};
+				let (permit, runtime_permit) = if let Some(host) = host {
+					acquire_runtime_permit(
+						&self.build_semaphore,
+						&self.runtime_semaphores,
+						&host,
+						permit,
+					)
+					.await
+				} else {
+					(permit, None)
+				};
+				(BuildPermit(Either::Left(permit)), runtime_permit)
+			},
+			permit @ BuildPermit(Either::Right(_)) => (permit, None),
+		};
+
 		// Set the build's permit.
 		let permit = Arc::new(tokio::sync::Mutex::new(Some(permit)));
 		self.build_permits.insert(build.id().clone(), permit);
@@ -119,6 +142,7 @@ impl Server {
 
 		// Build.
 		let result = self.build_task_inner(build.clone(), remote.clone()).await;
+		drop(runtime_permit);
 		let outcome = match result {
 			Ok(outcome) => outcome,
 			Err(error) => {
@@ -130,7 +154,10 @@ impl Server {
 		// Push the output if the build is remote.
 		if let Some(remote) = remote.clone() {
 			if let tg::build::outcome::Data::Success(success) = &outcome {
-				let arg = tg::object::push::Arg { remote };
+				let arg = tg::object::push::Arg {
+					remote,
+					verbose: false,
+				};
 				tg::Value::try_from(success.value.clone())?
 					.objects()
 					.iter()
@@ -216,3 +243,137 @@ impl Server {
 		Ok(())
 	}
 }
+
+/// Acquire a permit for the host's runtime if it has a concurrency limit. If the runtime is at its limit, then the build permit is released while waiting for the runtime permit and reacquired afterward, so that builds waiting for a runtime do not hold build permits that builds for other runtimes could use.
+async fn acquire_runtime_permit(
+	build_semaphore: &Arc<tokio::sync::Semaphore>,
+	runtime_semaphores: &RuntimeSemaphores,
+	host: &str,
+	permit: tokio::sync::OwnedSemaphorePermit,
+) -> (
+	tokio::sync::OwnedSemaphorePermit,
+	Option<tokio::sync::OwnedSemaphorePermit>,
+) {
+	let Some(semaphore) = runtime_semaphores.get(host).cloned() else {
+		return (permit, None);
+	};
+	if let Ok(runtime_permit) = semaphore.clone().try_acquire_owned() {
+		return (permit, Some(runtime_permit));
+	}
+	drop(permit);
+	let runtime_permit = semaphore.acquire_owned().await.unwrap();
+	let permit = build_semaphore.clone().acquire_owned().await.unwrap();
+	(permit, Some(runtime_permit))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::acquire_runtime_permit;
+	use crate::RuntimeSemaphores;
+	use futures::{stream::FuturesUnordered, StreamExt as _};
+	use std::{
+		collections::VecDeque,
+		sync::{
+			atomic::{AtomicUsize, Ordering},
+			Arc,
+		},
+		time::Duration,
+	};
+
+	#[tokio::test]
+	async fn runtime_concurrency() {
+		let build_semaphore = Arc::new(tokio::sync::Semaphore::new(64));
+		let semaphores: RuntimeSemaphores = [
+			("linux".to_owned(), Arc::new(tokio::sync::Semaphore::new(2))),
+			("js".to_owned(), Arc::new(tokio::sync::Semaphore::new(4))),
+		]
+		.into_iter()
+		.collect();
+		let semaphores = Arc::new(semaphores);
+
+		// Run many builds on each runtime and record the maximum number running at once.
+		let hosts = ["linux", "js", "darwin"];
+		let running = hosts.map(|_| Arc::new(AtomicUsize::new(0)));
+		let maximum = hosts.map(|_| Arc::new(AtomicUsize::new(0)));
+		let mut tasks = FuturesUnordered::new();
+		for (index, host) in hosts.into_iter().enumerate() {
+			for _ in 0..16 {
+				let build_semaphore = build_semaphore.clone();
+				let semaphores = semaphores.clone();
+				let running = running[index].clone();
+				let maximum = maximum[index].clone();
+				tasks.push(tokio::spawn(async move {
+					let permit = build_semaphore.clone().acquire_owned().await.unwrap();
+					let permits =
+						acquire_runtime_permit(&build_semaphore, &semaphores, host, permit).await;
+					let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+					maximum.fetch_max(current, Ordering::SeqCst);
+					tokio::time::sleep(Duration::from_millis(10)).await;
+					running.fetch_sub(1, Ordering::SeqCst);
+					drop(permits);
+				}));
+			}
+		}
+		while let Some(result) = tasks.next().await {
+			result.unwrap();
+		}
+
+		// Verify that the limits were respected and that the runtime without a limit was unbounded.
+		assert_eq!(maximum[0].load(Ordering::SeqCst), 2);
+		assert_eq!(maximum[1].load(Ordering::SeqCst), 4);
+		assert_eq!(maximum[2].load(Ordering::SeqCst), 16);
+	}
+
+	#[tokio::test]
+	async fn capped_runtime_does_not_block_other_runtimes() {
+		// Allow two builds at once, and one linux build at once.
+		let build_semaphore = Arc::new(tokio::sync::Semaphore::new(2));
+		let semaphores: RuntimeSemaphores =
+			[("linux".to_owned(), Arc::new(tokio::sync::Semaphore::new(1)))]
+				.into_iter()
+				.collect();
+		let semaphores = Arc::new(semaphores);
+
+		// Queue several linux builds that never finish, followed by a js build.
+		let mut queue = VecDeque::from(["linux", "linux", "linux", "linux", "js"]);
+		let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+
+		// Run the queue the way the build spawn task does, acquiring a build permit before dequeuing each build.
+		let runner = tokio::spawn({
+			let build_semaphore = build_semaphore.clone();
+			async move {
+				while let Some(host) = queue.pop_front() {
+					let permit = build_semaphore.clone().acquire_owned().await.unwrap();
+					let build_semaphore = build_semaphore.clone();
+					let semaphores = semaphores.clone();
+					let sender = sender.clone();
+					tokio::spawn(async move {
+						let permits =
+							acquire_runtime_permit(&build_semaphore, &semaphores, host, permit)
+								.await;
+						sender.send(host).unwrap();
+						if host == "linux" {
+							std::future::pending::<()>().await;
+						}
+						drop(permits);
+					});
+				}
+			}
+		});
+
+		// Verify that the js build runs even though the linux builds are waiting on the linux runtime.
+		let result = tokio::time::timeout(Duration::from_secs(5), async {
+			while let Some(host) = receiver.recv().await {
+				if host == "js" {
+					break;
+				}
+			}
+		})
+		.await;
+		assert!(
+			result.is_ok(),
+			"the js build was blocked by the linux builds"
+		);
+		runner.abort();
+	}
+}
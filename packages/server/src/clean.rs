@@ -1,14 +1,33 @@
 use super::Server;
+use crate::database::Transaction;
 use indoc::formatdoc;
+use std::collections::VecDeque;
 use tangram_client as tg;
 use tangram_database::{self as db, prelude::*};
-use tangram_http::{outgoing::response::Ext as _, Incoming, Outgoing};
+use tangram_either::Either;
+use tangram_http::{incoming::request::Ext as _, outgoing::response::Ext as _, Incoming, Outgoing};
+use time::format_description::well_known::Rfc3339;
 
 #[cfg(test)]
 mod tests;
 
 impl Server {
-	pub async fn clean(&self) -> tg::Result<()> {
+	pub async fn clean(&self, arg: tg::clean::Arg) -> tg::Result<tg::clean::Output> {
+		// Prune the tags.
+		let pruned = if let Some(keep_last) = arg.keep_last {
+			let prefix = arg.tag_prefix.as_deref().unwrap_or_default();
+			self.prune_tags(prefix, keep_last).await?
+		} else {
+			Vec::new()
+		};
+
+		// If roots were specified, then only clean their closures.
+		if !arg.roots.is_empty() {
+			let bytes = self.clean_roots(&arg.roots).await?;
+			let output = tg::clean::Output { bytes, pruned };
+			return Ok(output);
+		}
+
 		// Clean the temporary directory.
 		tokio::fs::remove_dir_all(self.temp_path())
 			.await
@@ -91,6 +110,20 @@ impl Server {
 					.await
 					.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
 
+				// Remove the build labels.
+				let p = transaction.p();
+				let statement = formatdoc!(
+					"
+						delete from build_labels
+						where build = {p}1;
+					"
+				);
+				let params = db::params![id];
+				transaction
+					.execute(statement, params)
+					.await
+					.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
 				// Remove the build objects.
 				let p = transaction.p();
 				let statement = formatdoc!(
@@ -114,6 +147,7 @@ impl Server {
 		}
 
 		// Remove objects.
+		let mut bytes = 0;
 		loop {
 			// Begin a transaction.
 			let transaction = connection
@@ -122,9 +156,14 @@ impl Server {
 				.map_err(|source| tg::error!(!source, "failed to begin a transaction"))?;
 
 			// Get objects to remove.
+			#[derive(serde::Deserialize)]
+			struct Row {
+				id: tg::object::Id,
+				size: u64,
+			}
 			let statement = formatdoc!(
 				"
-					select id
+					select id, coalesce(length(bytes), 0) as size
 					from objects
 					where (
 						select count(*) = 0
@@ -144,7 +183,7 @@ impl Server {
 			);
 			let params = db::params![];
 			let objects = transaction
-				.query_all_value_into::<tg::object::Id>(statement, params)
+				.query_all_into::<Row>(statement, params)
 				.await
 				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
 
@@ -153,7 +192,9 @@ impl Server {
 				break;
 			}
 
-			for id in objects {
+			for Row { id, size } in objects {
+				bytes += size;
+
 				// Remove the object.
 				let p = transaction.p();
 				let statement = formatdoc!(
@@ -190,19 +231,379 @@ impl Server {
 				.map_err(|source| tg::error!(!source, "failed to commit the transaction"))?;
 		}
 
-		Ok(())
+		let output = tg::clean::Output { bytes, pruned };
+
+		Ok(output)
+	}
+
+	/// Remove the roots and the items in their closures that are no longer referenced by a build, object, or tag, and return the number of bytes of object data that were removed.
+	async fn clean_roots(
+		&self,
+		roots: &[Either<tg::build::Id, tg::object::Id>],
+	) -> tg::Result<u64> {
+		// Get a database connection.
+		let mut connection = self
+			.database
+			.write_connection()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
+
+		// Begin a transaction.
+		let transaction = connection
+			.transaction()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to begin a transaction"))?;
+
+		// Remove the items. An item is visited once for each of its removed parents, so an item shared by several removed parents is removed after the last of them.
+		let mut bytes = 0;
+		let mut queue = roots.iter().cloned().collect::<VecDeque<_>>();
+		while let Some(item) = queue.pop_front() {
+			match item {
+				Either::Left(build) => {
+					clean_build(&transaction, &build, &mut queue).await?;
+				},
+				Either::Right(object) => {
+					bytes += clean_object(&transaction, &object, &mut queue).await?;
+				},
+			}
+		}
+
+		// Commit the transaction.
+		transaction
+			.commit()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to commit the transaction"))?;
+
+		Ok(bytes)
+	}
+
+	/// Delete all but the `keep_last` most recently created tags under `prefix`, and return the deleted tags. Tags written before creation times were recorded are treated as the oldest.
+	async fn prune_tags(&self, prefix: &str, keep_last: u64) -> tg::Result<Vec<tg::Tag>> {
+		// Get a database connection.
+		let mut connection = self
+			.database
+			.write_connection()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
+
+		// Begin a transaction.
+		let transaction = connection
+			.transaction()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to begin a transaction"))?;
+
+		// Get the tags under the prefix. The pattern's wildcards are escaped, and because `like` is case insensitive on some databases, the rows are filtered by the prefix again.
+		#[derive(serde::Deserialize)]
+		struct Row {
+			tag: tg::Tag,
+			created_at: Option<String>,
+		}
+		let p = transaction.p();
+		let statement = formatdoc!(
+			r"
+				select tag, created_at
+				from tags
+				where tag like {p}1 escape '\';
+			"
+		);
+		let pattern = format!(
+			"{}%",
+			prefix
+				.replace('\\', "\\\\")
+				.replace('%', "\\%")
+				.replace('_', "\\_")
+		);
+		let params = db::params![pattern];
+		let rows = transaction
+			.query_all_into::<Row>(statement, params)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+		// Sort the tags from newest to oldest.
+		let mut tags = rows
+			.into_iter()
+			.filter(|row| row.tag.as_str().starts_with(prefix))
+			.map(|row| {
+				let created_at = row
+					.created_at
+					.map(|created_at| time::OffsetDateTime::parse(&created_at, &Rfc3339))
+					.transpose()
+					.map_err(|source| tg::error!(!source, "failed to parse the creation time"))?;
+				Ok::<_, tg::Error>((row.tag, created_at))
+			})
+			.collect::<tg::Result<Vec<_>>>()?;
+		tags.sort_by(|(a_tag, a_created_at), (b_tag, b_created_at)| {
+			b_created_at
+				.cmp(a_created_at)
+				.then_with(|| b_tag.as_str().cmp(a_tag.as_str()))
+		});
+
+		// Delete the tags beyond the ones to keep.
+		let keep_last = keep_last.try_into().unwrap_or(usize::MAX);
+		let pruned = tags
+			.into_iter()
+			.skip(keep_last)
+			.map(|(tag, _)| tag)
+			.collect::<Vec<_>>();
+		for tag in &pruned {
+			let p = transaction.p();
+			let statement = formatdoc!(
+				"
+					delete from tags
+					where tag = {p}1;
+				"
+			);
+			let params = db::params![tag];
+			transaction
+				.execute(statement, params)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+		}
+
+		// Commit the transaction.
+		transaction
+			.commit()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to commit the transaction"))?;
+
+		Ok(pruned)
 	}
 }
 
+/// Remove the build if it is not referenced, and enqueue its children and objects.
+async fn clean_build(
+	transaction: &Transaction<'_>,
+	id: &tg::build::Id,
+	queue: &mut VecDeque<Either<tg::build::Id, tg::object::Id>>,
+) -> tg::Result<()> {
+	// Determine if the build exists and is not referenced.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			select (
+				select count(*)
+				from build_children
+				where child = {p}1
+			) + (
+				select count(*)
+				from tags
+				where item = {p}1
+			)
+			from builds
+			where id = {p}1;
+		"
+	);
+	let params = db::params![id];
+	let parents = transaction
+		.query_optional_value_into::<u64>(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+	if parents != Some(0) {
+		return Ok(());
+	}
+
+	// Get the build's children.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			select child
+			from build_children
+			where build = {p}1;
+		"
+	);
+	let params = db::params![id];
+	let children = transaction
+		.query_all_value_into::<tg::build::Id>(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Get the build's objects.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			select object
+			from build_objects
+			where build = {p}1;
+		"
+	);
+	let params = db::params![id];
+	let objects = transaction
+		.query_all_value_into::<tg::object::Id>(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Remove the build.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			delete from builds
+			where id = {p}1;
+		"
+	);
+	let params = db::params![id];
+	transaction
+		.execute(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Remove the build children.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			delete from build_children
+			where build = {p}1;
+		"
+	);
+	let params = db::params![id];
+	transaction
+		.execute(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Remove the build labels.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			delete from build_labels
+			where build = {p}1;
+		"
+	);
+	let params = db::params![id];
+	transaction
+		.execute(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Remove the build objects.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			delete from build_objects
+			where build = {p}1;
+		"
+	);
+	let params = db::params![id];
+	transaction
+		.execute(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Enqueue the children and objects.
+	queue.extend(children.into_iter().map(Either::Left));
+	queue.extend(objects.into_iter().map(Either::Right));
+
+	Ok(())
+}
+
+/// Remove the object if it is not referenced, enqueue its children, and return the number of bytes removed.
+async fn clean_object(
+	transaction: &Transaction<'_>,
+	id: &tg::object::Id,
+	queue: &mut VecDeque<Either<tg::build::Id, tg::object::Id>>,
+) -> tg::Result<u64> {
+	// Determine if the object exists and is not referenced.
+	#[derive(serde::Deserialize)]
+	struct Row {
+		parents: u64,
+		size: u64,
+	}
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			select (
+				select count(*)
+				from object_children
+				where child = {p}1
+			) + (
+				select count(*)
+				from build_objects
+				where object = {p}1
+			) + (
+				select count(*)
+				from tags
+				where item = {p}1
+			) as parents, coalesce(length(bytes), 0) as size
+			from objects
+			where id = {p}1;
+		"
+	);
+	let params = db::params![id];
+	let row = transaction
+		.query_optional_into::<Row>(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+	let Some(Row { parents: 0, size }) = row else {
+		return Ok(0);
+	};
+
+	// Get the object's children.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			select child
+			from object_children
+			where object = {p}1;
+		"
+	);
+	let params = db::params![id];
+	let children = transaction
+		.query_all_value_into::<tg::object::Id>(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Remove the object.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			delete from objects
+			where id = {p}1;
+		"
+	);
+	let params = db::params![id];
+	transaction
+		.execute(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Remove the object children.
+	let p = transaction.p();
+	let statement = formatdoc!(
+		"
+			delete from object_children
+			where object = {p}1;
+		"
+	);
+	let params = db::params![id];
+	transaction
+		.execute(statement, params)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+	// Enqueue the children.
+	queue.extend(children.into_iter().map(Either::Right));
+
+	Ok(size)
+}
+
 impl Server {
 	pub(crate) async fn handle_server_clean_request<H>(
 		handle: &H,
-		_request: http::Request<Incoming>,
+		request: http::Request<Incoming>,
 	) -> tg::Result<http::Response<Outgoing>>
 	where
 		H: tg::Handle,
 	{
-		handle.clean().await?;
-		Ok(http::Response::builder().empty().unwrap())
+		// Get the arg. The body is optional, and an empty body is the default arg.
+		let bytes = request.bytes().await?;
+		let arg = if bytes.is_empty() {
+			tg::clean::Arg::default()
+		} else {
+			serde_json::from_slice(&bytes)
+				.map_err(|source| tg::error!(!source, "failed to deserialize the arg"))?
+		};
+
+		let output = handle.clean(arg).await?;
+		let response = http::Response::builder().json(output).unwrap();
+		Ok(response)
 	}
 }
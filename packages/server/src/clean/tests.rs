@@ -1,7 +1,7 @@
 use crate::{Config, Server};
 use bytes::Bytes;
 use futures::{stream::FuturesUnordered, FutureExt as _, TryStreamExt as _};
-use std::{fmt::Debug, panic::AssertUnwindSafe};
+use std::{collections::BTreeMap, fmt::Debug, panic::AssertUnwindSafe};
 use tangram_client as tg;
 use tangram_either::Either;
 use tangram_temp::Temp;
@@ -42,7 +42,7 @@ async fn test_builds() -> tg::Result<()> {
 		server.put_tag(&tag, arg).await?;
 
 		// Clean.
-		server.clean().await?;
+		server.clean(tg::clean::Arg::default()).await?;
 
 		// Assert.
 		assert_build_presence(
@@ -132,7 +132,7 @@ async fn test_objects() -> tg::Result<()> {
 		server.put_tag(&tag, arg).await?;
 
 		// Clean.
-		server.clean().await?;
+		server.clean(tg::clean::Arg::default()).await?;
 
 		// Assert.
 		assert_object_presence(
@@ -159,6 +159,205 @@ async fn test_objects() -> tg::Result<()> {
 	result.unwrap()
 }
 
+#[tokio::test]
+async fn test_keep_last() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		// Create a build for each tag.
+		let mut builds = Vec::new();
+		for name in ["ci/a", "ci/b", "ci/c", "other"] {
+			let build = create_test_build(&server, Vec::new()).await?;
+			let tag = name
+				.parse()
+				.map_err(|source| tg::error!(!source, "failed to parse the tag"))?;
+			let arg = tg::tag::put::Arg {
+				force: false,
+				item: Either::Left(build.clone()),
+				remote: None,
+			};
+			server.put_tag(&tag, arg).await?;
+			builds.push(build);
+
+			// Ensure that the tags have distinct creation times.
+			tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+		}
+
+		// Clean, keeping the two most recent tags under the prefix.
+		let arg = tg::clean::Arg {
+			keep_last: Some(2),
+			roots: Vec::new(),
+			tag_prefix: Some("ci/".to_owned()),
+		};
+		let output = server.clean(arg).await?;
+
+		// Assert.
+		let pruned = output
+			.pruned
+			.iter()
+			.map(tg::Tag::as_str)
+			.collect::<Vec<_>>();
+		assert_eq!(pruned, vec!["ci/a"]);
+		assert_build_presence(
+			vec![
+				(builds[0].clone(), false),
+				(builds[1].clone(), true),
+				(builds[2].clone(), true),
+				(builds[3].clone(), true),
+			],
+			&server,
+		)
+		.await?;
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	server.stop();
+	server.wait().await;
+	result.unwrap()
+}
+
+#[tokio::test]
+async fn test_keep_last_escapes_prefix_and_keeps_creation_times() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		// Create a build for each tag. The `_` in the prefix must not match `x`.
+		let mut builds = Vec::new();
+		for name in ["ci_/a", "ci_/b", "cix/c"] {
+			let build = create_test_build(&server, Vec::new()).await?;
+			let tag = name
+				.parse()
+				.map_err(|source| tg::error!(!source, "failed to parse the tag"))?;
+			let arg = tg::tag::put::Arg {
+				force: false,
+				item: Either::Left(build.clone()),
+				remote: None,
+			};
+			server.put_tag(&tag, arg).await?;
+			builds.push(build);
+
+			// Ensure that the tags have distinct creation times.
+			tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+		}
+
+		// Put the oldest tag again, which must not change its creation time.
+		let tag = "ci_/a"
+			.parse()
+			.map_err(|source| tg::error!(!source, "failed to parse the tag"))?;
+		let arg = tg::tag::put::Arg {
+			force: true,
+			item: Either::Left(builds[0].clone()),
+			remote: None,
+		};
+		server.put_tag(&tag, arg).await?;
+
+		// Clean, keeping the most recent tag under the prefix.
+		let arg = tg::clean::Arg {
+			keep_last: Some(1),
+			roots: Vec::new(),
+			tag_prefix: Some("ci_".to_owned()),
+		};
+		let output = server.clean(arg).await?;
+
+		// Assert.
+		let pruned = output
+			.pruned
+			.iter()
+			.map(tg::Tag::as_str)
+			.collect::<Vec<_>>();
+		assert_eq!(pruned, vec!["ci_/a"]);
+		assert_build_presence(
+			vec![
+				(builds[0].clone(), false),
+				(builds[1].clone(), true),
+				(builds[2].clone(), true),
+			],
+			&server,
+		)
+		.await?;
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	server.stop();
+	server.wait().await;
+	result.unwrap()
+}
+
+#[tokio::test]
+async fn test_roots() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		// Create the directories.
+		let a = tg::directory! {
+			"b" => tg::directory! {
+				"f" => tg::file!("f"),
+			},
+			"e" => tg::file!("e"),
+		};
+		let z = tg::directory! {
+			"y" => tg::file!("y"),
+		};
+
+		// Get the IDs.
+		let b = a.get(&server, "b").await?.id(&server).await?;
+		let e = a.get(&server, "e").await?.id(&server).await?;
+		let f = a.get(&server, "b/f").await?.id(&server).await?;
+		let a = a.id(&server).await?;
+		let z = z.id(&server).await?;
+
+		// Index the root objects.
+		server.index_object_recursive(&a.clone().into()).await?;
+		server.index_object_recursive(&z.clone().into()).await?;
+
+		// Tag e.
+		let tag = "e"
+			.parse()
+			.map_err(|source| tg::error!(!source, "failed to parse the tag"))?;
+		let arg = tg::tag::put::Arg {
+			force: false,
+			item: Either::Right(e.clone().into()),
+			remote: None,
+		};
+		server.put_tag(&tag, arg).await?;
+
+		// Clean the closure of a.
+		let arg = tg::clean::Arg {
+			roots: vec![Either::Right(a.clone().into())],
+			..Default::default()
+		};
+		let output = server.clean(arg).await?;
+
+		// Assert.
+		assert!(output.bytes > 0);
+		assert_object_presence(
+			&server,
+			vec![
+				(a.into(), false),
+				(b, false),
+				(f, false),
+				(e, true),
+				(z.into(), true),
+			],
+		)
+		.await?;
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	server.stop();
+	server.wait().await;
+	result.unwrap()
+}
+
 async fn create_test_build(
 	server: &Server,
 	build_children: Vec<tg::build::Id>,
@@ -169,6 +368,7 @@ async fn create_test_build(
 		children: build_children,
 		depth: 1,
 		host: "host".to_string(),
+		labels: BTreeMap::new(),
 		log: None,
 		outcome: None,
 		retry: tg::build::Retry::Succeeded,
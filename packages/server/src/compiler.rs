@@ -4,10 +4,11 @@ use dashmap::DashMap;
 use futures::{future, Future, FutureExt as _, TryFutureExt as _, TryStreamExt};
 use lsp_types::{self as lsp, notification::Notification as _, request::Request as _};
 use std::{
-	collections::{BTreeMap, BTreeSet, HashMap},
+	collections::{BTreeMap, HashMap},
 	path::{Path, PathBuf},
 	pin::pin,
 	sync::{Arc, Mutex},
+	time::SystemTime,
 };
 use tangram_client as tg;
 use tangram_futures::task::{Stop, Task};
@@ -19,11 +20,13 @@ use tokio::io::{
 
 pub mod analysis;
 pub mod check;
+pub mod code_action;
 pub mod completion;
 pub mod definition;
 pub mod diagnostics;
 pub mod document;
 pub mod error;
+pub mod folding_ranges;
 pub mod format;
 pub mod hover;
 pub mod initialize;
@@ -33,6 +36,8 @@ pub mod parse;
 pub mod references;
 pub mod rename;
 pub mod resolve;
+pub mod semantic_tokens;
+pub mod signature_help;
 pub mod symbols;
 pub mod syscall;
 pub mod transpile;
@@ -74,25 +79,33 @@ pub struct Inner {
 	/// The server.
 	server: Server,
 
+	/// The workspace symbols for each module, along with the modified time of the module's file when they were computed. They are removed when the module's document changes, and recomputed when the file's modified time changes.
+	symbols:
+		DashMap<tg::Module, (Option<SystemTime>, Vec<lsp::WorkspaceSymbol>), fnv::FnvBuildHasher>,
+
 	/// The task.
 	task: Mutex<Option<Task<()>>>,
 
-	/// The workspaces.
-	workspaces: tokio::sync::RwLock<BTreeSet<PathBuf>>,
+	/// The workspaces, and the modules in each. The modules are listed when they are first needed, and listed again after a document in the workspace is saved.
+	workspaces: tokio::sync::RwLock<BTreeMap<PathBuf, Option<Vec<tg::Module>>>>,
 }
 
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "snake_case", tag = "kind", content = "request")]
 enum Request {
 	Check(check::Request),
+	CodeAction(code_action::Request),
 	Completion(completion::Request),
 	Definition(definition::Request),
 	TypeDefinition(definition::Request),
 	Diagnostics(diagnostics::Request),
 	Document(document::Request),
+	FoldingRanges(folding_ranges::Request),
 	Hover(hover::Request),
 	References(references::Request),
 	Rename(rename::Request),
+	SemanticTokens(semantic_tokens::Request),
+	SignatureHelp(signature_help::Request),
 	Symbols(symbols::Request),
 }
 
@@ -100,14 +113,18 @@ enum Request {
 #[serde(rename_all = "snake_case", tag = "kind", content = "response")]
 enum Response {
 	Check(check::Response),
+	CodeAction(code_action::Response),
 	Completion(completion::Response),
 	Definition(definition::Response),
 	TypeDefinition(definition::Response),
 	Diagnostics(diagnostics::Response),
 	Document(document::Response),
+	FoldingRanges(folding_ranges::Response),
 	Hover(hover::Response),
 	References(references::Response),
 	Rename(rename::Response),
+	SemanticTokens(semantic_tokens::Response),
+	SignatureHelp(signature_help::Response),
 	Symbols(symbols::Response),
 }
 
@@ -127,7 +144,8 @@ impl Compiler {
 		let sender = std::sync::RwLock::new(None);
 		let serve_task = tokio::sync::Mutex::new(None);
 		let stop_task = Mutex::new(None);
-		let workspaces = tokio::sync::RwLock::new(BTreeSet::new());
+		let symbols = DashMap::default();
+		let workspaces = tokio::sync::RwLock::new(BTreeMap::new());
 
 		// Create the compiler.
 		let compiler = Self(Arc::new(Inner {
@@ -140,6 +158,7 @@ impl Compiler {
 			sender,
 			serve_task,
 			server: server.clone(),
+			symbols,
 			task: stop_task,
 			workspaces,
 		}));
@@ -377,6 +396,12 @@ impl Compiler {
 
 	async fn handle_request(&self, request: jsonrpc::Request) {
 		match request.method.as_str() {
+			lsp::request::CodeActionRequest::METHOD => self
+				.handle_request_with::<lsp::request::CodeActionRequest, _, _>(request, |params| {
+					self.handle_code_action_request(params)
+				})
+				.boxed(),
+
 			lsp::request::Completion::METHOD => self
 				.handle_request_with::<lsp::request::Completion, _, _>(request, |params| {
 					self.handle_completion_request(params)
@@ -402,6 +427,12 @@ impl Compiler {
 				})
 				.boxed(),
 
+			lsp::request::FoldingRangeRequest::METHOD => self
+				.handle_request_with::<lsp::request::FoldingRangeRequest, _, _>(request, |params| {
+					self.handle_folding_range_request(params)
+				})
+				.boxed(),
+
 			lsp::request::Formatting::METHOD => self
 				.handle_request_with::<lsp::request::Formatting, _, _>(request, |params| {
 					self.handle_format_request(params)
@@ -420,6 +451,12 @@ impl Compiler {
 				})
 				.boxed(),
 
+			lsp::request::RangeFormatting::METHOD => self
+				.handle_request_with::<lsp::request::RangeFormatting, _, _>(request, |params| {
+					self.handle_range_format_request(params)
+				})
+				.boxed(),
+
 			lsp::request::References::METHOD => self
 				.handle_request_with::<lsp::request::References, _, _>(request, |params| {
 					self.handle_references_request(params)
@@ -432,6 +469,27 @@ impl Compiler {
 				})
 				.boxed(),
 
+			lsp::request::SemanticTokensFullRequest::METHOD => self
+				.handle_request_with::<lsp::request::SemanticTokensFullRequest, _, _>(
+					request,
+					|params| self.handle_semantic_tokens_full_request(params),
+				)
+				.boxed(),
+
+			lsp::request::SignatureHelpRequest::METHOD => self
+				.handle_request_with::<lsp::request::SignatureHelpRequest, _, _>(
+					request,
+					|params| self.handle_signature_help_request(params),
+				)
+				.boxed(),
+
+			lsp::request::WorkspaceSymbolRequest::METHOD => self
+				.handle_request_with::<lsp::request::WorkspaceSymbolRequest, _, _>(
+					request,
+					|params| self.handle_workspace_symbols_request(params),
+				)
+				.boxed(),
+
 			lsp::request::Shutdown::METHOD => self
 				.handle_request_with::<lsp::request::Shutdown, _, _>(request, |()| async move {
 					Ok::<_, tg::Error>(())
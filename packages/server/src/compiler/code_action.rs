@@ -0,0 +1,119 @@
+use super::Compiler;
+use lsp_types as lsp;
+use tangram_client as tg;
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+	pub module: tg::Module,
+	pub range: tg::Range,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+	pub actions: Vec<Action>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Action {
+	pub title: String,
+	pub changes: Vec<Change>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+	pub module: tg::Module,
+	pub edits: Vec<Edit>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Edit {
+	pub range: tg::Range,
+	pub text: String,
+}
+
+impl Compiler {
+	pub(super) async fn handle_code_action_request(
+		&self,
+		params: lsp::CodeActionParams,
+	) -> tg::Result<Option<lsp::CodeActionResponse>> {
+		// Only quick fixes are supported.
+		if let Some(only) = &params.context.only {
+			if !only.iter().any(|kind| {
+				lsp::CodeActionKind::QUICKFIX
+					.as_str()
+					.starts_with(kind.as_str())
+			}) {
+				return Ok(None);
+			}
+		}
+
+		// Get the module.
+		let module = self.module_for_lsp_uri(&params.text_document.uri).await?;
+
+		// Get the code actions.
+		let actions = self.code_actions(&module, params.range.into()).await?;
+
+		// Convert the code actions.
+		let mut output = Vec::new();
+		for action in actions {
+			let mut document_changes = Vec::new();
+			for change in action.changes {
+				let uri = self.lsp_uri_for_module(&change.module).await?;
+				let version = self.get_module_version(&change.module).await?;
+				let edits = change
+					.edits
+					.into_iter()
+					.map(|edit| {
+						lsp::OneOf::Left(lsp::TextEdit {
+							range: edit.range.into(),
+							new_text: edit.text,
+						})
+					})
+					.collect();
+				document_changes.push(lsp::TextDocumentEdit {
+					text_document: lsp::OptionalVersionedTextDocumentIdentifier {
+						uri,
+						version: Some(version),
+					},
+					edits,
+				});
+			}
+			let edit = lsp::WorkspaceEdit {
+				changes: None,
+				document_changes: Some(lsp::DocumentChanges::Edits(document_changes)),
+				change_annotations: None,
+			};
+			output.push(lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+				title: action.title,
+				kind: Some(lsp::CodeActionKind::QUICKFIX),
+				edit: Some(edit),
+				..Default::default()
+			}));
+		}
+
+		Ok(Some(output))
+	}
+
+	/// Get the quick fixes for the diagnostics in a range of a module.
+	pub async fn code_actions(
+		&self,
+		module: &tg::Module,
+		range: tg::Range,
+	) -> tg::Result<Vec<Action>> {
+		// Create the request.
+		let request = super::Request::CodeAction(Request {
+			module: module.clone(),
+			range,
+		});
+
+		// Perform the request.
+		let response = self.request(request).await?.unwrap_code_action();
+
+		Ok(response.actions)
+	}
+}
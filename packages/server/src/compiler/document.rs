@@ -68,6 +68,9 @@ impl Compiler {
 		// Insert the document.
 		self.documents.insert(module.clone(), document);
 
+		// Invalidate the module's symbols.
+		self.symbols.remove(module);
+
 		Ok(())
 	}
 
@@ -105,6 +108,9 @@ impl Compiler {
 		// Replace the text.
 		document.text.as_mut().unwrap().replace_range(range, &text);
 
+		// Invalidate the module's symbols.
+		self.symbols.remove(module);
+
 		Ok(())
 	}
 
@@ -129,6 +135,9 @@ impl Compiler {
 		// Clear the document's text.
 		document.text = None;
 
+		// Invalidate the module's symbols.
+		self.symbols.remove(module);
+
 		// Set the document's modified time if it is a path module.
 		let tg::module::Item::Path(path) = &module.referent.item else {
 			return Ok(());
@@ -157,19 +166,31 @@ impl Compiler {
 			.get_mut(module)
 			.ok_or_else(|| tg::error!("failed to get document"))?;
 		document.dirty = false;
+		drop(document);
 
-		// Check in the object if necessary.
+		// Invalidate the module's symbols.
+		self.symbols.remove(module);
+
+		// Get the package path.
 		let tg::module::Item::Path(package_path) = module.referent.item.clone() else {
 			return Ok(());
 		};
+
+		// Invalidate the modules of the workspaces that contain the package, since the save may have added a module.
+		self.invalidate_workspace_modules(&package_path).await;
+
+		// Check in the package.
 		let arg = tg::artifact::checkin::Arg {
 			cache: false,
 			path: package_path.clone(),
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
+			strategy: None,
 		};
 		tg::Artifact::check_in(&self.server, arg).await.map_err(
 			|source| tg::error!(!source, %package = package_path.display(), "failed to check in package"),
@@ -192,9 +213,12 @@ impl Compiler {
 			path: params.text_document.uri.path().as_str().into(),
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
+			strategy: None,
 		};
 		tg::Artifact::check_in(&self.server, arg)
 			.await
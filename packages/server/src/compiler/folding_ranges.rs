@@ -0,0 +1,76 @@
+use super::Compiler;
+use lsp_types as lsp;
+use tangram_client as tg;
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+	pub module: tg::Module,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+	pub folding_ranges: Vec<FoldingRange>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoldingRange {
+	pub start_line: u32,
+	pub end_line: u32,
+	pub kind: Option<Kind>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+	Comment,
+	Imports,
+	Region,
+}
+
+impl Compiler {
+	pub(super) async fn handle_folding_range_request(
+		&self,
+		params: lsp::FoldingRangeParams,
+	) -> tg::Result<Option<Vec<lsp::FoldingRange>>> {
+		// Get the module.
+		let module = self.module_for_lsp_uri(&params.text_document.uri).await?;
+
+		// Get the folding ranges.
+		let folding_ranges = self.folding_ranges(&module).await?;
+
+		// Convert the folding ranges. Only lines are returned, so the position encoding does not apply.
+		let folding_ranges = folding_ranges
+			.into_iter()
+			.map(|folding_range| lsp::FoldingRange {
+				start_line: folding_range.start_line,
+				start_character: None,
+				end_line: folding_range.end_line,
+				end_character: None,
+				kind: folding_range.kind.map(|kind| match kind {
+					Kind::Comment => lsp::FoldingRangeKind::Comment,
+					Kind::Imports => lsp::FoldingRangeKind::Imports,
+					Kind::Region => lsp::FoldingRangeKind::Region,
+				}),
+				collapsed_text: None,
+			})
+			.collect();
+
+		Ok(Some(folding_ranges))
+	}
+
+	/// Get the folding ranges for the blocks, imports, comments, and multi-line template literals of a module.
+	pub async fn folding_ranges(&self, module: &tg::Module) -> tg::Result<Vec<FoldingRange>> {
+		// Create the request.
+		let request = super::Request::FoldingRanges(Request {
+			module: module.clone(),
+		});
+
+		// Perform the request.
+		let response = self.request(request).await?.unwrap_folding_ranges();
+
+		Ok(response.folding_ranges)
+	}
+}
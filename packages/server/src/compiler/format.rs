@@ -16,6 +16,29 @@ impl Compiler {
 			.into_code();
 		Ok(text)
 	}
+
+	/// Format a byte range of a module's text. The range is expanded to the enclosing statements, so a range that splits a token is formatted as the statement that contains it. This returns the expanded range and its formatted text, or `None` if the range covers no statements.
+	pub fn format_range(
+		text: &str,
+		range: std::ops::Range<usize>,
+	) -> tg::Result<Option<(std::ops::Range<usize>, String)>> {
+		let source_type = biome_js_syntax::JsFileSource::ts();
+		let options = biome_js_parser::JsParserOptions::default();
+		let node = biome_js_parser::parse(text, source_type, options);
+		let options = biome_js_formatter::context::JsFormatOptions::new(source_type);
+		let start = u32::try_from(range.start)
+			.map_err(|source| tg::error!(!source, "the range is too large"))?;
+		let end = u32::try_from(range.end)
+			.map_err(|source| tg::error!(!source, "the range is too large"))?;
+		let range = biome_js_syntax::TextRange::new(start.into(), end.into());
+		let printed = biome_js_formatter::format_range(options, &node.syntax(), range)
+			.map_err(|source| tg::error!(!source, "failed to format"))?;
+		let Some(range) = printed.range() else {
+			return Ok(None);
+		};
+		let range = usize::from(range.start())..usize::from(range.end());
+		Ok(Some((range, printed.into_code())))
+	}
 }
 
 impl Compiler {
@@ -44,3 +67,50 @@ impl Compiler {
 		Ok(Some(vec![edit]))
 	}
 }
+
+impl Compiler {
+	pub(super) async fn handle_range_format_request(
+		&self,
+		params: lsp::DocumentRangeFormattingParams,
+	) -> tg::Result<Option<Vec<lsp::TextEdit>>> {
+		// Get the module.
+		let module = self.module_for_lsp_uri(&params.text_document.uri).await?;
+
+		// Load the module.
+		let text = self.load_module(&module).await?;
+
+		// Get the byte range.
+		let range = tg::Range::from(params.range).to_byte_range_in_string(&text);
+
+		// Format the range.
+		let Some((range, formatted_text)) = Self::format_range(&text, range)? else {
+			return Ok(Some(Vec::new()));
+		};
+
+		// Create the edit.
+		let edit = lsp::TextEdit {
+			range: tg::Range::from_byte_range_in_string(&text, range).into(),
+			new_text: formatted_text,
+		};
+
+		Ok(Some(vec![edit]))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_format_range() {
+		let text = "export let a = () => {\n\tlet   x=1;\n\treturn   x\n};\n\nexport let b = () => {  return   2  };\n";
+		let start = text.find("let   x").unwrap();
+		let end = text.find("return   x").unwrap() + "return   x".len();
+		let (range, formatted) = Compiler::format_range(text, start..end).unwrap().unwrap();
+		let mut left = text.to_owned();
+		left.replace_range(range, &formatted);
+		assert!(left.starts_with("export let a = () => {\n\tlet x = 1;\n"));
+		assert!(left.contains("return x;\n};\n"));
+		assert!(left.ends_with("\n\nexport let b = () => {  return   2  };\n"));
+	}
+}
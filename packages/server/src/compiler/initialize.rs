@@ -30,13 +30,36 @@ impl Compiler {
 					},
 				)),
 				hover_provider: Some(lsp::HoverProviderCapability::Simple(true)),
+				folding_range_provider: Some(lsp::FoldingRangeProviderCapability::Simple(true)),
+				signature_help_provider: Some(lsp::SignatureHelpOptions {
+					trigger_characters: Some(vec!["(".to_owned(), ",".to_owned()]),
+					retrigger_characters: None,
+					work_done_progress_options: lsp::WorkDoneProgressOptions::default(),
+				}),
 				completion_provider: Some(lsp::CompletionOptions::default()),
+				code_action_provider: Some(lsp::CodeActionProviderCapability::Options(
+					lsp::CodeActionOptions {
+						code_action_kinds: Some(vec![lsp::CodeActionKind::QUICKFIX]),
+						..Default::default()
+					},
+				)),
 				definition_provider: Some(lsp::OneOf::Left(true)),
 				type_definition_provider: Some(lsp::TypeDefinitionProviderCapability::Simple(true)),
 				references_provider: Some(lsp::OneOf::Left(true)),
 				document_formatting_provider: Some(lsp::OneOf::Left(true)),
+				document_range_formatting_provider: Some(lsp::OneOf::Left(true)),
 				document_symbol_provider: Some(lsp::OneOf::Left(true)),
 				rename_provider: Some(lsp::OneOf::Left(true)),
+				semantic_tokens_provider: Some(
+					lsp::SemanticTokensServerCapabilities::SemanticTokensOptions(
+						lsp::SemanticTokensOptions {
+							legend: Self::semantic_tokens_legend(),
+							full: Some(lsp::SemanticTokensFullOptions::Bool(true)),
+							..Default::default()
+						},
+					),
+				),
+				workspace_symbol_provider: Some(lsp::OneOf::Left(true)),
 				workspace: Some(lsp::WorkspaceServerCapabilities {
 					workspace_folders: Some(lsp::WorkspaceFoldersServerCapabilities {
 						supported: Some(true),
@@ -160,10 +160,13 @@ where
 				cache: false,
 				destructive: false,
 				deterministic: false,
+				follow_symlinks: None,
+				hash_only: false,
 				ignore: true,
 				locked: false,
 				lockfile: true,
 				path: temp.path().to_owned(),
+				strategy: None,
 			},
 		)
 		.await
@@ -217,10 +220,13 @@ where
 				cache: false,
 				destructive: false,
 				deterministic: false,
+				follow_symlinks: None,
+				hash_only: false,
 				ignore: true,
 				locked: false,
 				lockfile: true,
 				path: directory.path().to_owned(),
+				strategy: None,
 			},
 		)
 		.await
@@ -0,0 +1,151 @@
+use super::Compiler;
+use lsp_types as lsp;
+use tangram_client as tg;
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+	pub module: tg::Module,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+	pub tokens: Vec<Token>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Token {
+	pub range: tg::Range,
+	#[serde(rename = "type")]
+	pub type_: Type,
+	pub modifiers: Vec<Modifier>,
+}
+
+/// A token type. The order of the variants is the order of the token types in the legend.
+#[derive(Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Type {
+	Class,
+	Enum,
+	Interface,
+	Namespace,
+	TypeParameter,
+	Type,
+	Parameter,
+	Variable,
+	EnumMember,
+	Property,
+	Function,
+	Method,
+	Module,
+	Template,
+}
+
+/// A token modifier. The order of the variants is the order of the token modifiers in the legend.
+#[derive(Clone, Copy, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Modifier {
+	Declaration,
+	Static,
+	Async,
+	Readonly,
+	DefaultLibrary,
+	Local,
+}
+
+impl Compiler {
+	/// Get the legend for the semantic tokens. Module specifiers and the literal text of templates have their own token types, so that they can be highlighted differently from other strings.
+	pub(super) fn semantic_tokens_legend() -> lsp::SemanticTokensLegend {
+		let token_types = vec![
+			lsp::SemanticTokenType::CLASS,
+			lsp::SemanticTokenType::ENUM,
+			lsp::SemanticTokenType::INTERFACE,
+			lsp::SemanticTokenType::NAMESPACE,
+			lsp::SemanticTokenType::TYPE_PARAMETER,
+			lsp::SemanticTokenType::TYPE,
+			lsp::SemanticTokenType::PARAMETER,
+			lsp::SemanticTokenType::VARIABLE,
+			lsp::SemanticTokenType::ENUM_MEMBER,
+			lsp::SemanticTokenType::PROPERTY,
+			lsp::SemanticTokenType::FUNCTION,
+			lsp::SemanticTokenType::METHOD,
+			lsp::SemanticTokenType::new("module"),
+			lsp::SemanticTokenType::new("template"),
+		];
+		let token_modifiers = vec![
+			lsp::SemanticTokenModifier::DECLARATION,
+			lsp::SemanticTokenModifier::STATIC,
+			lsp::SemanticTokenModifier::ASYNC,
+			lsp::SemanticTokenModifier::READONLY,
+			lsp::SemanticTokenModifier::DEFAULT_LIBRARY,
+			lsp::SemanticTokenModifier::new("local"),
+		];
+		lsp::SemanticTokensLegend {
+			token_types,
+			token_modifiers,
+		}
+	}
+
+	pub async fn semantic_tokens(&self, module: &tg::Module) -> tg::Result<Vec<Token>> {
+		// Create the request.
+		let request = super::Request::SemanticTokens(Request {
+			module: module.clone(),
+		});
+
+		// Perform the request.
+		let response = self.request(request).await?.unwrap_semantic_tokens();
+
+		Ok(response.tokens)
+	}
+}
+
+impl Compiler {
+	pub(super) async fn handle_semantic_tokens_full_request(
+		&self,
+		params: lsp::SemanticTokensParams,
+	) -> tg::Result<Option<lsp::SemanticTokensResult>> {
+		// Get the module.
+		let module = self.module_for_lsp_uri(&params.text_document.uri).await?;
+
+		// Get the tokens.
+		let tokens = self.semantic_tokens(&module).await?;
+
+		// Encode the tokens. Each token's position is relative to the previous token's.
+		let mut data = Vec::with_capacity(tokens.len());
+		let mut previous = tg::Position {
+			line: 0,
+			character: 0,
+		};
+		for token in tokens {
+			let start = token.range.start;
+			let delta_line = start.line - previous.line;
+			let delta_start = if delta_line == 0 {
+				start.character - previous.character
+			} else {
+				start.character
+			};
+			let length = token.range.end.character.saturating_sub(start.character);
+			let token_modifiers_bitset = token
+				.modifiers
+				.iter()
+				.fold(0, |bitset, modifier| bitset | (1 << *modifier as u32));
+			data.push(lsp::SemanticToken {
+				delta_line,
+				delta_start,
+				length,
+				token_type: token.type_ as u32,
+				token_modifiers_bitset,
+			});
+			previous = start;
+		}
+
+		let tokens = lsp::SemanticTokens {
+			result_id: None,
+			data,
+		};
+
+		Ok(Some(lsp::SemanticTokensResult::Tokens(tokens)))
+	}
+}
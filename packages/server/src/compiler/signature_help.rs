@@ -0,0 +1,107 @@
+use super::Compiler;
+use lsp_types as lsp;
+use tangram_client as tg;
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+	pub module: tg::Module,
+	pub position: tg::Position,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+	pub signature_help: Option<SignatureHelp>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureHelp {
+	pub signatures: Vec<Signature>,
+	pub active_signature: u32,
+	pub active_parameter: u32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Signature {
+	pub label: String,
+	pub documentation: Option<String>,
+	pub parameters: Vec<Parameter>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameter {
+	pub label: String,
+	pub documentation: Option<String>,
+}
+
+impl Compiler {
+	pub(super) async fn handle_signature_help_request(
+		&self,
+		params: lsp::SignatureHelpParams,
+	) -> tg::Result<Option<lsp::SignatureHelp>> {
+		// Get the module.
+		let module = self
+			.module_for_lsp_uri(&params.text_document_position_params.text_document.uri)
+			.await?;
+
+		// Get the position for the request.
+		let position = params.text_document_position_params.position;
+
+		// Get the signature help.
+		let signature_help = self.signature_help(&module, position.into()).await?;
+		let Some(signature_help) = signature_help else {
+			return Ok(None);
+		};
+
+		// Convert the signature help.
+		let signatures = signature_help
+			.signatures
+			.into_iter()
+			.map(|signature| {
+				let parameters = signature
+					.parameters
+					.into_iter()
+					.map(|parameter| lsp::ParameterInformation {
+						label: lsp::ParameterLabel::Simple(parameter.label),
+						documentation: parameter.documentation.map(lsp::Documentation::String),
+					})
+					.collect();
+				lsp::SignatureInformation {
+					label: signature.label,
+					documentation: signature.documentation.map(lsp::Documentation::String),
+					parameters: Some(parameters),
+					active_parameter: None,
+				}
+			})
+			.collect();
+		let signature_help = lsp::SignatureHelp {
+			signatures,
+			active_signature: Some(signature_help.active_signature),
+			active_parameter: Some(signature_help.active_parameter),
+		};
+
+		Ok(Some(signature_help))
+	}
+
+	/// Get the signatures of the call at a position in a module, along with the active signature and parameter.
+	pub async fn signature_help(
+		&self,
+		module: &tg::Module,
+		position: tg::Position,
+	) -> tg::Result<Option<SignatureHelp>> {
+		// Create the request.
+		let request = super::Request::SignatureHelp(Request {
+			module: module.clone(),
+			position,
+		});
+
+		// Perform the request.
+		let response = self.request(request).await?.unwrap_signature_help();
+
+		Ok(response.signature_help)
+	}
+}
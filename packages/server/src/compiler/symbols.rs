@@ -1,5 +1,6 @@
 use super::Compiler;
 use lsp_types as lsp;
+use std::time::SystemTime;
 use tangram_client as tg;
 
 #[derive(Debug, serde::Serialize)]
@@ -79,6 +80,45 @@ impl Compiler {
 
 		Ok(response.symbols)
 	}
+
+	/// Search the symbols of all modules in the workspaces for names that fuzzy match the query.
+	pub async fn workspace_symbols(&self, query: &str) -> tg::Result<Vec<lsp::WorkspaceSymbol>> {
+		let mut output = Vec::new();
+		for module in self.list_workspace_modules().await? {
+			// Get the module's symbols from the cache, or compute them if they are not cached or the module's file has changed on disk.
+			let modified = module_modified(&module).await;
+			let cached = self
+				.symbols
+				.get(&module)
+				.filter(|entry| entry.0 == modified)
+				.map(|entry| entry.1.clone());
+			let symbols = if let Some(symbols) = cached {
+				symbols
+			} else {
+				let uri = self.lsp_uri_for_module(&module).await?;
+				let symbols = self
+					.symbols(&module)
+					.await?
+					.into_iter()
+					.flatten()
+					.map(collect_symbol_tree)
+					.fold(Vec::new(), |mut symbols, symbol| {
+						collect_workspace_symbols(&uri, None, symbol, &mut symbols);
+						symbols
+					});
+				self.symbols.insert(module, (modified, symbols.clone()));
+				symbols
+			};
+
+			// Add the symbols that match the query.
+			output.extend(
+				symbols
+					.into_iter()
+					.filter(|symbol| fuzzy_match(query, &symbol.name)),
+			);
+		}
+		Ok(output)
+	}
 }
 
 fn collect_symbol_tree(symbol: Symbol) -> lsp::DocumentSymbol {
@@ -164,6 +204,50 @@ fn collect_symbol_tree(symbol: Symbol) -> lsp::DocumentSymbol {
 	}
 }
 
+fn collect_workspace_symbols(
+	uri: &lsp::Uri,
+	container_name: Option<&str>,
+	symbol: lsp::DocumentSymbol,
+	output: &mut Vec<lsp::WorkspaceSymbol>,
+) {
+	let location = lsp::Location {
+		uri: uri.clone(),
+		range: symbol.selection_range,
+	};
+	output.push(lsp::WorkspaceSymbol {
+		name: symbol.name.clone(),
+		kind: symbol.kind,
+		tags: symbol.tags,
+		container_name: container_name.map(ToOwned::to_owned),
+		location: lsp::OneOf::Left(location),
+		data: None,
+	});
+	for child in symbol.children.into_iter().flatten() {
+		collect_workspace_symbols(uri, Some(&symbol.name), child, output);
+	}
+}
+
+/// Get the modified time of a path module's file. Object modules cannot change, so they have none.
+async fn module_modified(module: &tg::Module) -> Option<SystemTime> {
+	let tg::module::Item::Path(path) = &module.referent.item else {
+		return None;
+	};
+	let path = match &module.referent.subpath {
+		Some(subpath) => path.join(subpath),
+		None => path.clone(),
+	};
+	tokio::fs::metadata(&path).await.ok()?.modified().ok()
+}
+
+/// Check whether the characters of the query appear in order in the name, ignoring case.
+fn fuzzy_match(query: &str, name: &str) -> bool {
+	let mut name = name.chars().flat_map(char::to_lowercase);
+	query
+		.chars()
+		.flat_map(char::to_lowercase)
+		.all(|c| name.any(|n| n == c))
+}
+
 impl Compiler {
 	pub(super) async fn handle_symbols_request(
 		&self,
@@ -184,3 +268,15 @@ impl Compiler {
 		Ok(Some(lsp::DocumentSymbolResponse::Nested(symbols)))
 	}
 }
+
+impl Compiler {
+	pub(super) async fn handle_workspace_symbols_request(
+		&self,
+		params: lsp::WorkspaceSymbolParams,
+	) -> tg::Result<Option<lsp::WorkspaceSymbolResponse>> {
+		// Get the workspace symbols.
+		let symbols = self.workspace_symbols(&params.query).await?;
+
+		Ok(Some(lsp::WorkspaceSymbolResponse::Nested(symbols)))
+	}
+}
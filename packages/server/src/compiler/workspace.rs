@@ -1,6 +1,6 @@
 use super::Compiler;
 use lsp_types as lsp;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tangram_client as tg;
 
 impl Compiler {
@@ -18,7 +18,7 @@ impl Compiler {
 				"file" => PathBuf::from(uri.path().as_str()),
 				scheme => return Err(tg::error!(%scheme, "invalid URI for workspace folder")),
 			};
-			workspaces.insert(path);
+			workspaces.insert(path, None);
 		}
 
 		// Remove the specified workspaces.
@@ -32,6 +32,89 @@ impl Compiler {
 
 		Ok(())
 	}
+
+	/// List the modules in the workspaces. Each workspace's modules are listed once and cached until a document in it is saved.
+	pub(crate) async fn list_workspace_modules(&self) -> tg::Result<Vec<tg::Module>> {
+		let workspaces = self.workspaces.read().await.clone();
+		let mut modules = Vec::new();
+		for (path, cached) in workspaces {
+			if let Some(cached) = cached {
+				modules.extend(cached);
+				continue;
+			}
+			let workspace_modules = self.list_modules_in_workspace(&path).await?;
+			if let Some(cached) = self.workspaces.write().await.get_mut(&path) {
+				cached.replace(workspace_modules.clone());
+			}
+			modules.extend(workspace_modules);
+		}
+		Ok(modules)
+	}
+
+	/// Clear the cached modules of the workspaces that contain the path, so that they are listed again when they are next needed.
+	pub(crate) async fn invalidate_workspace_modules(&self, path: &Path) {
+		for (workspace, cached) in self.workspaces.write().await.iter_mut() {
+			if path.starts_with(workspace) {
+				cached.take();
+			}
+		}
+	}
+
+	/// List the modules in a workspace. Hidden and ignored paths, and modules that are not in a package with a lockfile, are skipped.
+	async fn list_modules_in_workspace(&self, path: &Path) -> tg::Result<Vec<tg::Module>> {
+		// Create the ignore matcher.
+		let ignore = self.server.ignore_matcher_for_checkin().await?;
+
+		// Collect the module paths.
+		let mut paths = Vec::new();
+		let mut stack = vec![path.to_owned()];
+		while let Some(path) = stack.pop() {
+			let mut entries = tokio::fs::read_dir(&path).await.map_err(
+				|source| tg::error!(!source, %path = path.display(), "failed to read the directory"),
+			)?;
+			while let Some(entry) = entries
+				.next_entry()
+				.await
+				.map_err(|source| tg::error!(!source, "failed to get the directory entry"))?
+			{
+				let name = entry.file_name();
+				let hidden = name.to_str().is_some_and(|name| name.starts_with('.'));
+				if hidden {
+					continue;
+				}
+				let file_type = entry
+					.file_type()
+					.await
+					.map_err(|source| tg::error!(!source, "failed to get the file type"))?;
+				let path = entry.path();
+				let is_directory = file_type.is_dir();
+				if ignore
+					.matches(&path, Some(is_directory))
+					.await
+					.map_err(|source| {
+						tg::error!(!source, "failed to check if the path should be ignored")
+					})? {
+					continue;
+				}
+				if is_directory {
+					stack.push(path);
+				} else if file_type.is_file() && tg::package::is_module_path(&path) {
+					paths.push(path);
+				}
+			}
+		}
+		paths.sort();
+
+		// Get the modules.
+		let mut modules = Vec::new();
+		for path in paths {
+			if let Ok(module) = self.server.module_for_path(&path).await {
+				modules.push(module);
+			}
+		}
+
+		Ok(modules)
+	}
 }
 
 impl Compiler {
@@ -62,3 +145,97 @@ impl Compiler {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{compiler::Compiler, util::fs::cleanup, Config, Server};
+	use futures::FutureExt as _;
+	use lsp_types as lsp;
+	use std::{panic::AssertUnwindSafe, path::PathBuf};
+	use tangram_client as tg;
+	use tangram_temp::{self as temp, Temp};
+
+	#[tokio::test]
+	async fn list_workspace_modules() -> tg::Result<()> {
+		let temp = Temp::new();
+		let config = Config::with_path(temp.path().to_owned());
+		let server = Server::start(config).await?;
+		let compiler = Compiler::new(&server, tokio::runtime::Handle::current());
+		let result = AssertUnwindSafe(async {
+			// Create a package with an ignored directory and a hidden directory.
+			let workspace = Temp::new();
+			let artifact = temp::directory! {
+				".gitignore" => "node_modules\n",
+				".hidden" => temp::directory! {
+					"hidden.tg.ts" => "",
+				},
+				"foo.tg.ts" => "",
+				"node_modules" => temp::directory! {
+					"ignored.tg.ts" => "",
+				},
+				"tangram.ts" => r#"import * as foo from "./foo.tg.ts""#,
+			};
+			artifact
+				.to_path(workspace.as_ref())
+				.await
+				.map_err(|source| tg::error!(!source, "failed to write the artifact"))?;
+			let arg = tg::artifact::checkin::Arg {
+				cache: false,
+				destructive: false,
+				deterministic: false,
+				follow_symlinks: None,
+				hash_only: false,
+				ignore: true,
+				locked: false,
+				lockfile: true,
+				path: workspace.path().to_owned(),
+				strategy: None,
+			};
+			tg::Artifact::check_in(&server, arg).await?;
+
+			// Add the workspace.
+			let uri = format!("file://{}", workspace.path().display())
+				.parse::<lsp::Uri>()
+				.unwrap();
+			compiler.update_workspaces(vec![uri], Vec::new()).await?;
+
+			// List the modules.
+			let subpaths = |modules: Vec<tg::Module>| {
+				modules
+					.into_iter()
+					.map(|module| module.referent.subpath.unwrap())
+					.collect::<Vec<_>>()
+			};
+			let modules = compiler.list_workspace_modules().await?;
+			assert_eq!(
+				subpaths(modules),
+				[PathBuf::from("foo.tg.ts"), PathBuf::from("tangram.ts")]
+			);
+
+			// Add a module. It is not listed until the workspace's modules are invalidated.
+			tokio::fs::write(workspace.path().join("bar.tg.ts"), "")
+				.await
+				.map_err(|source| tg::error!(!source, "failed to write the module"))?;
+			let modules = compiler.list_workspace_modules().await?;
+			assert_eq!(modules.len(), 2);
+			compiler
+				.invalidate_workspace_modules(workspace.path())
+				.await;
+			let modules = compiler.list_workspace_modules().await?;
+			assert_eq!(
+				subpaths(modules),
+				[
+					PathBuf::from("bar.tg.ts"),
+					PathBuf::from("foo.tg.ts"),
+					PathBuf::from("tangram.ts")
+				]
+			);
+
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+}
@@ -1,8 +1,10 @@
 use std::{
+	collections::BTreeMap,
 	path::{Path, PathBuf},
 	time::Duration,
 };
 use tangram_client as tg;
+use tangram_database as db;
 use url::Url;
 
 #[derive(Clone, Debug)]
@@ -28,6 +30,7 @@ pub struct Advanced {
 	pub error_trace_options: tg::error::TraceOptions,
 	pub file_descriptor_semaphore_size: usize,
 	pub preserve_temp_directories: bool,
+	pub tag_batch_size: usize,
 	pub write_blobs_to_blobs_directory: bool,
 	pub write_build_logs_to_database: bool,
 	pub write_build_logs_to_stderr: bool,
@@ -36,6 +39,7 @@ pub struct Advanced {
 #[derive(Clone, Debug, Default)]
 pub struct Authentication {
 	pub providers: AuthenticationProviders,
+	pub token_ttl: Option<Duration>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -58,12 +62,19 @@ pub struct Build {
 	pub heartbeat_interval: Duration,
 	pub max_depth: u64,
 	pub remotes: Vec<String>,
+	pub runtimes: BTreeMap<String, Runtime>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Runtime {
+	pub concurrency: Option<usize>,
 }
 
 #[derive(Clone, Debug)]
 pub struct BuildHeartbeatMonitor {
 	pub interval: Duration,
 	pub limit: usize,
+	pub max_missed_heartbeats: Option<u32>,
 	pub timeout: Duration,
 }
 
@@ -79,6 +90,18 @@ pub enum Database {
 #[derive(Clone, Debug)]
 pub struct PostgresDatabase {
 	pub connections: usize,
+
+	/// The URL of a read replica. If this is set, then read connections use the replica and write connections use the primary. Otherwise, all connections use the primary.
+	///
+	/// Reads from the replica may lag behind writes to the primary, so a read that immediately follows a write may not observe it.
+	pub read_url: Option<Url>,
+
+	/// The maximum number of prepared statements to cache per connection.
+	pub statement_cache_size: usize,
+
+	/// The maximum duration of each statement. A statement that exceeds it is interrupted and fails. If this is not set, then statements do not time out.
+	pub timeout: Option<Duration>,
+
 	pub url: Url,
 }
 
@@ -86,6 +109,12 @@ pub struct PostgresDatabase {
 pub struct SqliteDatabase {
 	pub connections: usize,
 	pub path: PathBuf,
+
+	/// The maximum number of prepared statements to cache per connection.
+	pub statement_cache_size: usize,
+
+	/// The maximum duration of each statement. A statement that exceeds it is interrupted and fails. If this is not set, then statements do not time out.
+	pub timeout: Option<Duration>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -111,6 +140,9 @@ pub struct Vfs {
 	pub cache_ttl: Duration,
 	pub cache_size: usize,
 	pub database_connections: usize,
+
+	/// Whether to mount the VFS read-only. This defaults to `true`, which changes the mount options from prior versions, which mounted the VFS read-write. Writes are always rejected with `EROFS`, but a read-only mount lets the kernel reject them without a round trip to the server.
+	pub read_only: bool,
 }
 
 impl Config {
@@ -161,6 +193,7 @@ impl Default for Advanced {
 			},
 			file_descriptor_semaphore_size: 1_000_000_000,
 			preserve_temp_directories: false,
+			tag_batch_size: 1000,
 			write_blobs_to_blobs_directory: true,
 			write_build_logs_to_database: false,
 			write_build_logs_to_stderr: false,
@@ -176,6 +209,7 @@ impl Default for Build {
 			heartbeat_interval: Duration::from_secs(1),
 			max_depth: 4096,
 			remotes: Vec::new(),
+			runtimes: BTreeMap::new(),
 		}
 	}
 }
@@ -185,6 +219,7 @@ impl Default for BuildHeartbeatMonitor {
 		Self {
 			interval: Duration::from_secs(1),
 			limit: 100,
+			max_missed_heartbeats: None,
 			timeout: Duration::from_secs(60),
 		}
 	}
@@ -197,12 +232,19 @@ impl SqliteDatabase {
 		Self {
 			connections: n.into(),
 			path,
+			statement_cache_size: db::DEFAULT_STATEMENT_CACHE_SIZE,
+			timeout: None,
 		}
 	}
 
 	#[must_use]
 	pub fn with_path_and_connections(path: PathBuf, connections: usize) -> Self {
-		Self { connections, path }
+		Self {
+			connections,
+			path,
+			statement_cache_size: db::DEFAULT_STATEMENT_CACHE_SIZE,
+			timeout: None,
+		}
 	}
 }
 
@@ -211,6 +253,9 @@ impl Default for PostgresDatabase {
 		let n = std::thread::available_parallelism().unwrap();
 		Self {
 			connections: n.into(),
+			read_url: None,
+			statement_cache_size: db::DEFAULT_STATEMENT_CACHE_SIZE,
+			timeout: None,
 			url: "postgres://localhost:5432".parse().unwrap(),
 		}
 	}
@@ -238,6 +283,7 @@ impl Default for Vfs {
 			cache_ttl: Duration::from_secs(3600),
 			cache_size: 4096,
 			database_connections: 4,
+			read_only: true,
 		}
 	}
 }
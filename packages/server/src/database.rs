@@ -24,7 +24,12 @@ pub async fn migrate(database: &Database) -> tg::Result<()> {
 		return Ok(());
 	}
 
-	let migrations = vec![migration_0000(database).boxed()];
+	let migrations = vec![
+		migration_0000(database).boxed(),
+		migration_0001(database).boxed(),
+		migration_0002(database).boxed(),
+		migration_0003(database).boxed(),
+	];
 
 	let version = match database {
 		Either::Left(database) => {
@@ -234,3 +239,77 @@ async fn migration_0000(database: &Database) -> tg::Result<()> {
 		.await?;
 	Ok(())
 }
+
+async fn migration_0001(database: &Database) -> tg::Result<()> {
+	let sql = indoc!(
+		r"
+			create table build_labels (
+				build text not null,
+				key text not null,
+				value text not null
+			);
+
+			create unique index build_labels_index on build_labels (build, key);
+
+			create index build_labels_key_value_index on build_labels (key, value, build);
+		"
+	);
+	let database = database.as_ref().unwrap_left();
+	let connection = database
+		.write_connection()
+		.await
+		.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
+	connection
+		.with(move |connection| {
+			connection
+				.execute_batch(sql)
+				.map_err(|source| tg::error!(!source, "failed to execute the statements"))?;
+			Ok::<_, tg::Error>(())
+		})
+		.await?;
+	Ok(())
+}
+
+async fn migration_0002(database: &Database) -> tg::Result<()> {
+	let sql = indoc!(
+		r"
+			alter table tokens add column expires_at text;
+		"
+	);
+	let database = database.as_ref().unwrap_left();
+	let connection = database
+		.write_connection()
+		.await
+		.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
+	connection
+		.with(move |connection| {
+			connection
+				.execute_batch(sql)
+				.map_err(|source| tg::error!(!source, "failed to execute the statements"))?;
+			Ok::<_, tg::Error>(())
+		})
+		.await?;
+	Ok(())
+}
+
+async fn migration_0003(database: &Database) -> tg::Result<()> {
+	let sql = indoc!(
+		r"
+			alter table tags add column created_at text;
+		"
+	);
+	let database = database.as_ref().unwrap_left();
+	let connection = database
+		.write_connection()
+		.await
+		.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
+	connection
+		.with(move |connection| {
+			connection
+				.execute_batch(sql)
+				.map_err(|source| tg::error!(!source, "failed to execute the statements"))?;
+			Ok::<_, tg::Error>(())
+		})
+		.await?;
+	Ok(())
+}
@@ -7,6 +7,71 @@ use tangram_http::{outgoing::response::Ext as _, Incoming, Outgoing};
 
 impl Server {
 	pub async fn health(&self) -> tg::Result<tg::Health> {
+		let mut errors = Vec::new();
+
+		// Get the build health.
+		let builds = self
+			.builds_health()
+			.await
+			.inspect_err(|error| {
+				errors.push(tg::health::Error {
+					subsystem: "database".to_owned(),
+					message: error.to_string(),
+				});
+			})
+			.ok();
+
+		// Get the database health.
+		let available_connections = match &self.database {
+			Either::Left(database) => {
+				database.read_pool().available().to_u64().unwrap()
+					+ database.write_pool().available().to_u64().unwrap()
+			},
+			Either::Right(database) => database.pool().available().to_u64().unwrap(),
+		};
+		let database = builds.is_some().then_some(tg::health::Database {
+			available_connections,
+		});
+
+		// Check that the messenger is connected.
+		if let Either::Right(messenger) = &self.messenger {
+			if !messenger.is_connected() {
+				errors.push(tg::health::Error {
+					subsystem: "messenger".to_owned(),
+					message: "the messenger is not connected".to_owned(),
+				});
+			}
+		}
+
+		// Check that the VFS is running if it is enabled.
+		if self.config.vfs.is_some() && self.vfs.lock().unwrap().is_none() {
+			errors.push(tg::health::Error {
+				subsystem: "vfs".to_owned(),
+				message: "the VFS failed to start".to_owned(),
+			});
+		}
+
+		let file_descriptor_semaphore = tg::health::FileDescriptorSemaphore {
+			available_permits: self
+				.file_descriptor_semaphore
+				.available_permits()
+				.to_u64()
+				.unwrap(),
+		};
+
+		let health = tg::Health {
+			builds,
+			database,
+			errors,
+			file_descriptor_semaphore: Some(file_descriptor_semaphore),
+			objects: Some(self.object_stats.get()),
+			version: self.config.version.clone(),
+		};
+
+		Ok(health)
+	}
+
+	async fn builds_health(&self) -> tg::Result<tg::health::Builds> {
 		// Get a database connection.
 		let connection = self
 			.database
@@ -43,36 +108,7 @@ impl Server {
 			started,
 		};
 
-		// Drop the database connection.
-		drop(connection);
-
-		let available_connections = match &self.database {
-			Either::Left(database) => {
-				database.read_pool().available().to_u64().unwrap()
-					+ database.write_pool().available().to_u64().unwrap()
-			},
-			Either::Right(database) => database.pool().available().to_u64().unwrap(),
-		};
-		let database = tg::health::Database {
-			available_connections,
-		};
-
-		let file_descriptor_semaphore = tg::health::FileDescriptorSemaphore {
-			available_permits: self
-				.file_descriptor_semaphore
-				.available_permits()
-				.to_u64()
-				.unwrap(),
-		};
-
-		let health = tg::Health {
-			builds: Some(builds),
-			database: Some(database),
-			file_descriptor_semaphore: Some(file_descriptor_semaphore),
-			version: self.config.version.clone(),
-		};
-
-		Ok(health)
+		Ok(builds)
 	}
 }
 
@@ -125,6 +161,14 @@ mod tests {
      "file_descriptor_semaphore": {
        "available_permits": 1
      },
+     "objects": {
+       "bytes_read": 0,
+       "bytes_written": 0,
+       "get_hits": 0,
+       "get_misses": 0,
+       "gets": 0,
+       "puts": 0
+     },
      "version": null
    }
    "#);
@@ -71,8 +71,10 @@ pub struct Inner {
 	local_pool_handle: tokio_util::task::LocalPoolHandle,
 	lock_file: Mutex<Option<tokio::fs::File>>,
 	messenger: Messenger,
+	object_stats: self::object::stats::Stats,
 	path: PathBuf,
 	remotes: DashMap<String, tg::Client, fnv::FnvBuildHasher>,
+	runtime_semaphores: RuntimeSemaphores,
 	runtimes: RwLock<HashMap<String, Runtime>>,
 	task: Mutex<Option<Task<()>>>,
 	temp_paths: DashSet<PathBuf, fnv::FnvBuildHasher>,
@@ -87,6 +89,8 @@ struct BuildPermit(
 	Either<tokio::sync::OwnedSemaphorePermit, tokio::sync::OwnedMutexGuard<Option<Self>>>,
 );
 
+type RuntimeSemaphores = HashMap<String, Arc<tokio::sync::Semaphore>>;
+
 type BuildTaskMap = TaskMap<tg::build::Id, (), fnv::FnvBuildHasher>;
 
 type ArtifactCacheTaskMap =
@@ -188,6 +192,24 @@ impl Server {
 			.unwrap_or_default();
 		let build_semaphore = Arc::new(tokio::sync::Semaphore::new(permits));
 
+		// Create the runtime semaphores.
+		let runtime_semaphores = config
+			.build
+			.iter()
+			.flat_map(|build| &build.runtimes)
+			.filter_map(|(host, runtime)| {
+				let permits = runtime.concurrency?;
+				if permits == 0 {
+					return Some(Err(tg::error!(
+						%host,
+						"the runtime's concurrency must be greater than zero"
+					)));
+				}
+				let semaphore = Arc::new(tokio::sync::Semaphore::new(permits));
+				Some(Ok((host.clone(), semaphore)))
+			})
+			.collect::<tg::Result<_>>()?;
+
 		// Create the build tasks.
 		let builds = TaskMap::default();
 
@@ -212,6 +234,8 @@ impl Server {
 					connections: options.connections,
 					initialize,
 					path: path.join("database"),
+					statement_cache_size: options.statement_cache_size,
+					timeout: options.timeout,
 				};
 				let database = db::sqlite::Database::new(options)
 					.await
@@ -222,6 +246,9 @@ impl Server {
 				let options = db::postgres::DatabaseOptions {
 					url: options.url.clone(),
 					connections: options.connections,
+					read_url: options.read_url.clone(),
+					statement_cache_size: options.statement_cache_size,
+					timeout: options.timeout,
 				};
 				let database = db::postgres::Database::new(options)
 					.await
@@ -267,6 +294,9 @@ impl Server {
 		// Create the vfs.
 		let vfs = Mutex::new(None);
 
+		// Create the object stats.
+		let object_stats = self::object::stats::Stats::default();
+
 		// Create the server.
 		let server = Self(Arc::new(Inner {
 			artifact_cache_task_map,
@@ -280,8 +310,10 @@ impl Server {
 			local_pool_handle,
 			lock_file,
 			messenger,
+			object_stats,
 			path,
 			remotes,
+			runtime_semaphores,
 			runtimes,
 			task,
 			temp_paths,
@@ -824,6 +856,9 @@ impl Server {
 			(http::Method::GET, ["blobs", blob, "read"]) => {
 				Self::handle_read_blob_request(handle, request, blob).boxed()
 			},
+			(http::Method::GET, ["blobs", blob, "entries"]) => {
+				Self::handle_list_archive_entries_request(handle, request, blob).boxed()
+			},
 
 			// Builds.
 			(http::Method::GET, ["builds", build]) => {
@@ -856,6 +891,12 @@ impl Server {
 			(http::Method::POST, ["builds", build, "log"]) => {
 				Self::handle_add_build_log_request(handle, request, build).boxed()
 			},
+			(http::Method::GET, ["builds", build, "progress"]) => {
+				Self::handle_get_build_progress_request(handle, request, build).boxed()
+			},
+			(http::Method::POST, ["builds", build, "progress"]) => {
+				Self::handle_add_build_progress_request(handle, request, build).boxed()
+			},
 			(http::Method::GET, ["builds", build, "outcome"]) => {
 				Self::handle_get_build_outcome_request(handle, request, build).boxed()
 			},
@@ -873,6 +914,9 @@ impl Server {
 			(http::Method::POST, ["lsp"]) => Self::handle_lsp_request(handle, request).boxed(),
 
 			// Objects.
+			(http::Method::GET, ["objects"]) => {
+				Self::handle_find_objects_request(handle, request).boxed()
+			},
 			(http::Method::HEAD, ["objects", object]) => {
 				Self::handle_head_object_request(handle, request, object).boxed()
 			},
@@ -1045,6 +1089,14 @@ impl tg::Handle for Server {
 		self.try_read_blob_stream(id, arg)
 	}
 
+	fn list_archive_entries(
+		&self,
+		blob: &tg::blob::Id,
+		arg: tg::artifact::archive::list::Arg,
+	) -> impl Future<Output = tg::Result<tg::artifact::archive::list::Output>> {
+		self.list_archive_entries(blob, arg)
+	}
+
 	fn try_get_build(
 		&self,
 		id: &tg::build::Id,
@@ -1144,6 +1196,27 @@ impl tg::Handle for Server {
 		self.add_build_log(id, arg)
 	}
 
+	fn try_get_build_progress_stream(
+		&self,
+		id: &tg::build::Id,
+	) -> impl Future<
+		Output = tg::Result<
+			Option<
+				impl Stream<Item = tg::Result<tg::build::progress::get::Event>> + Send + 'static,
+			>,
+		>,
+	> {
+		self.try_get_build_progress_stream(id)
+	}
+
+	fn add_build_progress(
+		&self,
+		id: &tg::build::Id,
+		arg: tg::build::progress::post::Arg,
+	) -> impl Future<Output = tg::Result<()>> {
+		self.add_build_progress(id, arg)
+	}
+
 	fn try_get_build_outcome_future(
 		&self,
 		id: &tg::build::Id,
@@ -1187,6 +1260,13 @@ impl tg::Handle for Server {
 		self.lsp(input, output)
 	}
 
+	fn find_objects(
+		&self,
+		arg: tg::object::find::Arg,
+	) -> impl Future<Output = tg::Result<tg::object::find::Output>> {
+		self.find_objects(arg)
+	}
+
 	fn try_get_object_metadata(
 		&self,
 		id: &tg::object::Id,
@@ -1318,8 +1398,8 @@ impl tg::Handle for Server {
 		self.health()
 	}
 
-	fn clean(&self) -> impl Future<Output = tg::Result<()>> {
-		self.clean()
+	fn clean(&self, arg: tg::clean::Arg) -> impl Future<Output = tg::Result<tg::clean::Output>> {
+		self.clean(arg)
 	}
 
 	fn list_tags(
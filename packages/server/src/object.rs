@@ -1,6 +1,7 @@
-mod archive;
+pub(crate) mod archive;
 mod export;
 mod extract;
+mod find;
 mod get;
 mod import;
 mod index;
@@ -8,3 +9,4 @@ mod metadata;
 mod pull;
 mod push;
 mod put;
+pub(crate) mod stats;
@@ -12,9 +12,10 @@ pub const MAGIC_NUMBER: [u8; 4] = *b"tgar";
 pub const VERSION: u64 = 0;
 
 impl Server {
-	pub(crate) async fn archive_object(
+	/// Write the objects and their closures to an archive. The objects are written first in the order given, so the first is the archive's root.
+	pub(crate) async fn archive_objects(
 		&self,
-		id: &tg::object::Id,
+		ids: Vec<tg::object::Id>,
 		mut writer: impl AsyncWrite + Unpin + Send + 'static,
 	) -> tg::Result<()> {
 		// Pin the writer.
@@ -33,8 +34,10 @@ impl Server {
 			.map_err(|source| tg::error!(!source, "failed to write the version"))?;
 
 		// Write the objects.
-		let object = tg::Object::with_id(id.clone());
-		let mut queue = VecDeque::from([object]);
+		let mut queue = ids
+			.into_iter()
+			.map(tg::Object::with_id)
+			.collect::<VecDeque<_>>();
 		let mut visited = BTreeSet::new();
 		while let Some(object) = queue.pop_front() {
 			// Check if this object has already been written to the archive.
@@ -87,3 +90,76 @@ async fn write_varint(writer: &mut Pin<&mut impl AsyncWrite>, mut src: u64) -> t
 	}
 	Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{util::fs::cleanup, Config, Server};
+	use futures::{FutureExt as _, TryStreamExt as _};
+	use std::{collections::BTreeMap, panic::AssertUnwindSafe};
+	use tangram_client as tg;
+	use tangram_temp::Temp;
+	use tokio::io::AsyncReadExt as _;
+
+	#[tokio::test]
+	async fn symlink_with_artifact_round_trips() -> tg::Result<()> {
+		let temp = Temp::new();
+		let options = Config::with_path(temp.path().to_owned());
+		let server = Server::start(options).await?;
+		let result = AssertUnwindSafe(async {
+			// Create a directory that contains a symlink to an artifact with a subpath.
+			let file = tg::File::with_contents(tg::Blob::from("hello"));
+			let inner = tg::Directory::with_entries(BTreeMap::from([(
+				"hello.txt".to_owned(),
+				tg::Artifact::from(file),
+			)]));
+			let symlink = tg::Symlink::with_artifact_and_subpath(
+				inner.clone().into(),
+				Some("hello.txt".into()),
+			);
+			let directory = tg::Directory::with_entries(BTreeMap::from([
+				("inner".to_owned(), tg::Artifact::from(inner)),
+				("link".to_owned(), tg::Artifact::from(symlink.clone())),
+			]));
+			let id = directory.store(&server).await?;
+			let symlink_id: tg::object::Id = symlink.id(&server).await?.into();
+
+			// Archive the directory.
+			let arg = tg::object::export::Arg {
+				objects: Vec::new(),
+				remote: None,
+			};
+			let reader = server.export_object(&id.into(), arg).await?;
+			let mut reader = std::pin::pin!(reader);
+			let mut bytes = Vec::new();
+			reader
+				.read_to_end(&mut bytes)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to read the archive"))?;
+
+			// Extract the archive and verify that every object, including the symlink, is unchanged.
+			let objects = server
+				.extract_object(std::io::Cursor::new(bytes))
+				.await?
+				.try_collect::<Vec<_>>()
+				.await?;
+			for (id, bytes) in &objects {
+				assert_eq!(&tg::object::Id::new(id.kind(), bytes), id);
+			}
+			let (_, bytes) = objects
+				.iter()
+				.find(|(id, _)| id == &symlink_id)
+				.expect("the symlink was not archived");
+			let data = tg::symlink::Data::deserialize(bytes)?;
+			assert!(matches!(
+				data,
+				tg::symlink::Data::Artifact { subpath: Some(subpath), .. } if subpath.as_os_str() == "hello.txt"
+			));
+
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+}
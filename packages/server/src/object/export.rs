@@ -1,6 +1,6 @@
 use crate::Server;
-use std::pin::Pin;
 use tangram_client::{self as tg, Handle as _};
+use tangram_either::Either;
 use tangram_futures::read::Ext as _;
 use tangram_http::{incoming::request::Ext as _, outgoing::response::Ext as _, Incoming, Outgoing};
 use tokio::io::AsyncRead;
@@ -14,19 +14,21 @@ impl Server {
 	) -> tg::Result<impl AsyncRead + Send + 'static> {
 		if let Some(remote) = arg.remote.clone() {
 			let remote = self.get_remote_client(remote).await?;
-			let arg = tg::object::export::Arg { remote: None };
+			let arg = tg::object::export::Arg {
+				remote: None,
+				..arg
+			};
 			let reader = remote.export_object(id, arg).await?;
-			let reader = Box::pin(reader) as Pin<Box<dyn AsyncRead + Send + 'static>>;
-			return Ok(reader);
+			return Ok(Either::Left(reader));
 		}
 
 		let (writer, reader) = tokio::io::duplex(8192);
 		let task = tokio::spawn({
-			let id = id.clone();
+			let ids = std::iter::once(id.clone()).chain(arg.objects).collect();
 			let server = self.clone();
 			async move {
 				server
-					.archive_object(&id, writer)
+					.archive_objects(ids, writer)
 					.await
 					.inspect_err(|error| tracing::error!(?error, "an error occured while archving"))
 					.ok();
@@ -34,9 +36,9 @@ impl Server {
 		});
 		let abort_handle = AbortOnDropHandle::new(task);
 
-		let reader = reader.attach(abort_handle).boxed();
+		let reader = reader.attach(abort_handle);
 
-		Ok(reader)
+		Ok(Either::Right(reader))
 	}
 }
 
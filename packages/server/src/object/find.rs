@@ -0,0 +1,141 @@
+use crate::Server;
+use indoc::formatdoc;
+use tangram_client as tg;
+use tangram_database::{self as db, prelude::*};
+use tangram_http::{incoming::request::Ext as _, outgoing::response::Ext as _, Incoming, Outgoing};
+
+/// The number of objects returned if the arg does not specify a length.
+const DEFAULT_LENGTH: u64 = 100;
+
+impl Server {
+	/// Find objects in the database whose kind and size match the arg. Objects are ordered by ID. This scans the database's objects table, so objects that are only in a remote or have no stored bytes are not found.
+	pub async fn find_objects(
+		&self,
+		arg: tg::object::find::Arg,
+	) -> tg::Result<tg::object::find::Output> {
+		// Get a database connection.
+		let connection = self
+			.database
+			.connection()
+			.await
+			.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
+
+		// Create the statement.
+		let p = connection.p();
+		let mut conditions = vec!["bytes is not null".to_owned()];
+		let mut params = Vec::new();
+		if let Some(kind) = arg.kind {
+			params.push(db::Value::Text(format!("{kind}_")));
+			let n = params.len();
+			conditions.push(format!("id >= {p}{n} and id < {p}{n} || x'ff'"));
+		}
+		if let Some(min_size) = arg.min_size {
+			params.extend(db::params![min_size]);
+			let n = params.len();
+			conditions.push(format!("length(bytes) >= {p}{n}"));
+		}
+		if let Some(max_size) = arg.max_size {
+			params.extend(db::params![max_size]);
+			let n = params.len();
+			conditions.push(format!("length(bytes) <= {p}{n}"));
+		}
+		params.extend(db::params![
+			arg.length.unwrap_or(DEFAULT_LENGTH),
+			arg.position.unwrap_or_default()
+		]);
+		let length = params.len() - 1;
+		let position = params.len();
+		let conditions = conditions.join(" and ");
+		let statement = formatdoc!(
+			"
+				select id, length(bytes) as size
+				from objects
+				where {conditions}
+				order by id
+				limit {p}{length}
+				offset {p}{position};
+			"
+		);
+
+		// Execute the statement.
+		let data = connection
+			.query_all_into::<tg::object::find::Item>(statement, params)
+			.await
+			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+		// Drop the database connection.
+		drop(connection);
+
+		Ok(tg::object::find::Output { data })
+	}
+}
+
+impl Server {
+	pub(crate) async fn handle_find_objects_request<H>(
+		handle: &H,
+		request: http::Request<Incoming>,
+	) -> tg::Result<http::Response<Outgoing>>
+	where
+		H: tg::Handle,
+	{
+		let arg = request.query_params().transpose()?.unwrap_or_default();
+		let output = handle.find_objects(arg).await?;
+		let response = http::Response::builder().json(output).unwrap();
+		Ok(response)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{util::fs::cleanup, Config, Server};
+	use futures::FutureExt as _;
+	use std::panic::AssertUnwindSafe;
+	use tangram_client as tg;
+	use tangram_temp::Temp;
+
+	#[tokio::test]
+	async fn find_by_kind_and_size() -> tg::Result<()> {
+		let temp = Temp::new();
+		let config = Config::with_path(temp.path().to_owned());
+		let server = Server::start(config).await?;
+		let result = AssertUnwindSafe(async {
+			// Store a small file and a large blob.
+			let file = tg::file!("hello");
+			let file = file.id(&server).await?;
+			let blob = tg::Leaf::from(bytes::Bytes::from(vec![0u8; 4096]));
+			let blob = blob.id(&server).await?;
+
+			// Find the files.
+			let arg = tg::object::find::Arg {
+				kind: Some(tg::object::Kind::File),
+				..Default::default()
+			};
+			let output = server.find_objects(arg).await?;
+			let ids = output
+				.data
+				.into_iter()
+				.map(|item| item.id)
+				.collect::<Vec<_>>();
+			assert_eq!(ids, vec![tg::object::Id::from(file)]);
+
+			// Find the large objects.
+			let arg = tg::object::find::Arg {
+				min_size: Some(1024),
+				..Default::default()
+			};
+			let output = server.find_objects(arg).await?;
+			let ids = output
+				.data
+				.into_iter()
+				.map(|item| item.id)
+				.collect::<Vec<_>>();
+			assert_eq!(ids, vec![tg::object::Id::from(blob)]);
+
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+}
@@ -57,6 +57,7 @@ impl Server {
 			.await
 			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?
 		else {
+			self.object_stats.record_get(None);
 			return Ok(None);
 		};
 
@@ -79,8 +80,11 @@ impl Server {
 
 		// If the bytes were not found, then return None.
 		let Some(bytes) = bytes else {
+			self.object_stats.record_get(None);
 			return Ok(None);
 		};
+		self.object_stats
+			.record_get(Some(bytes.len().to_u64().unwrap()));
 
 		// Create the output.
 		let output = tg::object::get::Output {
@@ -200,6 +204,7 @@ impl Server {
 			return Ok(http::Response::builder().not_found().empty().unwrap());
 		};
 		let response = http::Response::builder()
+			.header(http::header::CONTENT_LENGTH, output.bytes.len())
 			.header_json(tg::object::metadata::HEADER, output.metadata)
 			.unwrap()
 			.bytes(output.bytes)
@@ -91,11 +91,13 @@ impl Server {
 			.map_err(|source| tg::error!(!source, "failed to begin a transaction"))?;
 
 		let mut object = None;
+		let mut count = 0;
 		let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
 		while let Some((id, bytes)) = stream.try_next().await? {
 			if object.is_none() {
 				object.replace(id.clone());
 			}
+			Self::validate_object(&id, &bytes)?;
 			let p = transaction.p();
 			let statement = formatdoc!(
 				"
@@ -109,6 +111,7 @@ impl Server {
 				.execute(statement, params)
 				.await
 				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+			count += 1;
 		}
 
 		// Commit the transaction.
@@ -119,7 +122,7 @@ impl Server {
 
 		let object = object.ok_or_else(|| tg::error!("the archive is empty"))?;
 
-		let output = tg::object::import::Output { object };
+		let output = tg::object::import::Output { object, count };
 
 		Ok(output)
 	}
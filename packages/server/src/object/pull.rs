@@ -10,7 +10,7 @@ impl Server {
 		arg: tg::object::pull::Arg,
 	) -> tg::Result<impl Stream<Item = tg::Result<tg::progress::Event<()>>> + Send + 'static> {
 		let remote = self.get_remote_client(arg.remote.clone()).await?;
-		Self::push_or_pull_object(&remote, self, object).await
+		Self::push_or_pull_object(&remote, self, object, arg.verbose).await
 	}
 }
 
@@ -26,13 +26,15 @@ impl Server {
 		arg: tg::object::push::Arg,
 	) -> tg::Result<impl Stream<Item = tg::Result<tg::progress::Event<()>>> + Send + 'static> {
 		let remote = self.get_remote_client(arg.remote.clone()).await?;
-		Self::push_or_pull_object(self, &remote, object).await
+		Self::push_or_pull_object(self, &remote, object, arg.verbose).await
 	}
 
+	/// Copy an object and its children from `src` to `dst`. If `verbose` is set, then an indicator is reported for each object while it is transferred.
 	pub(crate) async fn push_or_pull_object<S, D>(
 		src: &S,
 		dst: &D,
 		object: &tg::object::Id,
+		verbose: bool,
 	) -> tg::Result<
 		impl Stream<Item = tg::Result<tg::progress::Event<()>>> + Send + 'static + use<S, D>,
 	>
@@ -63,7 +65,8 @@ impl Server {
 					metadata.weight,
 				);
 				let result = AssertUnwindSafe(
-					Self::push_or_pull_object_inner(&src, &dst, &object, &progress).map_ok(|_| ()),
+					Self::push_or_pull_object_inner(&src, &dst, &object, verbose, &progress)
+						.map_ok(|_| ()),
 				)
 				.catch_unwind()
 				.await;
@@ -95,6 +98,7 @@ impl Server {
 		src: &impl tg::Handle,
 		dst: &impl tg::Handle,
 		object: &tg::object::Id,
+		verbose: bool,
 		progress: &crate::progress::Handle<()>,
 	) -> tg::Result<InnerOutput> {
 		// Get the object.
@@ -104,6 +108,17 @@ impl Server {
 			.map_err(|source| tg::error!(!source, %object, "failed to get the object"))?;
 		let size = bytes.len().to_u64().unwrap();
 
+		// Start the object's indicator with the object's size as its total if requested.
+		if verbose {
+			progress.start(
+				object.to_string(),
+				object.to_string(),
+				tg::progress::IndicatorFormat::Bytes,
+				Some(0),
+				Some(size),
+			);
+		}
+
 		// Put the object.
 		let arg = tg::object::put::Arg { bytes };
 		let output = dst
@@ -115,12 +130,18 @@ impl Server {
 		progress.increment("objects", 1);
 		progress.increment("bytes", size);
 
+		// Finish the object's indicator.
+		if verbose {
+			progress.increment(&object.to_string(), size);
+			progress.finish(&object.to_string());
+		}
+
 		// Recurse into the incomplete children.
 		let (incomplete_count, incomplete_depth, incomplete_weight) = output
 			.incomplete
 			.into_iter()
 			.map(|object| async move {
-				Self::push_or_pull_object_inner(src, dst, &object, progress).await
+				Self::push_or_pull_object_inner(src, dst, &object, verbose, progress).await
 			})
 			.collect::<FuturesUnordered<_>>()
 			.try_collect::<Vec<_>>()
@@ -1,5 +1,5 @@
 use crate::{util::fs::cleanup, Config, Server};
-use futures::FutureExt as _;
+use futures::{FutureExt as _, TryStreamExt as _};
 use std::{panic::AssertUnwindSafe, pin::pin};
 use tangram_client as tg;
 use tangram_futures::stream::TryExt as _;
@@ -43,6 +43,7 @@ async fn push_file() -> tg::Result<()> {
 
 		let arg = tg::object::push::Arg {
 			remote: "default".to_string(),
+			verbose: false,
 		};
 		let stream = server.push_object(&file.clone().into(), arg).await?;
 		pin!(stream)
@@ -109,6 +110,7 @@ async fn push_simple_directory() -> tg::Result<()> {
 
 		let arg = tg::object::push::Arg {
 			remote: "default".to_string(),
+			verbose: false,
 		};
 		let stream = server.push_object(&directory.clone().into(), arg).await?;
 		pin!(stream)
@@ -119,6 +121,7 @@ async fn push_simple_directory() -> tg::Result<()> {
 
 		let arg = tg::object::pull::Arg {
 			remote: "default".to_string(),
+			verbose: false,
 		};
 		let stream = other.pull_object(&directory.clone().into(), arg).await?;
 		pin!(stream)
@@ -142,3 +145,77 @@ async fn push_simple_directory() -> tg::Result<()> {
 
 	result.unwrap()
 }
+
+#[tokio::test]
+async fn push_verbose_indicators_do_not_exceed_their_totals() -> tg::Result<()> {
+	let remote_temp = Temp::new();
+	let remote_config = Config::with_path(remote_temp.path().to_owned());
+	let remote = Server::start(remote_config).await?;
+
+	let server_temp = Temp::new();
+	let server_config = Config::with_path(server_temp.path().to_owned());
+	let server = Server::start(server_config).await?;
+
+	let result = AssertUnwindSafe(async {
+		server
+			.put_remote(
+				"default",
+				tg::remote::put::Arg {
+					url: remote.url().clone(),
+				},
+			)
+			.await?;
+
+		let directory = tg::directory! {
+			"hello.txt" => tg::file!("Hello, world!"),
+			"subdirectory" => tg::directory! {
+				"nested.txt" => tg::file!("I'm nested!")
+			}
+		};
+		let directory = directory.id(&server).await?;
+
+		let arg = tg::object::push::Arg {
+			remote: "default".to_string(),
+			verbose: true,
+		};
+		let events = server
+			.push_object(&directory.clone().into(), arg)
+			.await?
+			.try_collect::<Vec<_>>()
+			.await?;
+		assert!(matches!(
+			events.last(),
+			Some(tg::progress::Event::Output(()))
+		));
+		let mut finished = Vec::new();
+		for event in events {
+			let (tg::progress::Event::Start(indicator)
+			| tg::progress::Event::Update(indicator)
+			| tg::progress::Event::Finish(indicator)) = event
+			else {
+				continue;
+			};
+			if indicator.name != "objects" && indicator.name != "bytes" {
+				assert!(indicator.total.is_some(), "{indicator:?}");
+			}
+			if let (Some(current), Some(total)) = (indicator.current, indicator.total) {
+				assert!(current <= total, "{indicator:?}");
+				if current == total && indicator.name.parse::<tg::object::Id>().is_ok() {
+					finished.push(indicator.name.clone());
+				}
+			}
+		}
+
+		// Confirm that at least one indicator for a pushed object reached its total.
+		assert!(!finished.is_empty());
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+
+	cleanup(server_temp, server).await;
+	cleanup(remote_temp, remote).await;
+
+	result.unwrap()
+}
@@ -1,5 +1,7 @@
 use crate::Server;
+use bytes::Bytes;
 use indoc::formatdoc;
+use num::ToPrimitive as _;
 use std::collections::BTreeSet;
 use tangram_client as tg;
 use tangram_database::{self as db, prelude::*};
@@ -12,6 +14,29 @@ impl Server {
 		id: &tg::object::Id,
 		arg: tg::object::put::Arg,
 	) -> tg::Result<tg::object::put::Output> {
+		self.put_object_inner(id, arg, false).await
+	}
+
+	/// Put an object without validating its bytes. Only use this for objects the server created itself.
+	pub(crate) async fn put_object_trusted(
+		&self,
+		id: &tg::object::Id,
+		arg: tg::object::put::Arg,
+	) -> tg::Result<tg::object::put::Output> {
+		self.put_object_inner(id, arg, true).await
+	}
+
+	async fn put_object_inner(
+		&self,
+		id: &tg::object::Id,
+		arg: tg::object::put::Arg,
+		trusted: bool,
+	) -> tg::Result<tg::object::put::Output> {
+		// Validate the object.
+		if !trusted {
+			Self::validate_object(id, &arg.bytes)?;
+		}
+
 		// Get a database connection.
 		let connection = self
 			.database
@@ -40,6 +65,8 @@ impl Server {
 			.query_one_into::<Row>(statement, params)
 			.await
 			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+		self.object_stats
+			.record_put(arg.bytes.len().to_u64().unwrap());
 
 		// Get the incomplete children.
 		let incomplete: BTreeSet<tg::object::Id> = if children {
@@ -92,6 +119,19 @@ impl Server {
 	}
 }
 
+impl Server {
+	/// Verify that an object's bytes match its id and deserialize to a well-formed object of its kind.
+	pub(crate) fn validate_object(id: &tg::object::Id, bytes: &Bytes) -> tg::Result<()> {
+		let expected = tg::object::Id::new(id.kind(), bytes);
+		if &expected != id {
+			return Err(tg::error!(%id, %expected, "the object's id does not match its bytes"));
+		}
+		tg::object::Data::deserialize(id.kind(), bytes)
+			.map_err(|source| tg::error!(!source, %id, "the object's bytes are malformed"))?;
+		Ok(())
+	}
+}
+
 impl Server {
 	pub(crate) async fn handle_put_object_request<H>(
 		handle: &H,
@@ -109,3 +149,46 @@ impl Server {
 		Ok(response)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{util::fs::cleanup, Config, Server};
+	use bytes::Bytes;
+	use futures::FutureExt as _;
+	use std::panic::AssertUnwindSafe;
+	use tangram_client as tg;
+	use tangram_temp::Temp;
+
+	#[tokio::test]
+	async fn rejects_invalid_objects() -> tg::Result<()> {
+		let temp = Temp::new();
+		let options = Config::with_path(temp.path().to_owned());
+		let server = Server::start(options).await?;
+		let result = AssertUnwindSafe(async {
+			// An object whose id does not match its bytes is rejected.
+			let id: tg::object::Id = tg::leaf::Id::new(&Bytes::from("hello")).into();
+			let arg = tg::object::put::Arg {
+				bytes: Bytes::from("world"),
+			};
+			assert!(server.put_object(&id, arg).await.is_err());
+
+			// A directory whose bytes are not a directory is rejected.
+			let bytes = Bytes::from(r#"{"entries":{"foo":"not an id"}}"#);
+			let id: tg::object::Id = tg::directory::Id::new(&bytes).into();
+			let arg = tg::object::put::Arg { bytes };
+			assert!(server.put_object(&id, arg).await.is_err());
+
+			// A well-formed object is accepted.
+			let bytes = Bytes::from("hello");
+			let id: tg::object::Id = tg::leaf::Id::new(&bytes).into();
+			let arg = tg::object::put::Arg { bytes };
+			server.put_object(&id, arg).await?;
+
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+}
@@ -0,0 +1,87 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use tangram_client as tg;
+
+/// Counters for the server's local object storage.
+#[derive(Debug, Default)]
+pub struct Stats {
+	bytes_read: AtomicU64,
+	bytes_written: AtomicU64,
+	get_hits: AtomicU64,
+	get_misses: AtomicU64,
+	gets: AtomicU64,
+	puts: AtomicU64,
+}
+
+impl Stats {
+	pub fn record_get(&self, bytes: Option<u64>) {
+		self.gets.fetch_add(1, Ordering::Relaxed);
+		if let Some(bytes) = bytes {
+			self.get_hits.fetch_add(1, Ordering::Relaxed);
+			self.bytes_read.fetch_add(bytes, Ordering::Relaxed);
+		} else {
+			self.get_misses.fetch_add(1, Ordering::Relaxed);
+		}
+	}
+
+	pub fn record_put(&self, bytes: u64) {
+		self.puts.fetch_add(1, Ordering::Relaxed);
+		self.bytes_written.fetch_add(bytes, Ordering::Relaxed);
+	}
+
+	pub fn get(&self) -> tg::health::Objects {
+		tg::health::Objects {
+			bytes_read: self.bytes_read.load(Ordering::Relaxed),
+			bytes_written: self.bytes_written.load(Ordering::Relaxed),
+			get_hits: self.get_hits.load(Ordering::Relaxed),
+			get_misses: self.get_misses.load(Ordering::Relaxed),
+			gets: self.gets.load(Ordering::Relaxed),
+			puts: self.puts.load(Ordering::Relaxed),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{util::fs::cleanup, Config, Server};
+	use bytes::Bytes;
+	use futures::FutureExt as _;
+	use std::panic::AssertUnwindSafe;
+	use tangram_client as tg;
+	use tangram_temp::Temp;
+
+	#[tokio::test]
+	async fn counters() -> tg::Result<()> {
+		let temp = Temp::new();
+		let options = Config::with_path(temp.path().to_owned());
+		let server = Server::start(options).await?;
+		let result = AssertUnwindSafe(async {
+			let before = server.object_stats.get();
+
+			// Put an object.
+			let bytes = Bytes::from("hello, world!");
+			let id: tg::object::Id = tg::leaf::Id::new(&bytes).into();
+			let arg = tg::object::put::Arg {
+				bytes: bytes.clone(),
+			};
+			server.put_object(&id, arg).await?;
+
+			// Get the object and an object that does not exist.
+			server.try_get_object_local(&id).await?;
+			let missing: tg::object::Id = tg::leaf::Id::new(&Bytes::from("missing")).into();
+			server.try_get_object_local(&missing).await?;
+
+			let after = server.object_stats.get();
+			assert_eq!(after.puts - before.puts, 1);
+			assert_eq!(after.bytes_written - before.bytes_written, 13);
+			assert_eq!(after.gets - before.gets, 2);
+			assert_eq!(after.get_hits - before.get_hits, 1);
+			assert_eq!(after.get_misses - before.get_misses, 1);
+			assert_eq!(after.bytes_read - before.bytes_read, 13);
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+}
@@ -88,10 +88,13 @@ where
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
 			path: directory.to_owned(),
+			strategy: None,
 		};
 		let package = tg::Artifact::check_in(&server, checkin_arg)
 			.await?
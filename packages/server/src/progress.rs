@@ -79,7 +79,11 @@ impl<T> Handle<T> {
 	}
 
 	pub fn finish(&self, name: &str) {
-		self.indicators.write().unwrap().shift_remove(name);
+		let indicator = self.indicators.write().unwrap().shift_remove(name);
+		if let Some(indicator) = indicator {
+			let event = tg::progress::Event::Finish(indicator.get());
+			self.sender.try_send(Ok(event)).ok();
+		}
 	}
 
 	pub fn output(&self, output: T) {
@@ -101,27 +105,7 @@ impl<T> Handle<T> {
 				.read()
 				.unwrap()
 				.values()
-				.map(move |indicator| {
-					let name = indicator.name.clone();
-					let format = indicator.format.clone();
-					let title = indicator.title.lock().unwrap().clone();
-					let current = indicator
-						.current
-						.as_ref()
-						.map(|value| value.load(std::sync::atomic::Ordering::Relaxed));
-					let total = indicator
-						.total
-						.as_ref()
-						.map(|value| value.load(std::sync::atomic::Ordering::Relaxed));
-					let indicator = tg::progress::Indicator {
-						current,
-						format,
-						name,
-						title,
-						total,
-					};
-					Ok(tg::progress::Event::Update(indicator))
-				})
+				.map(|indicator| Ok(tg::progress::Event::Update(indicator.get())))
 				.collect::<Vec<_>>();
 			stream::iter(indicators)
 		});
@@ -133,3 +117,23 @@ impl<T> Handle<T> {
 		})
 	}
 }
+
+impl Indicator {
+	fn get(&self) -> tg::progress::Indicator {
+		let current = self
+			.current
+			.as_ref()
+			.map(|value| value.load(std::sync::atomic::Ordering::Relaxed));
+		let total = self
+			.total
+			.as_ref()
+			.map(|value| value.load(std::sync::atomic::Ordering::Relaxed));
+		tg::progress::Indicator {
+			current,
+			format: self.format.clone(),
+			name: self.name.clone(),
+			title: self.title.lock().unwrap().clone(),
+			total,
+		}
+	}
+}
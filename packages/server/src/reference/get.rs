@@ -39,10 +39,13 @@ impl Server {
 					cache: false,
 					destructive: false,
 					deterministic: false,
+					follow_symlinks: None,
+					hash_only: false,
 					ignore: true,
 					locked: false,
 					lockfile: true,
 					path: path.clone(),
+					strategy: None,
 				};
 				let mut stream = self.check_in_artifact(arg).await?;
 				let output = pin!(stream)
@@ -1,6 +1,13 @@
 use super::Runtime;
 use crate::Server;
-use std::path::Path;
+use std::{
+	path::Path,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc, Mutex,
+	},
+	time::Duration,
+};
 use tangram_client as tg;
 use tokio_util::compat::{
 	FuturesAsyncWriteCompatExt as _, TokioAsyncReadCompatExt as _, TokioAsyncWriteCompatExt as _,
@@ -10,7 +17,7 @@ impl Runtime {
 	pub async fn archive(
 		&self,
 		build: &tg::Build,
-		_remote: Option<String>,
+		remote: Option<String>,
 	) -> tg::Result<tg::Value> {
 		let server = &self.server;
 
@@ -39,17 +46,80 @@ impl Runtime {
 			.parse::<tg::artifact::archive::Format>()
 			.map_err(|source| tg::error!(!source, "invalid format"))?;
 
+		// Create the log task.
+		let progress = Progress::default();
+		let log_task = tokio::spawn({
+			let server = server.clone();
+			let build = build.clone();
+			let remote = remote.clone();
+			let progress = progress.clone();
+			async move {
+				loop {
+					let indicator = tg::progress::Indicator {
+						current: Some(progress.bytes.load(Ordering::Relaxed)),
+						format: tg::progress::IndicatorFormat::Bytes,
+						name: progress.current.lock().unwrap().clone(),
+						title: "archiving".to_owned(),
+						total: None,
+					};
+					let message = indicator.to_string();
+					let arg = tg::build::log::post::Arg {
+						bytes: message.into(),
+						remote: remote.clone(),
+					};
+					let result = build.add_log(&server, arg).await;
+					if result.is_err() {
+						break;
+					}
+					tokio::time::sleep(Duration::from_secs(1)).await;
+				}
+			}
+		});
+		let log_task_abort_handle = log_task.abort_handle();
+		scopeguard::defer! {
+			log_task_abort_handle.abort();
+		};
+
 		// Create the archive task.
 		let blob = match format {
-			tg::artifact::archive::Format::Tar => tar(server, &artifact).await?,
-			tg::artifact::archive::Format::Zip => zip(server, &artifact).await?,
+			tg::artifact::archive::Format::Tar => tar(server, &artifact, &progress).await?,
+			tg::artifact::archive::Format::Tgar => tgar(server, &artifact).await?,
+			tg::artifact::archive::Format::Zip => zip(server, &artifact, &progress).await?,
 		};
 
+		// Abort and await the log task.
+		log_task.abort();
+		match log_task.await {
+			Ok(()) => Ok(()),
+			Err(error) if error.is_cancelled() => Ok(()),
+			Err(error) => Err(error),
+		}
+		.unwrap();
+
+		// Log that the archive finished.
+		let message = "finished archiving\n";
+		let arg = tg::build::log::post::Arg {
+			bytes: message.into(),
+			remote: remote.clone(),
+		};
+		build.add_log(server, arg).await.ok();
+
 		Ok(blob.into())
 	}
 }
 
-async fn tar(server: &Server, artifact: &tg::Artifact) -> tg::Result<tg::Blob> {
+/// The progress of an archive. The number of bytes is the total size of the files' contents that have been written.
+#[derive(Clone, Default)]
+struct Progress {
+	bytes: Arc<AtomicU64>,
+	current: Arc<Mutex<String>>,
+}
+
+async fn tar(
+	server: &Server,
+	artifact: &tg::Artifact,
+	progress: &Progress,
+) -> tg::Result<tg::Blob> {
 	// Create a duplex stream.
 	let (reader, writer) = tokio::io::duplex(8192);
 
@@ -64,7 +134,7 @@ async fn tar(server: &Server, artifact: &tg::Artifact) -> tg::Result<tg::Blob> {
 			.ok()
 			.ok_or_else(|| tg::error!("can only tar a directory"))?;
 		for (name, artifact) in directory.entries(server).await? {
-			tar_inner(server, &mut builder, Path::new(&name), &artifact).await?;
+			tar_inner(server, &mut builder, Path::new(&name), &artifact, progress).await?;
 		}
 
 		// Finish writing the archive.
@@ -98,10 +168,12 @@ async fn tar_inner<W>(
 	builder: &mut async_tar::Builder<W>,
 	path: &Path,
 	artifact: &tg::Artifact,
+	progress: &Progress,
 ) -> tg::Result<()>
 where
 	W: futures::io::AsyncWrite + Unpin + Send + Sync,
 {
+	*progress.current.lock().unwrap() = path.display().to_string();
 	match artifact {
 		tg::Artifact::Directory(directory) => {
 			let mut header = async_tar::Header::new_gnu();
@@ -113,7 +185,14 @@ where
 				.await
 				.map_err(|source| tg::error!(!source, "failed to append directory"))?;
 			for (name, artifact) in directory.entries(server).await? {
-				Box::pin(tar_inner(server, builder, &path.join(name), &artifact)).await?;
+				Box::pin(tar_inner(
+					server,
+					builder,
+					&path.join(name),
+					&artifact,
+					progress,
+				))
+				.await?;
 			}
 			Ok(())
 		},
@@ -133,7 +212,9 @@ where
 			builder
 				.append_data(&mut header, path, reader)
 				.await
-				.map_err(|source| tg::error!(!source, "failed to append file"))
+				.map_err(|source| tg::error!(!source, "failed to append file"))?;
+			progress.bytes.fetch_add(size, Ordering::Relaxed);
+			Ok(())
 		},
 		tg::Artifact::Symlink(symlink) => {
 			let target = symlink
@@ -155,7 +236,37 @@ where
 	}
 }
 
-async fn zip(server: &Server, artifact: &tg::Artifact) -> tg::Result<tg::Blob> {
+/// Archive an artifact and its closure as a tgar, so that it can be extracted to the same artifact.
+async fn tgar(server: &Server, artifact: &tg::Artifact) -> tg::Result<tg::Blob> {
+	// Create a duplex stream.
+	let (reader, writer) = tokio::io::duplex(8192);
+
+	// Create the archive future.
+	let id = artifact.id(server).await?;
+	let archive_future = server.archive_objects(vec![id.into()], writer);
+
+	// Create the blob future.
+	let blob_future = tg::Blob::with_reader(server, reader);
+
+	// Join the futures.
+	let blob = match futures::future::join(archive_future, blob_future).await {
+		(Ok(()), Ok(blob)) => blob,
+		(Err(source), _) | (_, Err(source)) => {
+			return Err(tg::error!(
+				!source,
+				"failed to join the archive and blob futures"
+			));
+		},
+	};
+
+	Ok(blob)
+}
+
+async fn zip(
+	server: &Server,
+	artifact: &tg::Artifact,
+	progress: &Progress,
+) -> tg::Result<tg::Blob> {
 	// Create a duplex stream.
 	let (reader, writer) = tokio::io::duplex(8192);
 
@@ -170,7 +281,7 @@ async fn zip(server: &Server, artifact: &tg::Artifact) -> tg::Result<tg::Blob> {
 			.ok()
 			.ok_or_else(|| tg::error!("can only zip a directory"))?;
 		for (name, artifact) in directory.entries(server).await? {
-			zip_inner(server, &mut builder, Path::new(&name), &artifact).await?;
+			zip_inner(server, &mut builder, Path::new(&name), &artifact, progress).await?;
 		}
 
 		// Finish writing the archive.
@@ -205,10 +316,12 @@ async fn zip_inner<W>(
 	builder: &mut async_zip::base::write::ZipFileWriter<W>,
 	path: &Path,
 	artifact: &tg::Artifact,
+	progress: &Progress,
 ) -> tg::Result<()>
 where
 	W: futures::io::AsyncWrite + Unpin + Send + Sync,
 {
+	*progress.current.lock().unwrap() = path.display().to_string();
 	match artifact {
 		tg::Artifact::Directory(directory) => {
 			let filename = format!("{}/", path.to_string_lossy());
@@ -225,6 +338,7 @@ where
 					builder,
 					&path.join(name),
 					&artifact.clone(),
+					progress,
 				))
 				.await?;
 			}
@@ -247,9 +361,10 @@ where
 				.unwrap()
 				.compat_write();
 			let mut file_reader = file.read(server, tg::blob::read::Arg::default()).await?;
-			tokio::io::copy(&mut file_reader, &mut entry_writer)
+			let size = tokio::io::copy(&mut file_reader, &mut entry_writer)
 				.await
 				.map_err(|source| tg::error!(!source, "could not write the file entry"))?;
+			progress.bytes.fetch_add(size, Ordering::Relaxed);
 			entry_writer.into_inner().close().await.unwrap();
 			Ok(())
 		},
@@ -74,6 +74,7 @@ async fn executable_file_with_dependency() -> tg::Result<()> {
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(bundled.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -134,6 +135,7 @@ async fn directory_containing_file_with_file_dependency() -> tg::Result<()> {
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(bundled.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -197,6 +199,7 @@ async fn directory_containing_file_with_directory_dependency_target_symlink() ->
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(bundled.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -272,6 +275,7 @@ async fn directory_containing_file_with_directory_dependency_artifact_path_symli
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(bundled.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -328,6 +332,7 @@ where
 		object.load_recursive(&server).await?;
 		let value = tg::Value::from(bundled.clone());
 		let options = tg::value::print::Options {
+			preview_bytes: None,
 			recursive: true,
 			style: tg::value::print::Style::Pretty { indentation: "\t" },
 		};
@@ -1,13 +1,15 @@
 use super::Runtime;
 use crate::Server;
-use futures::{AsyncReadExt as _, StreamExt as _};
+use futures::{AsyncReadExt as _, StreamExt as _, TryStreamExt as _};
 use std::{
 	path::{Path, PathBuf},
+	pin::pin,
+	sync::{Arc, Mutex},
 	time::Duration,
 };
 use tangram_client as tg;
 use tangram_futures::read::shared_position_reader::SharedPositionReader;
-use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek};
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncReadExt as _, AsyncSeek};
 use tokio_util::compat::{FuturesAsyncReadCompatExt as _, TokioAsyncReadCompatExt as _};
 
 impl Runtime {
@@ -46,6 +48,13 @@ impl Runtime {
 			None
 		};
 
+		// Detect the format if it was not provided.
+		let format = if let Some(format) = format {
+			format
+		} else {
+			detect_format(server, &blob).await?
+		};
+
 		// Create the reader.
 		let reader = crate::blob::Reader::new(&self.server, blob.clone()).await?;
 		let reader = SharedPositionReader::with_reader_and_position(reader, 0)
@@ -55,17 +64,20 @@ impl Runtime {
 		// Create the log task.
 		let position = reader.shared_position();
 		let size = blob.size(server).await?;
+		let current = Arc::new(Mutex::new(String::new()));
 		let log_task = tokio::spawn({
 			let server = server.clone();
 			let build = build.clone();
 			let remote = remote.clone();
+			let current = current.clone();
 			async move {
 				loop {
 					let position = position.load(std::sync::atomic::Ordering::Relaxed);
+					let name = current.lock().unwrap().clone();
 					let indicator = tg::progress::Indicator {
 						current: Some(position),
 						format: tg::progress::IndicatorFormat::Bytes,
-						name: String::new(),
+						name,
 						title: "extracting".to_owned(),
 						total: Some(size),
 					};
@@ -87,13 +99,11 @@ impl Runtime {
 			log_task_abort_handle.abort();
 		};
 
-		let format =
-			format.ok_or_else(|| tg::error!("archive format detection is unimplemented"))?;
-
 		// Extract the artifact.
 		let artifact = match format {
-			tg::artifact::archive::Format::Tar => tar(server, reader).await?,
-			tg::artifact::archive::Format::Zip => zip(server, reader).await?,
+			tg::artifact::archive::Format::Tar => tar(server, reader, &current).await?,
+			tg::artifact::archive::Format::Tgar => tgar(server, reader).await?,
+			tg::artifact::archive::Format::Zip => zip(server, reader, &current).await?,
 		};
 
 		// Abort and await the log task.
@@ -117,7 +127,36 @@ impl Runtime {
 	}
 }
 
-async fn tar<R>(server: &Server, reader: R) -> tg::Result<tg::Artifact>
+/// Detect an archive's format from its first bytes. A tgar archive begins with its magic number. A zip archive begins with a local file header, or with an end of central directory record if it is empty. A tar archive has the `ustar` magic at offset 257 of its first header.
+async fn detect_format(
+	server: &Server,
+	blob: &tg::Blob,
+) -> tg::Result<tg::artifact::archive::Format> {
+	let reader = crate::blob::Reader::new(server, blob.clone()).await?;
+	let mut bytes = Vec::new();
+	reader
+		.take(512)
+		.read_to_end(&mut bytes)
+		.await
+		.map_err(|source| tg::error!(!source, "failed to read the archive"))?;
+	detect_format_from_bytes(&bytes)
+		.ok_or_else(|| tg::error!("failed to detect the archive format"))
+}
+
+fn detect_format_from_bytes(bytes: &[u8]) -> Option<tg::artifact::archive::Format> {
+	if bytes.starts_with(&crate::object::archive::MAGIC_NUMBER) {
+		return Some(tg::artifact::archive::Format::Tgar);
+	}
+	if bytes.starts_with(b"PK\x03\x04") || bytes.starts_with(b"PK\x05\x06") {
+		return Some(tg::artifact::archive::Format::Zip);
+	}
+	if bytes.get(257..262) == Some(b"ustar".as_slice()) {
+		return Some(tg::artifact::archive::Format::Tar);
+	}
+	None
+}
+
+async fn tar<R>(server: &Server, reader: R, current: &Mutex<String>) -> tg::Result<tg::Artifact>
 where
 	R: AsyncRead + Unpin + Send + 'static,
 {
@@ -139,6 +178,7 @@ where
 				.map_err(|source| tg::error!(!source, "failed to get the entry path"))?
 				.as_ref(),
 		);
+		*current.lock().unwrap() = path.display().to_string();
 		match header.entry_type() {
 			async_tar::EntryType::Directory => {
 				let directory = tg::Directory::with_entries([].into());
@@ -204,7 +244,24 @@ where
 	Ok(directory.into())
 }
 
-async fn zip<R>(server: &Server, reader: R) -> tg::Result<tg::Artifact>
+/// Extract a tgar by importing its objects. The archive's root is the extracted artifact.
+async fn tgar<R>(server: &Server, reader: R) -> tg::Result<tg::Artifact>
+where
+	R: AsyncRead + Unpin + Send + 'static,
+{
+	let arg = tg::object::import::Arg { remote: None };
+	let stream = server.import_object(arg, reader).await?;
+	let output = pin!(stream)
+		.try_last()
+		.await?
+		.and_then(|event| event.try_unwrap_output().ok())
+		.ok_or_else(|| tg::error!("stream ended without output"))?;
+	let id = tg::artifact::Id::try_from(output.object)
+		.map_err(|source| tg::error!(!source, "the archive's root is not an artifact"))?;
+	Ok(tg::Artifact::with_id(id))
+}
+
+async fn zip<R>(server: &Server, reader: R, current: &Mutex<String>) -> tg::Result<tg::Artifact>
 where
 	R: AsyncBufRead + AsyncSeek + Unpin + Send + 'static,
 {
@@ -229,6 +286,7 @@ where
 				.as_str()
 				.map_err(|source| tg::error!(!source, "failed to get the entry filename"))?,
 		);
+		*current.lock().unwrap() = path.display().to_string();
 
 		// Check if the entry is a directory.
 		let is_dir = reader
@@ -287,3 +345,42 @@ where
 
 	Ok(directory.into())
 }
+
+#[cfg(test)]
+mod tests {
+	use super::detect_format_from_bytes;
+	use tangram_client as tg;
+
+	#[test]
+	fn detect_tgar() {
+		let mut bytes = crate::object::archive::MAGIC_NUMBER.to_vec();
+		bytes.push(0);
+		let format = detect_format_from_bytes(&bytes);
+		assert!(matches!(format, Some(tg::artifact::archive::Format::Tgar)));
+	}
+
+	#[test]
+	fn detect_zip() {
+		let format = detect_format_from_bytes(b"PK\x03\x04\x14\x00");
+		assert!(matches!(format, Some(tg::artifact::archive::Format::Zip)));
+
+		// An empty zip archive begins with an end of central directory record.
+		let format = detect_format_from_bytes(b"PK\x05\x06\x00\x00");
+		assert!(matches!(format, Some(tg::artifact::archive::Format::Zip)));
+	}
+
+	#[test]
+	fn detect_tar() {
+		let mut bytes = vec![0; 512];
+		bytes[257..263].copy_from_slice(b"ustar\x00");
+		let format = detect_format_from_bytes(&bytes);
+		assert!(matches!(format, Some(tg::artifact::archive::Format::Tar)));
+	}
+
+	#[test]
+	fn detect_unknown() {
+		assert!(detect_format_from_bytes(b"").is_none());
+		assert!(detect_format_from_bytes(b"hello, world").is_none());
+		assert!(detect_format_from_bytes(&[0; 512]).is_none());
+	}
+}
@@ -484,10 +484,13 @@ impl Runtime {
 				cache: true,
 				destructive: true,
 				deterministic: true,
+				follow_symlinks: None,
+				hash_only: false,
 				ignore: false,
 				path: output_path.clone(),
 				locked: true,
 				lockfile: false,
+				strategy: None,
 			};
 			tg::Artifact::check_in(server, arg)
 				.await
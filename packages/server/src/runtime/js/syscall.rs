@@ -27,6 +27,7 @@ pub fn syscall<'s>(
 	let result = match name.as_str() {
 		"blob_read" => async_(scope, &args, self::blob::read),
 		"build_output" => async_(scope, &args, self::build::output),
+		"build_progress" => async_(scope, &args, self::build::progress),
 		"checksum" => async_(scope, &args, self::checksum::checksum),
 		"encoding_base64_decode" => sync(scope, &args, self::encoding::base64_decode),
 		"encoding_base64_encode" => sync(scope, &args, self::encoding::base64_encode),
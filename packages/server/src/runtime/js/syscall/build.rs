@@ -1,6 +1,7 @@
 use super::State;
-use std::rc::Rc;
+use std::{collections::BTreeMap, rc::Rc};
 use tangram_client as tg;
+use tangram_v8::Serde;
 
 pub async fn output(state: Rc<State>, args: (tg::Target,)) -> tg::Result<tg::Value> {
 	let (target,) = args;
@@ -13,6 +14,7 @@ pub async fn output(state: Rc<State>, args: (tg::Target,)) -> tg::Result<tg::Val
 			let retry = parent.retry(&server).await?;
 			let arg = tg::target::build::Arg {
 				create: true,
+				labels: BTreeMap::new(),
 				parent: Some(parent.id().clone()),
 				remote,
 				retry,
@@ -27,3 +29,21 @@ pub async fn output(state: Rc<State>, args: (tg::Target,)) -> tg::Result<tg::Val
 		.map_err(|source| tg::error!(!source, "failed to build the target"))?;
 	Ok(output)
 }
+
+pub async fn progress(state: Rc<State>, args: (Serde<serde_json::Value>,)) -> tg::Result<()> {
+	let (value,) = args;
+	let server = state.server.clone();
+	let build = state.build.clone();
+	state
+		.main_runtime_handle
+		.spawn(async move {
+			let arg = tg::build::progress::post::Arg {
+				value: value.into_inner(),
+			};
+			build.add_progress(&server, arg).await
+		})
+		.await
+		.unwrap()
+		.map_err(|source| tg::error!(!source, "failed to add the progress value"))?;
+	Ok(())
+}
@@ -154,6 +154,14 @@ impl tg::Handle for Proxy {
 		self.server.try_read_blob_stream(id, arg)
 	}
 
+	fn list_archive_entries(
+		&self,
+		blob: &tg::blob::Id,
+		arg: tg::artifact::archive::list::Arg,
+	) -> impl Future<Output = tg::Result<tg::artifact::archive::list::Output>> {
+		self.server.list_archive_entries(blob, arg)
+	}
+
 	fn try_get_build(
 		&self,
 		id: &tg::build::Id,
@@ -265,6 +273,38 @@ impl tg::Handle for Proxy {
 		Err(tg::error!("forbidden"))
 	}
 
+	fn try_get_build_progress_stream(
+		&self,
+		id: &tg::build::Id,
+	) -> impl Future<
+		Output = tg::Result<
+			Option<
+				impl Stream<Item = tg::Result<tg::build::progress::get::Event>> + Send + 'static,
+			>,
+		>,
+	> {
+		self.server.try_get_build_progress_stream(id)
+	}
+
+	async fn add_build_progress(
+		&self,
+		id: &tg::build::Id,
+		arg: tg::build::progress::post::Arg,
+	) -> tg::Result<()> {
+		// Only allow the build to add progress to itself.
+		if id != &self.build {
+			return Err(tg::error!("forbidden"));
+		}
+
+		// Progress is only published by the server that owns the build, so forward it to the remote if the build is remote.
+		if let Some(remote) = self.remote.clone() {
+			let client = self.server.get_remote_client(remote).await?;
+			return client.add_build_progress(id, arg).await;
+		}
+
+		self.server.add_build_progress(id, arg).await
+	}
+
 	fn try_get_build_outcome_future(
 		&self,
 		id: &tg::build::Id,
@@ -308,6 +348,13 @@ impl tg::Handle for Proxy {
 		Err(tg::error!("forbidden"))
 	}
 
+	fn find_objects(
+		&self,
+		arg: tg::object::find::Arg,
+	) -> impl Future<Output = tg::Result<tg::object::find::Output>> {
+		self.server.find_objects(arg)
+	}
+
 	fn try_get_object_metadata(
 		&self,
 		id: &tg::object::Id,
@@ -398,7 +445,7 @@ impl tg::Handle for Proxy {
 		Err(tg::error!("forbidden"))
 	}
 
-	async fn clean(&self) -> tg::Result<()> {
+	async fn clean(&self, _arg: tg::clean::Arg) -> tg::Result<tg::clean::Output> {
 		Err(tg::error!("forbidden"))
 	}
 
@@ -12,6 +12,7 @@ impl Server {
 			pattern: pattern.clone(),
 			remote: None,
 			reverse: true,
+			sort: None,
 		};
 		let tg::tag::list::Output { data } = self.list_tags(arg).await?;
 		let Some(output) = data.into_iter().next() else {
@@ -24,7 +25,7 @@ impl Server {
 impl Server {
 	pub(crate) async fn handle_get_tag_request<H>(
 		handle: &H,
-		_request: http::Request<Incoming>,
+		request: http::Request<Incoming>,
 		pattern: &[&str],
 	) -> tg::Result<http::Response<Outgoing>>
 	where
@@ -34,7 +35,32 @@ impl Server {
 		let Some(output) = handle.try_get_tag(&pattern).await? else {
 			return Ok(http::Response::builder().not_found().empty().unwrap());
 		};
-		let response = http::Response::builder().json(output).unwrap();
+
+		// Use the tag's item as its entity tag, and respond that it is not modified if the client already has it.
+		let etag = format!("\"{}\"", output.item);
+		let not_modified = request
+			.headers()
+			.get(http::header::IF_NONE_MATCH)
+			.and_then(|value| value.to_str().ok())
+			.is_some_and(|value| {
+				value
+					.split(',')
+					.map(|value| value.trim().trim_start_matches("W/"))
+					.any(|value| value == etag || value == "*")
+			});
+		if not_modified {
+			let response = http::Response::builder()
+				.status(http::StatusCode::NOT_MODIFIED)
+				.header(http::header::ETAG, etag)
+				.empty()
+				.unwrap();
+			return Ok(response);
+		}
+
+		let response = http::Response::builder()
+			.header(http::header::ETAG, etag)
+			.json(output)
+			.unwrap();
 		Ok(response)
 	}
 }
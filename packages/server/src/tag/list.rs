@@ -78,7 +78,11 @@ impl Server {
 		rows.retain(|row| arg.pattern.matches(&row.tag));
 
 		// Sort the rows.
-		rows.sort_by(|a, b| a.tag.cmp(&b.tag));
+		if let Some(sort) = arg.sort {
+			rows.sort_by(|a, b| sort.compare(&a.tag, &b.tag));
+		} else {
+			rows.sort_by(|a, b| a.tag.cmp(&b.tag));
+		}
 
 		// Reverse if requested.
 		if arg.reverse {
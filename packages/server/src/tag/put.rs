@@ -2,7 +2,9 @@ use crate::Server;
 use indoc::formatdoc;
 use tangram_client as tg;
 use tangram_database::{self as db, prelude::*};
+use tangram_either::Either;
 use tangram_http::{incoming::request::Ext as _, outgoing::response::Ext as _, Incoming, Outgoing};
+use time::format_description::well_known::Rfc3339;
 
 impl Server {
 	pub async fn put_tag(&self, tag: &tg::Tag, arg: tg::tag::put::Arg) -> tg::Result<()> {
@@ -24,16 +26,17 @@ impl Server {
 			.await
 			.map_err(|source| tg::error!(!source, "failed to get database connection"))?;
 
-		// Insert the tag.
+		// Insert the tag, keeping its creation time if it already exists.
 		let p = connection.p();
 		let statement = formatdoc!(
 			"
-				insert into tags (tag, item)
-				values ({p}1, {p}2)
-				on conflict (tag) do update set item = {p}2;
+				insert into tags (tag, item, created_at)
+				values ({p}1, {p}2, {p}3)
+				on conflict (tag) do update set item = {p}2, created_at = coalesce(tags.created_at, {p}3);
 			"
 		);
-		let params = db::params![tag, arg.item];
+		let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
+		let params = db::params![tag, arg.item, now];
 		connection
 			.execute(statement, params)
 			.await
@@ -41,6 +44,54 @@ impl Server {
 
 		Ok(())
 	}
+
+	/// Put many tags. The tags are written in chunks of `advanced.tag_batch_size`, each in its own transaction, so that a large batch does not hold the write lock for long. Each chunk is atomic, but the batch as a whole is not. If an error occurs, the chunks before it will have been written.
+	pub async fn put_tags(
+		&self,
+		tags: &[(tg::Tag, Either<tg::build::Id, tg::object::Id>)],
+	) -> tg::Result<()> {
+		let batch_size = self.config.advanced.tag_batch_size.max(1);
+		for chunk in tags.chunks(batch_size) {
+			// Get a database connection.
+			let mut connection = self
+				.database
+				.write_connection()
+				.await
+				.map_err(|source| tg::error!(!source, "failed to get database connection"))?;
+
+			// Begin a transaction.
+			let transaction = connection
+				.transaction()
+				.await
+				.map_err(|source| tg::error!(!source, "failed to begin a transaction"))?;
+
+			// Insert the tags, keeping their creation times if they already exist.
+			let now = time::OffsetDateTime::now_utc().format(&Rfc3339).unwrap();
+			for (tag, item) in chunk {
+				let p = transaction.p();
+				let statement = formatdoc!(
+					"
+						insert into tags (tag, item, created_at)
+						values ({p}1, {p}2, {p}3)
+						on conflict (tag) do update set item = {p}2, created_at = coalesce(tags.created_at, {p}3);
+					"
+				);
+				let params = db::params![tag, item, now];
+				transaction
+					.execute(statement, params)
+					.await
+					.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+			}
+
+			// Commit the transaction.
+			transaction
+				.commit()
+				.await
+				.map_err(|source| tg::error!(!source, "failed to commit the transaction"))?;
+		}
+
+		Ok(())
+	}
 }
 
 impl Server {
@@ -16,6 +16,7 @@ async fn list_no_results() -> tg::Result<()> {
 			pattern: "test".parse().unwrap(),
 			remote: None,
 			reverse: false,
+			sort: None,
 		};
 		let output = server.list_tags(arg).await?;
 		assert_json_snapshot!(output, @"[]");
@@ -75,6 +76,7 @@ async fn single() -> tg::Result<()> {
 			pattern,
 			remote: None,
 			reverse: false,
+			sort: None,
 		};
 		let output = server.list_tags(arg).await?;
 		assert_json_snapshot!(output, @r#"
@@ -134,6 +136,7 @@ async fn multiple() -> tg::Result<()> {
 			pattern,
 			remote: None,
 			reverse: false,
+			sort: None,
 		};
 		let output = server.list_tags(arg).await?;
 		assert_json_snapshot!(output, @r#"
@@ -151,6 +154,7 @@ async fn multiple() -> tg::Result<()> {
 			pattern,
 			remote: None,
 			reverse: false,
+			sort: None,
 		};
 		let output = server.list_tags(arg).await?;
 		assert_json_snapshot!(output, @r#"
@@ -271,3 +275,94 @@ async fn remote_put() -> tg::Result<()> {
 
 	result.unwrap()
 }
+
+#[tokio::test]
+async fn put_many() -> tg::Result<()> {
+	let temp = Temp::new();
+	let mut options = Config::with_path(temp.path().to_owned());
+	options.advanced.tag_batch_size = 300;
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		let file = tg::File::with_contents("test");
+		let id = file.id(&server).await?;
+
+		// Put the tags.
+		let tags = (0..10_000)
+			.map(|i| {
+				let tag = format!("bulk/{i}").parse().unwrap();
+				let item = tangram_either::Either::Right(id.clone().into());
+				(tag, item)
+			})
+			.collect::<Vec<_>>();
+		server.put_tags(&tags).await?;
+
+		// Confirm that every tag was put.
+		let pattern = "bulk/*".parse().unwrap();
+		let arg = tg::tag::list::Arg {
+			length: None,
+			pattern,
+			remote: None,
+			reverse: false,
+			sort: None,
+		};
+		let output = server.list_tags(arg).await?;
+		assert_eq!(output.data.len(), 10_000);
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	cleanup(temp, server).await;
+	result.unwrap()
+}
+
+#[tokio::test]
+async fn get_if_not() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		let client = tg::Client::new(server.url().clone());
+		client.connect().await?;
+
+		// Put the tag.
+		let file = tg::File::with_contents("test");
+		let id: tg::object::Id = file.id(&server).await?.into();
+		let tag = "test".parse().unwrap();
+		let arg = tg::tag::put::Arg {
+			force: false,
+			item: tangram_either::Either::Right(id.clone()),
+			remote: None,
+		};
+		server.put_tag(&tag, arg).await?;
+
+		// Confirm that the tag is not sent if it refers to the item.
+		let pattern = "test".parse().unwrap();
+		let item = tangram_either::Either::Right(id.clone());
+		let output = client.try_get_tag_if_not(&pattern, &item).await?;
+		assert!(matches!(
+			output,
+			Some(tg::tag::get::Conditional::NotModified)
+		));
+
+		// Confirm that the tag is sent if it refers to a different item.
+		let other: tg::object::Id = tg::File::with_contents("other").id(&server).await?.into();
+		let item = tangram_either::Either::Right(other);
+		let output = client.try_get_tag_if_not(&pattern, &item).await?;
+		let Some(tg::tag::get::Conditional::Modified(output)) = output else {
+			panic!("expected the tag to be modified");
+		};
+		assert_eq!(output.item, tangram_either::Either::Right(id));
+
+		// Confirm that a missing tag is not found.
+		let pattern = "missing".parse().unwrap();
+		let output = client.try_get_tag_if_not(&pattern, &item).await?;
+		assert!(output.is_none());
+
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	cleanup(temp, server).await;
+	result.unwrap()
+}
@@ -1,8 +1,10 @@
 use crate::Server;
 use indoc::formatdoc;
+use serde_with::serde_as;
 use tangram_client as tg;
 use tangram_database::{self as db, prelude::*};
 use tangram_http::{incoming::request::Ext as _, outgoing::response::Ext as _, Incoming, Outgoing};
+use time::format_description::well_known::Rfc3339;
 
 impl Server {
 	pub async fn get_user(&self, token: &str) -> tg::Result<Option<tg::user::User>> {
@@ -14,28 +16,73 @@ impl Server {
 			.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
 
 		// Get the user for the token.
+		#[serde_as]
+		#[derive(serde::Deserialize)]
+		struct Row {
+			id: tg::user::Id,
+			email: String,
+			#[serde_as(as = "Option<Rfc3339>")]
+			expires_at: Option<time::OffsetDateTime>,
+		}
 		let p = connection.p();
 		let statement = formatdoc!(
 			"
-				select users.id, users.email, tokens.token
+				select users.id, users.email, tokens.expires_at
 				from users
 				join tokens on tokens.user = users.id
-				where tokens.token = {p}1;
+				where tokens.id = {p}1;
 			"
 		);
 		let params = db::params![token];
-		let user = connection
-			.query_optional_into(statement, params)
+		let row = connection
+			.query_optional_into::<Row>(statement, params)
 			.await
 			.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
 
 		// Drop the database connection.
 		drop(connection);
 
-		Ok(user)
+		// Reject the token if it has expired. A token without an explicit expiry expires after the configured `authentication.token_ttl` has elapsed since it was issued.
+		let Some(row) = row else {
+			return Ok(None);
+		};
+		let ttl = self
+			.config
+			.authentication
+			.as_ref()
+			.and_then(|authentication| authentication.token_ttl);
+		let expires_at = row.expires_at.or_else(|| {
+			let ttl = ttl?;
+			let issued_at = token_issued_at(token)?;
+			Some(issued_at + ttl)
+		});
+		if expires_at.is_some_and(|expires_at| expires_at <= time::OffsetDateTime::now_utc()) {
+			return Ok(None);
+		}
+
+		let user = tg::user::User {
+			id: row.id,
+			email: row.email,
+		};
+
+		Ok(Some(user))
 	}
 }
 
+/// Get the time at which a token was issued from the timestamp in its ID.
+fn token_issued_at(token: &str) -> Option<time::OffsetDateTime> {
+	let tg::Id::V0(tg::id::V0 {
+		body: tg::id::Body::UuidV7(bytes),
+		..
+	}) = token.parse::<tg::Id>().ok()?
+	else {
+		return None;
+	};
+	let (seconds, nanoseconds) = uuid::Uuid::from_bytes(bytes).get_timestamp()?.to_unix();
+	let timestamp = i128::from(seconds) * 1_000_000_000 + i128::from(nanoseconds);
+	time::OffsetDateTime::from_unix_timestamp_nanos(timestamp).ok()
+}
+
 impl Server {
 	pub(crate) async fn handle_get_user_request<H>(
 		handle: &H,
@@ -75,3 +122,104 @@ impl Server {
 		Ok(Some(user))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{config, util::fs::cleanup, Config, Server};
+	use futures::FutureExt as _;
+	use indoc::formatdoc;
+	use std::{panic::AssertUnwindSafe, time::Duration};
+	use tangram_client as tg;
+	use tangram_database::{self as db, prelude::*};
+	use tangram_temp::Temp;
+	use time::format_description::well_known::Rfc3339;
+
+	#[tokio::test]
+	async fn rejects_expired_tokens() -> tg::Result<()> {
+		let temp = Temp::new();
+		let mut options = Config::with_path(temp.path().to_owned());
+		options.authentication = Some(config::Authentication {
+			token_ttl: Some(Duration::from_secs(3600)),
+			..Default::default()
+		});
+		let server = Server::start(options).await?;
+		let result = AssertUnwindSafe(async {
+			// Create a user.
+			let user: tg::user::Id = tg::Id::new_uuidv7(tg::id::Kind::User).try_into()?;
+			let connection = server
+				.database
+				.write_connection()
+				.await
+				.map_err(|source| tg::error!(!source, "failed to get a database connection"))?;
+			let p = connection.p();
+			let statement = formatdoc!(
+				"
+					insert into users (id, email)
+					values ({p}1, {p}2);
+				"
+			);
+			let params = db::params![user, "user@example.com"];
+			connection
+				.execute(statement, params)
+				.await
+				.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+
+			// Issue tokens the way they are issued in production, by inserting them without an expiry.
+			let now = time::OffsetDateTime::now_utc();
+			let fresh = token(now);
+			let stale = token(now - Duration::from_secs(7200));
+			let expired = token(now);
+			let statement = formatdoc!(
+				"
+					insert into tokens (id, \"user\", expires_at)
+					values ({p}1, {p}2, {p}3);
+				"
+			);
+			for (token, expires_at) in [
+				(&fresh, None),
+				(&stale, None),
+				(&expired, Some(now - Duration::from_secs(1))),
+			] {
+				let expires_at = expires_at.map(|expires_at| expires_at.format(&Rfc3339).unwrap());
+				let params = db::params![token, user, expires_at];
+				connection
+					.execute(statement.clone(), params)
+					.await
+					.map_err(|source| tg::error!(!source, "failed to execute the statement"))?;
+			}
+			drop(connection);
+
+			// Confirm that a token issued within the TTL is accepted.
+			let output = server.get_user(&fresh).await?;
+			assert_eq!(output.map(|user| user.id), Some(user.clone()));
+
+			// Confirm that a token issued before the TTL is rejected.
+			let output = server.get_user(&stale).await?;
+			assert!(output.is_none());
+
+			// Confirm that a token with an explicit expiry in the past is rejected.
+			let output = server.get_user(&expired).await?;
+			assert!(output.is_none());
+
+			Ok::<_, tg::Error>(())
+		})
+		.catch_unwind()
+		.await;
+		cleanup(temp, server).await;
+		result.unwrap()
+	}
+
+	fn token(issued_at: time::OffsetDateTime) -> String {
+		let timestamp = uuid::Timestamp::from_unix(
+			uuid::NoContext,
+			issued_at.unix_timestamp().try_into().unwrap(),
+			issued_at.nanosecond(),
+		);
+		let body = tg::id::Body::UuidV7(uuid::Uuid::new_v7(timestamp).into_bytes());
+		let id = tg::Id::V0(tg::id::V0 {
+			kind: tg::id::Kind::Token,
+			body,
+		});
+		id.to_string()
+	}
+}
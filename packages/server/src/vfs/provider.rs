@@ -412,6 +412,8 @@ impl Provider {
 			connections: options.database_connections,
 			initialize,
 			path,
+			statement_cache_size: db::DEFAULT_STATEMENT_CACHE_SIZE,
+			timeout: None,
 		};
 		let database = db::sqlite::Database::new(database_options)
 			.await
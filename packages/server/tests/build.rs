@@ -1,7 +1,7 @@
-use futures::{Future, FutureExt as _};
+use futures::{Future, FutureExt as _, TryStreamExt as _};
 use indoc::indoc;
 use insta::assert_snapshot;
-use std::{panic::AssertUnwindSafe, pin::pin, str::FromStr};
+use std::{collections::BTreeMap, panic::AssertUnwindSafe, pin::pin, str::FromStr};
 use tangram_client::{self as tg, handle::Ext};
 use tangram_futures::stream::TryExt as _;
 use tangram_server::{Config, Server};
@@ -993,10 +993,13 @@ async fn import_from_tag() -> tg::Result<()> {
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
 			path: bar_temp.as_ref().join("bar"),
+			strategy: None,
 		};
 		let bar_artifact = tg::Artifact::check_in(&server, arg)
 			.await?
@@ -1023,6 +1026,7 @@ async fn import_from_tag() -> tg::Result<()> {
 			.build();
 		let arg = tg::target::build::Arg {
 			create: true,
+			labels: BTreeMap::new(),
 			parent: None,
 			remote: None,
 			retry: tg::build::Retry::Canceled,
@@ -1095,10 +1099,13 @@ async fn build_create_false() -> tg::Result<()> {
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
 			path: artifact_temp.path().to_owned(),
+			strategy: None,
 		};
 		let artifact = tg::Artifact::check_in(&server, arg)
 			.await?
@@ -1128,6 +1135,7 @@ async fn build_create_false() -> tg::Result<()> {
 		// Build the target.
 		let arg = tg::target::build::Arg {
 			create: false,
+			labels: BTreeMap::new(),
 			parent: None,
 			remote: None,
 			retry: tg::build::Retry::Canceled,
@@ -1169,10 +1177,13 @@ async fn build_cache_hit() -> tg::Result<()> {
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
 			path: artifact_temp.path().to_owned(),
+			strategy: None,
 		};
 		let artifact = tg::Artifact::check_in(&server, arg)
 			.await?
@@ -1202,6 +1213,7 @@ async fn build_cache_hit() -> tg::Result<()> {
 		// Build the target.
 		let arg = tg::target::build::Arg {
 			create: true,
+			labels: BTreeMap::new(),
 			parent: None,
 			remote: None,
 			retry: tg::build::Retry::Canceled,
@@ -1240,6 +1252,188 @@ async fn build_cache_hit() -> tg::Result<()> {
 	result.unwrap()
 }
 
+#[tokio::test]
+async fn build_cache_hit_adds_labels() -> tg::Result<()> {
+	let artifact: temp::Artifact = temp::directory! {
+		"tangram.ts" => indoc!(r#"
+			export default tg.target(() => "hello, world!");
+		"#),
+	}
+	.into();
+	let temp = Temp::new();
+	let mut options = Config::with_path(temp.path().to_owned());
+	options.build = Some(tangram_server::config::Build::default());
+	options.build_heartbeat_monitor =
+		Some(tangram_server::config::BuildHeartbeatMonitor::default());
+
+	// Start the server.
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		// Create the temp.
+		let artifact_temp = Temp::new();
+		artifact.to_path(artifact_temp.path()).await.unwrap();
+
+		// Checkin the artifact.
+		let arg = tg::artifact::checkin::Arg {
+			cache: false,
+			destructive: false,
+			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
+			ignore: true,
+			locked: false,
+			lockfile: true,
+			path: artifact_temp.path().to_owned(),
+			strategy: None,
+		};
+		let artifact = tg::Artifact::check_in(&server, arg)
+			.await?
+			.try_unwrap_directory()
+			.unwrap();
+		let artifact = artifact.clone().into();
+		let subpath = Some("tangram.ts".parse().unwrap());
+		let env = [("TANGRAM_HOST".to_owned(), tg::host().into())].into();
+		let args = vec![tg::Value::String("default".into())];
+		let executable = Some(tg::target::Executable::Module(tg::target::Module {
+			kind: tg::module::Kind::Js,
+			referent: tg::Referent {
+				item: artifact,
+				path: Some(".".into()),
+				subpath,
+				tag: None,
+			},
+		}));
+		let target = tg::target::Builder::new("js")
+			.args(args)
+			.env(env)
+			.executable(executable)
+			.build();
+		let target = target.id(&server).await?;
+
+		// Build the target with a label.
+		let arg = tg::target::build::Arg {
+			create: true,
+			labels: [("a".to_owned(), "1".to_owned())].into(),
+			parent: None,
+			remote: None,
+			retry: tg::build::Retry::Canceled,
+		};
+		let build1 = server.build_target(&target, arg).await?.build;
+		tg::Build::with_id(build1.clone()).outcome(&server).await?;
+
+		// Build the target again with another label, which should hit the cache.
+		let arg = tg::target::build::Arg {
+			create: true,
+			labels: [
+				("a".to_owned(), "2".to_owned()),
+				("b".to_owned(), "3".to_owned()),
+			]
+			.into(),
+			parent: None,
+			remote: None,
+			retry: tg::build::Retry::Canceled,
+		};
+		let output = server.build_target(&target, arg).await?;
+		assert_eq!(output.build, build1);
+		assert!(matches!(
+			output.cache,
+			Some(tg::target::build::Cache::Local)
+		));
+
+		// Verify that the labels were added to the existing build.
+		let labels = server.get_build(&build1).await?.labels;
+		let expected: BTreeMap<String, String> = [
+			("a".to_owned(), "2".to_owned()),
+			("b".to_owned(), "3".to_owned()),
+		]
+		.into();
+		assert_eq!(labels, expected);
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	cleanup(temp, server).await;
+	result.unwrap()
+}
+
+#[tokio::test]
+async fn build_progress() -> tg::Result<()> {
+	let temp = Temp::new();
+	let options = Config::with_path(temp.path().to_owned());
+	let server = Server::start(options).await?;
+	let result = AssertUnwindSafe(async {
+		// Put a started build and a finished build.
+		let target = tg::Target::builder("js").build();
+		let target = target.id(&server).await?;
+		let put = |status| {
+			let id = tg::build::Id::new();
+			let arg = tg::build::put::Arg {
+				id: id.clone(),
+				children: Vec::new(),
+				depth: 1,
+				host: "js".to_owned(),
+				labels: BTreeMap::new(),
+				log: None,
+				outcome: None,
+				retry: tg::build::Retry::Canceled,
+				status,
+				target: target.clone(),
+				created_at: time::OffsetDateTime::now_utc(),
+				dequeued_at: None,
+				started_at: None,
+				finished_at: None,
+			};
+			let server = server.clone();
+			async move {
+				server.put_build(&id, arg).await?;
+				Ok::<_, tg::Error>(id)
+			}
+		};
+		let started = put(tg::build::Status::Started).await?;
+		let finished = put(tg::build::Status::Finished).await?;
+
+		// Stream the started build's progress over HTTP, and post values to it.
+		let client = tg::Client::new(server.url().clone());
+		client.connect().await?;
+		let mut stream = pin!(client
+			.try_get_build_progress_stream(&started)
+			.await?
+			.unwrap());
+		for value in [serde_json::json!({ "step": 1 }), serde_json::json!("done")] {
+			let arg = tg::build::progress::post::Arg {
+				value: value.clone(),
+			};
+			client.add_build_progress(&started, arg).await?;
+			let event = stream.try_next().await?.unwrap();
+			let tg::build::progress::get::Event::Value(received) = event else {
+				return Err(tg::error!("expected a value"));
+			};
+			assert_eq!(received, value);
+		}
+
+		// Verify that the stream of a finished build ends immediately.
+		let mut stream = pin!(client
+			.try_get_build_progress_stream(&finished)
+			.await?
+			.unwrap());
+		let event = stream.try_next().await?.unwrap();
+		assert!(matches!(event, tg::build::progress::get::Event::End));
+		assert!(stream.try_next().await?.is_none());
+
+		// Verify that the stream of a missing build is not found.
+		let missing = tg::build::Id::new();
+		assert!(client
+			.try_get_build_progress_stream(&missing)
+			.await?
+			.is_none());
+		Ok::<_, tg::Error>(())
+	})
+	.catch_unwind()
+	.await;
+	cleanup(temp, server).await;
+	result.unwrap()
+}
+
 async fn test<F, Fut>(
 	artifact: impl Into<temp::Artifact>,
 	path: &str,
@@ -1267,10 +1461,13 @@ where
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
 			path: artifact_temp.as_ref().join(path),
+			strategy: None,
 		};
 		let artifact = tg::Artifact::check_in(&server, arg)
 			.await?
@@ -1299,6 +1496,7 @@ where
 			.build();
 		let arg = tg::target::build::Arg {
 			create: true,
+			labels: BTreeMap::new(),
 			parent: None,
 			remote: None,
 			retry: tg::build::Retry::Canceled,
@@ -1359,10 +1557,13 @@ where
 			cache: false,
 			destructive: false,
 			deterministic: false,
+			follow_symlinks: None,
+			hash_only: false,
 			ignore: true,
 			locked: false,
 			lockfile: true,
 			path: artifact_temp.as_ref().join(path),
+			strategy: None,
 		};
 		let artifact = tg::Artifact::check_in(&local, arg)
 			.await?
@@ -1391,6 +1592,7 @@ where
 			.build();
 		let arg = tg::target::build::Arg {
 			create: true,
+			labels: BTreeMap::new(),
 			parent: None,
 			remote: Some("default".to_string()),
 			retry: tg::build::Retry::Canceled,
@@ -1401,6 +1603,7 @@ where
 				&target.clone().into(),
 				tg::object::push::Arg {
 					remote: "default".to_string(),
+					verbose: false,
 				},
 			)
 			.await?;
@@ -1,12 +1,19 @@
 use crate::Version;
 use winnow::{
-	ascii::dec_uint,
-	combinator::{alt, opt, preceded, separated},
+	ascii::{dec_uint, space0},
+	combinator::{alt, delimited, opt, preceded, separated},
 	prelude::*,
 };
 
+/// A version pattern. A pattern is a union of ranges separated by `||`, such as `^1.2||>=2.1,<3`. A version matches the pattern if it matches any of its ranges.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Pattern {
+	pub ranges: Vec<Range>,
+}
+
+/// A range in a version pattern. A range is a list of components separated by `,`, such as `>=1.2.3,<1.5`. A version matches the range if it matches all of its components.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Range {
 	pub components: Vec<Component>,
 }
 
@@ -34,6 +41,25 @@ pub enum Operator {
 pub struct ParseError;
 
 impl Pattern {
+	/// Create a pattern that matches every version. This is the pattern for a missing version constraint, and it is what the empty string parses to.
+	#[must_use]
+	pub fn any() -> Self {
+		Self { ranges: Vec::new() }
+	}
+
+	/// Return true if this pattern matches every version.
+	#[must_use]
+	pub fn is_any(&self) -> bool {
+		self.ranges.is_empty()
+	}
+
+	#[must_use]
+	pub fn matches(&self, version: &Version) -> bool {
+		self.is_any() || self.ranges.iter().any(|range| range.matches(version))
+	}
+}
+
+impl Range {
 	#[must_use]
 	pub fn matches(&self, version: &Version) -> bool {
 		self.components
@@ -132,7 +158,7 @@ impl Component {
 				}
 			},
 		}
-		true
+		false
 	}
 
 	fn matches_less(&self, version: &Version) -> bool {
@@ -159,7 +185,7 @@ impl Component {
 				}
 			},
 		}
-		true
+		false
 	}
 
 	fn matches_tilde(&self, version: &Version) -> bool {
@@ -181,6 +207,26 @@ impl Component {
 }
 
 impl std::fmt::Display for Pattern {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for (i, range) in self.ranges.iter().enumerate() {
+			write!(f, "{range}")?;
+			if i != self.ranges.len() - 1 {
+				write!(f, "||")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl std::str::FromStr for Pattern {
+	type Err = ParseError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		pattern.parse(s).ok().ok_or(ParseError)
+	}
+}
+
+impl std::fmt::Display for Range {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		for (i, component) in self.components.iter().enumerate() {
 			write!(f, "{component}")?;
@@ -192,11 +238,11 @@ impl std::fmt::Display for Pattern {
 	}
 }
 
-impl std::str::FromStr for Pattern {
+impl std::str::FromStr for Range {
 	type Err = ParseError;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		pattern.parse(s).ok().ok_or(ParseError)
+		range.parse(s).ok().ok_or(ParseError)
 	}
 }
 
@@ -245,8 +291,15 @@ impl std::str::FromStr for Operator {
 }
 
 fn pattern(input: &mut &str) -> PResult<Pattern> {
+	let ranges = opt(separated(1.., range, delimited(space0, "||", space0)))
+		.parse_next(input)?
+		.unwrap_or_default();
+	Ok(Pattern { ranges })
+}
+
+fn range(input: &mut &str) -> PResult<Range> {
 	let components = separated(1.., component, ",").parse_next(input)?;
-	Ok(Pattern { components })
+	Ok(Range { components })
 }
 
 fn component(input: &mut &str) -> PResult<Component> {
@@ -286,32 +339,151 @@ mod tests {
 	fn parse() {
 		let left = "=1.2.3";
 		let right = Pattern {
-			components: vec![Component {
-				operator: Operator::Eq,
-				major: 1,
-				minor: Some(2),
-				patch: Some(3),
+			ranges: vec![Range {
+				components: vec![Component {
+					operator: Operator::Eq,
+					major: 1,
+					minor: Some(2),
+					patch: Some(3),
+				}],
 			}],
 		};
 		assert_eq!(left.parse::<Pattern>().unwrap(), right);
 
 		let left = ">=1.2.3,<1.5";
 		let right = Pattern {
-			components: vec![
-				Component {
-					operator: Operator::GreaterEq,
-					major: 1,
-					minor: Some(2),
-					patch: Some(3),
+			ranges: vec![Range {
+				components: vec![
+					Component {
+						operator: Operator::GreaterEq,
+						major: 1,
+						minor: Some(2),
+						patch: Some(3),
+					},
+					Component {
+						operator: Operator::Less,
+						major: 1,
+						minor: Some(5),
+						patch: None,
+					},
+				],
+			}],
+		};
+		assert_eq!(left.parse::<Pattern>().unwrap(), right);
+
+		let left = "^1.2 || ~2.0.1";
+		let right = Pattern {
+			ranges: vec![
+				Range {
+					components: vec![Component {
+						operator: Operator::Caret,
+						major: 1,
+						minor: Some(2),
+						patch: None,
+					}],
 				},
-				Component {
-					operator: Operator::Less,
-					major: 1,
-					minor: Some(5),
-					patch: None,
+				Range {
+					components: vec![Component {
+						operator: Operator::Tilde,
+						major: 2,
+						minor: Some(0),
+						patch: Some(1),
+					}],
 				},
 			],
 		};
 		assert_eq!(left.parse::<Pattern>().unwrap(), right);
+
+		assert_eq!("".parse::<Pattern>().unwrap(), Pattern::any());
+		assert!(",".parse::<Pattern>().is_err());
+		assert!("||".parse::<Pattern>().is_err());
+		assert!("^1||".parse::<Pattern>().is_err());
+	}
+
+	#[test]
+	fn display() {
+		for pattern in [
+			"=1.2.3",
+			">=1.2.3,<1.5",
+			"^1.2||~2.0.1",
+			"^0||>=1,<2||=3.0.0",
+		] {
+			assert_eq!(pattern.parse::<Pattern>().unwrap().to_string(), pattern);
+		}
+		let pattern = "^1.2 || ~2.0.1".parse::<Pattern>().unwrap();
+		assert_eq!(pattern.to_string(), "^1.2||~2.0.1");
+	}
+
+	fn matches(pattern: &str, version: &str) -> bool {
+		let pattern = pattern.parse::<Pattern>().unwrap();
+		let version = version.parse::<Version>().unwrap();
+		pattern.matches(&version)
+	}
+
+	#[test]
+	fn caret() {
+		assert!(matches("^1.2.3", "1.2.3"));
+		assert!(matches("^1.2.3", "1.2.4"));
+		assert!(matches("^1.2.3", "1.9.0"));
+		assert!(!matches("^1.2.3", "1.2.2"));
+		assert!(!matches("^1.2.3", "2.0.0"));
+		assert!(matches("^1", "1.9.9"));
+		assert!(!matches("^1", "0.9.9"));
+		assert!(matches("^0.2.3", "0.2.9"));
+		assert!(!matches("^0.2.3", "0.3.0"));
+		assert!(matches("^0.0.3", "0.0.3"));
+		assert!(!matches("^0.0.3", "0.0.4"));
+		assert!(matches("^0.0", "0.0.9"));
+		assert!(!matches("^0.0", "0.1.0"));
+	}
+
+	#[test]
+	fn tilde() {
+		assert!(matches("~1.2.3", "1.2.3"));
+		assert!(matches("~1.2.3", "1.2.9"));
+		assert!(!matches("~1.2.3", "1.2.2"));
+		assert!(!matches("~1.2.3", "1.3.0"));
+		assert!(matches("~1.2", "1.2.0"));
+		assert!(!matches("~1.2", "1.3.0"));
+		assert!(matches("~1", "1.9.0"));
+		assert!(!matches("~1", "2.0.0"));
+	}
+
+	#[test]
+	fn comparison() {
+		assert!(matches(">=1.2.3", "1.2.3"));
+		assert!(matches(">=1.2.3", "2.0.0"));
+		assert!(!matches(">=1.2.3", "1.2.2"));
+		assert!(matches("<1.5", "1.4.9"));
+		assert!(!matches("<1.5", "1.5.0"));
+		assert!(matches(">=1.2.3,<1.5", "1.4.0"));
+		assert!(!matches(">=1.2.3,<1.5", "1.5.1"));
+		assert!(matches(">1.2.3", "1.2.4"));
+		assert!(!matches(">1.2.3", "1.2.3"));
+		assert!(matches("<1.2.3", "1.2.2"));
+		assert!(!matches("<1.2.3", "1.2.3"));
+		assert!(matches("<=1.2.3", "1.2.3"));
+		assert!(!matches("<=1.2.3", "1.2.4"));
+	}
+
+	#[test]
+	fn union() {
+		assert!(matches("^1.2||^2", "1.3.0"));
+		assert!(matches("^1.2||^2", "2.5.0"));
+		assert!(!matches("^1.2||^2", "1.1.0"));
+		assert!(!matches("^1.2||^2", "3.0.0"));
+		assert!(matches("<1||>=2,<3", "0.5.0"));
+		assert!(!matches("<1||>=2,<3", "1.5.0"));
+		assert!(matches("<1||>=2,<3", "2.5.0"));
+	}
+
+	#[test]
+	fn any() {
+		let pattern = Pattern::any();
+		assert!(pattern.is_any());
+		assert_eq!(pattern.to_string(), "");
+		for version in ["0.0.0", "0.1.0", "1.2.3", "1.2.3-alpha.1", "10.0.0+build"] {
+			assert!(pattern.matches(&version.parse().unwrap()));
+		}
 	}
 }
@@ -4,7 +4,10 @@ use winnow::{
 	prelude::*,
 };
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// A semantic version.
+///
+/// Equality and hashing include the build, so two versions that differ only in their build are distinct. Versions are ordered by their precedence, with the build as a tiebreaker. Use [`Version::cmp_precedence`] to compare versions by precedence alone.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Version {
 	pub major: u64,
 	pub minor: u64,
@@ -16,6 +19,172 @@ pub struct Version {
 #[derive(Clone, Debug, derive_more::Display, derive_more::Error)]
 pub struct ParseError;
 
+impl Version {
+	/// Return true if the version has a prerelease.
+	///
+	/// ```
+	/// # use tangram_version::Version;
+	/// assert!("1.2.3-alpha.1".parse::<Version>().unwrap().is_prerelease());
+	/// assert!(!"1.2.3".parse::<Version>().unwrap().is_prerelease());
+	/// ```
+	#[must_use]
+	pub fn is_prerelease(&self) -> bool {
+		self.prerelease.is_some()
+	}
+
+	/// Return true if the version is stable, which means its major version is at least one and it does not have a prerelease.
+	///
+	/// ```
+	/// # use tangram_version::Version;
+	/// assert!("1.2.3".parse::<Version>().unwrap().is_stable());
+	/// assert!(!"0.2.3".parse::<Version>().unwrap().is_stable());
+	/// assert!(!"1.2.3-alpha.1".parse::<Version>().unwrap().is_stable());
+	/// ```
+	#[must_use]
+	pub fn is_stable(&self) -> bool {
+		self.major >= 1 && !self.is_prerelease()
+	}
+
+	/// Compare two versions by their precedence, as defined by semver. The build is ignored, and a version with a prerelease has lower precedence than the same version without one. Prereleases are compared identifier by identifier. Numeric identifiers are compared numerically and have lower precedence than alphanumeric identifiers, which are compared lexically, and a prerelease with fewer identifiers has lower precedence if all the preceding identifiers are equal.
+	///
+	/// ```
+	/// # use tangram_version::Version;
+	/// let a = "1.0.0+a".parse::<Version>().unwrap();
+	/// let b = "1.0.0+b".parse::<Version>().unwrap();
+	/// assert!(a.cmp_precedence(&b).is_eq());
+	/// assert_ne!(a, b);
+	/// ```
+	#[must_use]
+	pub fn cmp_precedence(&self, other: &Self) -> std::cmp::Ordering {
+		self.major
+			.cmp(&other.major)
+			.then_with(|| self.minor.cmp(&other.minor))
+			.then_with(|| self.patch.cmp(&other.patch))
+			.then_with(|| match (&self.prerelease, &other.prerelease) {
+				(None, None) => std::cmp::Ordering::Equal,
+				(None, Some(_)) => std::cmp::Ordering::Greater,
+				(Some(_), None) => std::cmp::Ordering::Less,
+				(Some(a), Some(b)) => {
+					let a = a.split('.').map(Identifier::new);
+					let b = b.split('.').map(Identifier::new);
+					a.cmp(b)
+				},
+			})
+	}
+
+	/// Return the next major version. The minor and patch versions are reset to zero, and the prerelease and build are cleared.
+	///
+	/// ```
+	/// # use tangram_version::Version;
+	/// let version = "1.2.3-alpha.1+build".parse::<Version>().unwrap();
+	/// assert_eq!(version.increment_major().to_string(), "2.0.0");
+	/// ```
+	#[must_use]
+	pub fn increment_major(&self) -> Self {
+		Self {
+			major: self.major + 1,
+			minor: 0,
+			patch: 0,
+			prerelease: None,
+			build: None,
+		}
+	}
+
+	/// Return the next minor version. The patch version is reset to zero, and the prerelease and build are cleared.
+	///
+	/// ```
+	/// # use tangram_version::Version;
+	/// let version = "1.2.3-alpha.1+build".parse::<Version>().unwrap();
+	/// assert_eq!(version.increment_minor().to_string(), "1.3.0");
+	/// ```
+	#[must_use]
+	pub fn increment_minor(&self) -> Self {
+		Self {
+			major: self.major,
+			minor: self.minor + 1,
+			patch: 0,
+			prerelease: None,
+			build: None,
+		}
+	}
+
+	/// Return the next patch version. The prerelease and build are cleared.
+	///
+	/// ```
+	/// # use tangram_version::Version;
+	/// let version = "1.2.3-alpha.1+build".parse::<Version>().unwrap();
+	/// assert_eq!(version.increment_patch().to_string(), "1.2.4");
+	/// ```
+	#[must_use]
+	pub fn increment_patch(&self) -> Self {
+		Self {
+			major: self.major,
+			minor: self.minor,
+			patch: self.patch + 1,
+			prerelease: None,
+			build: None,
+		}
+	}
+
+	/// Return this version with its prerelease replaced. The build is kept.
+	///
+	/// ```
+	/// # use tangram_version::Version;
+	/// let version = "1.2.3+build".parse::<Version>().unwrap();
+	/// assert_eq!(version.with_prerelease("rc.1").to_string(), "1.2.3-rc.1+build");
+	/// ```
+	#[must_use]
+	pub fn with_prerelease(&self, prerelease: impl Into<String>) -> Self {
+		Self {
+			prerelease: Some(prerelease.into()),
+			..self.clone()
+		}
+	}
+
+	/// Return this version without its build.
+	///
+	/// ```
+	/// # use tangram_version::Version;
+	/// let version = "1.2.3-rc.1+build".parse::<Version>().unwrap();
+	/// assert_eq!(version.clear_build().to_string(), "1.2.3-rc.1");
+	/// ```
+	#[must_use]
+	pub fn clear_build(&self) -> Self {
+		Self {
+			build: None,
+			..self.clone()
+		}
+	}
+}
+
+impl PartialOrd for Version {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Version {
+	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+		self.cmp_precedence(other)
+			.then_with(|| self.build.cmp(&other.build))
+	}
+}
+
+/// A prerelease identifier. The variant order gives numeric identifiers lower precedence than alphanumeric ones.
+#[derive(Eq, Ord, PartialEq, PartialOrd)]
+enum Identifier<'a> {
+	Numeric(u64),
+	Alphanumeric(&'a str),
+}
+
+impl<'a> Identifier<'a> {
+	fn new(identifier: &'a str) -> Self {
+		identifier
+			.parse()
+			.map_or(Self::Alphanumeric(identifier), Self::Numeric)
+	}
+}
+
 impl std::fmt::Display for Version {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
@@ -85,4 +254,85 @@ mod tests {
 		};
 		assert_eq!(left.parse::<Version>().unwrap(), right);
 	}
+
+	#[test]
+	fn stability() {
+		let version = "1.0.0".parse::<Version>().unwrap();
+		assert!(!version.is_prerelease());
+		assert!(version.is_stable());
+
+		let version = "1.0.0+build.1".parse::<Version>().unwrap();
+		assert!(!version.is_prerelease());
+		assert!(version.is_stable());
+
+		let version = "0.9.9".parse::<Version>().unwrap();
+		assert!(!version.is_prerelease());
+		assert!(!version.is_stable());
+
+		let version = "2.0.0-rc.1".parse::<Version>().unwrap();
+		assert!(version.is_prerelease());
+		assert!(!version.is_stable());
+	}
+
+	#[test]
+	fn precedence() {
+		let versions = [
+			"1.0.0-alpha",
+			"1.0.0-alpha.1",
+			"1.0.0-alpha.beta",
+			"1.0.0-beta",
+			"1.0.0-beta.2",
+			"1.0.0-beta.11",
+			"1.0.0-rc.1",
+			"1.0.0",
+			"1.0.1",
+			"1.1.0",
+			"2.0.0",
+		]
+		.map(|version| version.parse::<Version>().unwrap());
+		for (a, b) in versions.iter().zip(versions.iter().skip(1)) {
+			assert!(a.cmp_precedence(b).is_lt(), "{a} < {b}");
+			assert!(a < b, "{a} < {b}");
+		}
+
+		let a = "1.0.0+a".parse::<Version>().unwrap();
+		let b = "1.0.0+b".parse::<Version>().unwrap();
+		assert!(a.cmp_precedence(&b).is_eq());
+		assert_ne!(a, b);
+		assert!(a < b);
+		assert_eq!(a.to_string(), "1.0.0+a");
+
+		let a = "1.0.0-rc.1+a".parse::<Version>().unwrap();
+		let b = "1.0.0-rc.1+b".parse::<Version>().unwrap();
+		assert!(a.cmp_precedence(&b).is_eq());
+		let c = "1.0.0".parse::<Version>().unwrap();
+		assert!(a.cmp_precedence(&c).is_lt());
+	}
+
+	#[test]
+	fn increment() {
+		let version = "0.0.0".parse::<Version>().unwrap();
+		assert_eq!(version.increment_major().to_string(), "1.0.0");
+		assert_eq!(version.increment_minor().to_string(), "0.1.0");
+		assert_eq!(version.increment_patch().to_string(), "0.0.1");
+
+		let version = "1.2.3".parse::<Version>().unwrap();
+		assert_eq!(version.increment_major().to_string(), "2.0.0");
+		assert_eq!(version.increment_minor().to_string(), "1.3.0");
+		assert_eq!(version.increment_patch().to_string(), "1.2.4");
+
+		let version = "1.0.0-rc.1+build.1".parse::<Version>().unwrap();
+		assert_eq!(version.increment_major().to_string(), "2.0.0");
+		assert_eq!(version.increment_minor().to_string(), "1.1.0");
+		assert_eq!(version.increment_patch().to_string(), "1.0.1");
+		assert_eq!(
+			version.with_prerelease("rc.2").to_string(),
+			"1.0.0-rc.2+build.1"
+		);
+		assert_eq!(version.clear_build().to_string(), "1.0.0-rc.1");
+		assert_eq!(
+			version.clear_build().with_prerelease("rc.2").to_string(),
+			"1.0.0-rc.2"
+		);
+	}
 }
@@ -55,6 +55,7 @@ enum RequestData {
 	ReadDir(sys::fuse_read_in),
 	ReadDirPlus(sys::fuse_read_in),
 	ReadLink,
+	ReadOnly(u32),
 	Release(sys::fuse_release_in),
 	ReleaseDir(sys::fuse_release_in),
 	Unsupported(u32),
@@ -83,7 +84,8 @@ impl<P> Server<P>
 where
 	P: Provider + Send + Sync + 'static,
 {
-	pub async fn start(provider: P, path: &Path) -> Result<Self> {
+	/// Start the server and mount it at the path. Requests that would modify the filesystem are always rejected with `EROFS`. If `read_only` is set, then the filesystem is also mounted read-only, so that the kernel rejects them before they reach the server.
+	pub async fn start(provider: P, path: &Path, read_only: bool) -> Result<Self> {
 		// Create the server.
 		let server = Self(Arc::new(Inner {
 			provider,
@@ -94,7 +96,7 @@ where
 		unmount(path).await.ok();
 
 		// Mount.
-		let fd = Self::mount(path)
+		let fd = Self::mount(path, read_only)
 			.await
 			.inspect_err(|error| tracing::error!(%error, "failed to mount"))?;
 
@@ -285,6 +287,21 @@ where
 			sys::fuse_opcode::FUSE_READLINK => RequestData::ReadLink,
 			sys::fuse_opcode::FUSE_RELEASE => RequestData::Release(read_data(data)?),
 			sys::fuse_opcode::FUSE_RELEASEDIR => RequestData::ReleaseDir(read_data(data)?),
+			sys::fuse_opcode::FUSE_COPY_FILE_RANGE
+			| sys::fuse_opcode::FUSE_CREATE
+			| sys::fuse_opcode::FUSE_FALLOCATE
+			| sys::fuse_opcode::FUSE_LINK
+			| sys::fuse_opcode::FUSE_MKDIR
+			| sys::fuse_opcode::FUSE_MKNOD
+			| sys::fuse_opcode::FUSE_REMOVEXATTR
+			| sys::fuse_opcode::FUSE_RENAME
+			| sys::fuse_opcode::FUSE_RENAME2
+			| sys::fuse_opcode::FUSE_RMDIR
+			| sys::fuse_opcode::FUSE_SETATTR
+			| sys::fuse_opcode::FUSE_SETXATTR
+			| sys::fuse_opcode::FUSE_SYMLINK
+			| sys::fuse_opcode::FUSE_UNLINK
+			| sys::fuse_opcode::FUSE_WRITE => RequestData::ReadOnly(header.opcode),
 			_ => RequestData::Unsupported(header.opcode),
 		};
 		let request = Request { header, data };
@@ -321,6 +338,9 @@ where
 					.await
 			},
 			RequestData::ReadLink => self.handle_read_link_request(request.header).await,
+			RequestData::ReadOnly(opcode) => {
+				self.handle_read_only_request(request.header, opcode).await
+			},
 			RequestData::Release(data) => self.handle_release_request(request.header, data).await,
 			RequestData::ReleaseDir(data) => {
 				self.handle_release_dir_request(request.header, data).await
@@ -468,8 +488,11 @@ where
 	async fn handle_open_request(
 		&self,
 		header: fuse_in_header,
-		_request: fuse_open_in,
+		request: fuse_open_in,
 	) -> Result<Option<Response>> {
+		if is_write_open(request.flags) {
+			return Err(Error::from_raw_os_error(libc::EROFS));
+		}
 		let fh = self.provider.open(header.nodeid).await?;
 		let out = fuse_open_out {
 			fh,
@@ -590,6 +613,14 @@ where
 		Ok(Some(Response::ReleaseDir))
 	}
 
+	async fn handle_read_only_request(
+		&self,
+		_header: fuse_in_header,
+		_request: u32,
+	) -> Result<Option<Response>> {
+		Err(Error::from_raw_os_error(libc::EROFS))
+	}
+
 	async fn handle_unsupported_request(
 		&self,
 		header: fuse_in_header,
@@ -599,7 +630,7 @@ where
 		Err(Error::from_raw_os_error(libc::ENOSYS))
 	}
 
-	async fn mount(path: &Path) -> Result<Arc<OwnedFd>> {
+	async fn mount(path: &Path, read_only: bool) -> Result<Arc<OwnedFd>> {
 		unsafe {
 			// Create the file socket pair.
 			let mut fds = [0, 0];
@@ -612,8 +643,9 @@ where
 			// Create the args.
 			let uid = libc::getuid();
 			let gid = libc::getgid();
+			let ro = if read_only { ",ro" } else { "" };
 			let options = CString::new(format!(
-				"rootmode=40755,user_id={uid},group_id={gid},default_permissions"
+				"rootmode=40755,user_id={uid},group_id={gid},default_permissions{ro}"
 			))
 			.unwrap();
 			let path = CString::new(path.as_os_str().as_bytes()).unwrap();
@@ -754,6 +786,12 @@ where
 	}
 }
 
+/// Whether the flags of an open request would allow writing to or truncating the file.
+fn is_write_open(flags: u32) -> bool {
+	let flags = i32::from_ne_bytes(flags.to_ne_bytes());
+	flags & libc::O_ACCMODE != libc::O_RDONLY || flags & libc::O_TRUNC != 0
+}
+
 fn read_data<T>(request_data: &[u8]) -> Result<T>
 where
 	T: zerocopy::FromBytes,
@@ -845,3 +883,18 @@ pub async fn unmount(path: &Path) -> Result<()> {
 		.await?;
 	Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn write_opens_are_rejected() {
+		let flags = |flags: i32| u32::from_ne_bytes(flags.to_ne_bytes());
+		assert!(!is_write_open(flags(libc::O_RDONLY)));
+		assert!(!is_write_open(flags(libc::O_RDONLY | libc::O_CLOEXEC)));
+		assert!(is_write_open(flags(libc::O_WRONLY)));
+		assert!(is_write_open(flags(libc::O_RDWR)));
+		assert!(is_write_open(flags(libc::O_RDONLY | libc::O_TRUNC)));
+	}
+}
@@ -2748,7 +2748,7 @@ impl xdr::Encode for nfs_resop4 {
 			},
 			nfs_resop4::OP_CREATE => {
 				encoder.encode(&nfs_opnum4::OP_CREATE)?;
-				encoder.encode(&nfsstat4::NFS4ERR_PERM)?;
+				encoder.encode(&nfsstat4::NFS4ERR_ROFS)?;
 			},
 			nfs_resop4::OP_DELEGPURGE => {
 				encoder.encode(&nfs_opnum4::OP_DELEGPURGE)?;
@@ -2768,7 +2768,7 @@ impl xdr::Encode for nfs_resop4 {
 			},
 			nfs_resop4::OP_LINK => {
 				encoder.encode(&nfs_opnum4::OP_LINK)?;
-				encoder.encode(&nfsstat4::NFS4ERR_PERM)?;
+				encoder.encode(&nfsstat4::NFS4ERR_ROFS)?;
 			},
 			nfs_resop4::OP_LOCK(res) => {
 				encoder.encode(&nfs_opnum4::OP_LOCK)?;
@@ -2836,11 +2836,11 @@ impl xdr::Encode for nfs_resop4 {
 			},
 			nfs_resop4::OP_REMOVE => {
 				encoder.encode(&nfs_opnum4::OP_REMOVE)?;
-				encoder.encode(&nfsstat4::NFS4ERR_PERM)?;
+				encoder.encode(&nfsstat4::NFS4ERR_ROFS)?;
 			},
 			nfs_resop4::OP_RENAME => {
 				encoder.encode(&nfs_opnum4::OP_RENAME)?;
-				encoder.encode(&nfsstat4::NFS4ERR_PERM)?;
+				encoder.encode(&nfsstat4::NFS4ERR_ROFS)?;
 			},
 			nfs_resop4::OP_RENEW(res) => {
 				encoder.encode(&nfs_opnum4::OP_RENEW)?;
@@ -2860,7 +2860,7 @@ impl xdr::Encode for nfs_resop4 {
 			},
 			nfs_resop4::OP_SETATTR => {
 				encoder.encode(&nfs_opnum4::OP_SETATTR)?;
-				encoder.encode(&nfsstat4::NFS4ERR_PERM)?;
+				encoder.encode(&nfsstat4::NFS4ERR_ROFS)?;
 			},
 			nfs_resop4::OP_SETCLIENTID(res) => {
 				encoder.encode(&nfs_opnum4::OP_SETCLIENTID)?;
@@ -2876,7 +2876,7 @@ impl xdr::Encode for nfs_resop4 {
 			},
 			nfs_resop4::OP_WRITE => {
 				encoder.encode(&nfs_opnum4::OP_WRITE)?;
-				encoder.encode(&nfsstat4::NFS4ERR_PERM)?;
+				encoder.encode(&nfsstat4::NFS4ERR_ROFS)?;
 			},
 			nfs_resop4::OP_RELEASE_LOCKOWNER(res) => {
 				encoder.encode(&nfs_opnum4::OP_RELEASE_LOCKOWNER)?;
@@ -3008,7 +3008,7 @@ impl nfs_resop4 {
 			nfs_resop4::OP_CLOSE(CLOSE4res::Error(e)) => *e,
 			nfs_resop4::OP_CLOSE(CLOSE4res::NFS4_OK(_)) => nfsstat4::NFS4_OK,
 			nfs_resop4::OP_COMMIT => nfsstat4::NFS4ERR_NOTSUPP,
-			nfs_resop4::OP_CREATE => nfsstat4::NFS4ERR_PERM,
+			nfs_resop4::OP_CREATE => nfsstat4::NFS4ERR_ROFS,
 			nfs_resop4::OP_DELEGPURGE => nfsstat4::NFS4ERR_NOTSUPP,
 			nfs_resop4::OP_DELEGRETURN => nfsstat4::NFS4ERR_NOTSUPP,
 			nfs_resop4::OP_GETATTR(GETATTR4res::Error(e)) => *e,
@@ -3016,7 +3016,7 @@ impl nfs_resop4 {
 			nfs_resop4::OP_GETFH(GETFH4res::Error(e)) => *e,
 			nfs_resop4::OP_GETFH(GETFH4res::NFS4_OK(_)) => nfsstat4::NFS4_OK,
 			nfs_resop4::OP_ILLEGAL(_) => nfsstat4::NFS4ERR_OP_ILLEGAL,
-			nfs_resop4::OP_LINK => nfsstat4::NFS4ERR_PERM,
+			nfs_resop4::OP_LINK => nfsstat4::NFS4ERR_ROFS,
 			nfs_resop4::OP_LOCK(LOCK4res::Error(e)) => *e,
 			nfs_resop4::OP_LOCK(LOCK4res::NFS4_OK(_)) => nfsstat4::NFS4_OK,
 			nfs_resop4::OP_LOCK(LOCK4res::NFS4ERR_DENIED(_)) => nfsstat4::NFS4ERR_DENIED,
@@ -3043,14 +3043,14 @@ impl nfs_resop4 {
 			nfs_resop4::OP_READDIR(READDIR4res::NFS4_OK(_)) => nfsstat4::NFS4_OK,
 			nfs_resop4::OP_READLINK(READLINK4res::Error(e)) => *e,
 			nfs_resop4::OP_READLINK(READLINK4res::NFS4_OK(_)) => nfsstat4::NFS4_OK,
-			nfs_resop4::OP_REMOVE => nfsstat4::NFS4ERR_PERM,
-			nfs_resop4::OP_RENAME => nfsstat4::NFS4ERR_PERM,
+			nfs_resop4::OP_REMOVE => nfsstat4::NFS4ERR_ROFS,
+			nfs_resop4::OP_RENAME => nfsstat4::NFS4ERR_ROFS,
 			nfs_resop4::OP_RENEW(RENEW4res { status }) => *status,
 			nfs_resop4::OP_RESTOREFH(RESTOREFH4res { status }) => *status,
 			nfs_resop4::OP_SAVEFH(SAVEFH4res { status }) => *status,
 			nfs_resop4::OP_SECINFO(SECINFO4res::Error(e)) => *e,
 			nfs_resop4::OP_SECINFO(SECINFO4res::NFS4_OK(_)) => nfsstat4::NFS4_OK,
-			nfs_resop4::OP_SETATTR => nfsstat4::NFS4ERR_PERM,
+			nfs_resop4::OP_SETATTR => nfsstat4::NFS4ERR_ROFS,
 			nfs_resop4::OP_SETCLIENTID_CONFIRM(SETCLIENTID_CONFIRM4res { status }) => *status,
 			nfs_resop4::OP_SETCLIENTID(SETCLIENTID4res::Error(e)) => *e,
 			nfs_resop4::OP_SETCLIENTID(SETCLIENTID4res::NFS4_OK(_)) => nfsstat4::NFS4_OK,
@@ -3058,7 +3058,7 @@ impl nfs_resop4 {
 				nfsstat4::NFS4ERR_CLID_INUSE
 			},
 			nfs_resop4::OP_VERIFY => nfsstat4::NFS4ERR_NOTSUPP,
-			nfs_resop4::OP_WRITE => nfsstat4::NFS4ERR_PERM,
+			nfs_resop4::OP_WRITE => nfsstat4::NFS4ERR_ROFS,
 			nfs_resop4::OP_RELEASE_LOCKOWNER(RELEASE_LOCKOWNER4res { status }) => *status,
 			nfs_resop4::Unknown(_) => nfsstat4::NFS4ERR_NOTSUPP,
 			nfs_resop4::Timeout(_) => nfsstat4::NFS4ERR_DELAY,
@@ -0,0 +1,124 @@
+#![cfg(target_os = "linux")]
+
+use bytes::Bytes;
+use std::io::{Error, Result};
+use tangram_vfs::{Attrs, FileType, ROOT_NODE_ID};
+
+const FILE_NAME: &str = "file";
+const FILE_NODE_ID: u64 = 2;
+const FILE_CONTENTS: &[u8] = b"contents";
+
+struct Provider;
+
+impl tangram_vfs::Provider for Provider {
+	async fn close(&self, _handle: u64) {}
+
+	async fn getattr(&self, id: u64) -> Result<Attrs> {
+		match id {
+			ROOT_NODE_ID => Ok(Attrs::new(FileType::Directory)),
+			FILE_NODE_ID => Ok(Attrs::new(FileType::File {
+				executable: false,
+				size: u64::try_from(FILE_CONTENTS.len()).unwrap(),
+			})),
+			_ => Err(Error::from_raw_os_error(libc::ENOENT)),
+		}
+	}
+
+	async fn getxattr(&self, _id: u64, _name: &str) -> Result<Option<Bytes>> {
+		Ok(None)
+	}
+
+	async fn listxattrs(&self, _id: u64) -> Result<Vec<String>> {
+		Ok(Vec::new())
+	}
+
+	async fn lookup(&self, id: u64, name: &str) -> Result<Option<u64>> {
+		Ok((id == ROOT_NODE_ID && name == FILE_NAME).then_some(FILE_NODE_ID))
+	}
+
+	async fn lookup_parent(&self, _id: u64) -> Result<u64> {
+		Ok(ROOT_NODE_ID)
+	}
+
+	async fn open(&self, id: u64) -> Result<u64> {
+		Ok(id)
+	}
+
+	async fn opendir(&self, id: u64) -> Result<u64> {
+		Ok(id)
+	}
+
+	async fn read(&self, _handle: u64, position: u64, length: u64) -> Result<Bytes> {
+		let start = usize::try_from(position).unwrap().min(FILE_CONTENTS.len());
+		let end = start
+			.saturating_add(usize::try_from(length).unwrap())
+			.min(FILE_CONTENTS.len());
+		Ok(Bytes::from_static(&FILE_CONTENTS[start..end]))
+	}
+
+	async fn readdir(&self, _handle: u64) -> Result<Vec<(String, u64)>> {
+		Ok(vec![(FILE_NAME.to_owned(), FILE_NODE_ID)])
+	}
+
+	async fn readlink(&self, _id: u64) -> Result<Bytes> {
+		Err(Error::from_raw_os_error(libc::EINVAL))
+	}
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn writes_fail_with_erofs() {
+	// Mounting requires FUSE, which is not available in every environment.
+	if !std::path::Path::new("/dev/fuse").exists() {
+		eprintln!("skipping because /dev/fuse does not exist");
+		return;
+	}
+
+	for read_only in [false, true] {
+		// Mount the VFS.
+		let mountpoint = std::env::temp_dir().join(format!(
+			"tangram_vfs_test_{}_{read_only}",
+			std::process::id()
+		));
+		tokio::fs::create_dir_all(&mountpoint).await.unwrap();
+		let server = tangram_vfs::fuse::Server::start(Provider, &mountpoint, read_only)
+			.await
+			.unwrap();
+
+		// Attempt to read and modify the filesystem. Run the operations on a blocking thread, because the kernel waits for the server to respond.
+		let path = mountpoint.clone();
+		let results = tokio::task::spawn_blocking(move || {
+			let file = path.join(FILE_NAME);
+			let read = std::fs::read(&file);
+			let write = std::fs::OpenOptions::new().write(true).open(&file).err();
+			let create = std::fs::File::create(path.join("new")).err();
+			let mkdir = std::fs::create_dir(path.join("directory")).err();
+			let remove = std::fs::remove_file(&file).err();
+			(read, [write, create, mkdir, remove])
+		})
+		.await
+		.unwrap();
+
+		// Unmount the VFS.
+		server.stop();
+		server.wait().await;
+		tangram_vfs::fuse::unmount(&mountpoint).await.ok();
+		tokio::fs::remove_dir(&mountpoint).await.ok();
+
+		// Verify that reads succeed and that every write fails. A read-only mount rejects writes with EROFS in the kernel. Otherwise, the kernel rejects writes by users other than root with EACCES because of the nodes' modes, and the server rejects writes by root with EROFS.
+		let expected = if read_only || unsafe { libc::geteuid() } == 0 {
+			libc::EROFS
+		} else {
+			libc::EACCES
+		};
+		let (read, errors) = results;
+		assert_eq!(read.unwrap(), FILE_CONTENTS, "read_only = {read_only}");
+		for error in errors {
+			let error = error.expect("expected the operation to fail");
+			assert_eq!(
+				error.raw_os_error(),
+				Some(expected),
+				"read_only = {read_only}, error = {error}"
+			);
+		}
+	}
+}